@@ -38,6 +38,10 @@ impl Abi {
         self.get_contract_name() + "Query"
     }
 
+    pub(crate) fn get_background_query_name(&self) -> String {
+        self.get_contract_name() + "BackgroundQuery"
+    }
+
     pub(crate) fn get_query_events_name(&self) -> String {
         self.get_contract_name() + "QueryEvents"
     }
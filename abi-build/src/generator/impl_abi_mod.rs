@@ -108,6 +108,7 @@ fn get_mod_imports() -> TokenStream {
         use multiversx_sc_scenario::scenario_model::ScQueryStep;
         use novax_executor::TransactionExecutor;
         use novax_executor::QueryExecutor;
+        use novax_executor::QueryBlock;
         use novax_executor::QueryEventsExecutor;
         use novax_executor::EventQueryResult;
         use novax_executor::IntoFilterTerms;
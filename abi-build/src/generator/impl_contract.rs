@@ -25,8 +25,9 @@ pub(crate) fn impl_contract(mod_name: &str, abi: &Abi) -> Result<TokenStream, Bu
     let contract_info_ident = format_ident!("{}", contract_info_name);
     let call_name = format_ident!("{}", abi.get_call_name());
     let query_name = format_ident!("{}", abi.get_query_name());
+    let background_query_name = format_ident!("{}", abi.get_background_query_name());
     let query_events_name = format_ident!("{}", abi.get_query_events_name());
-    let (calls_impls, queries_impls) = impl_abi_endpoints(
+    let (calls_impls, queries_impls, background_queries_impls) = impl_abi_endpoints(
         &contract_info_name,
         &abi.endpoints,
         &abi.types
@@ -145,6 +146,30 @@ pub(crate) fn impl_contract(mod_name: &str, abi: &Abi) -> Result<TokenStream, Bu
             {
                 contract_address: A,
                 egld_value: num_bigint::BigUint,
+                token_transfers: Vec<TokenTransfer>,
+                caller: Option<Address>,
+                block: Option<QueryBlock>,
+                executor: Executor,
+                caching: Caching
+            }
+
+            /// Struct representing a smart contract query refreshing outdated cached results in the background.
+            /// It is obtained through `#query_name::with_background_refresh` and has the same views as `#query_name`.
+            /// With a caching strategy supporting it, such as `CachingStale` from the "novax-caching" crate,
+            /// a stale result is returned right away while the query is executed again by a spawned task.
+            #[derive(Clone, Debug)]
+            pub struct #background_query_name<Executor, Caching, A>
+            where
+                Executor: QueryExecutor,
+                Caching: CachingStrategy,
+                A: Deref + Send + Sync,
+                Address: for<'a> From<&'a A::Target>
+            {
+                contract_address: A,
+                egld_value: num_bigint::BigUint,
+                token_transfers: Vec<TokenTransfer>,
+                caller: Option<Address>,
+                block: Option<QueryBlock>,
                 executor: Executor,
                 caching: Caching
             }
@@ -197,6 +222,9 @@ pub(crate) fn impl_contract(mod_name: &str, abi: &Abi) -> Result<TokenStream, Bu
                     #query_name {
                         contract_address: self.address,
                         egld_value: num_bigint::BigUint::from(0u8),
+                        token_transfers: vec![],
+                        caller: None,
+                        block: None,
                         executor,
                         caching: CachingNone
                     }
@@ -237,6 +265,9 @@ pub(crate) fn impl_contract(mod_name: &str, abi: &Abi) -> Result<TokenStream, Bu
                         contract_address: self.contract_address,
                         executor: self.executor,
                         egld_value: self.egld_value,
+                        token_transfers: self.token_transfers,
+                        caller: self.caller,
+                        block: self.block,
                         caching: strategy.clone(),
                     }
                 }
@@ -256,10 +287,98 @@ pub(crate) fn impl_contract(mod_name: &str, abi: &Abi) -> Result<TokenStream, Bu
                     }
                 }
 
+                /// Modifies the ESDT transfers associated with the query.
+                /// This method allows setting a new list of ESDT transfers sent along with the query, for views depending on payments.
+                ///
+                /// # Parameters
+                /// * `token_transfers`: A reference to the new list of ESDT transfers to be used.
+                ///
+                /// # Returns
+                /// A new instance of `#query_name` with the updated ESDT transfers.
+                pub fn with_esdt_transfers(self, token_transfers: &Vec<TokenTransfer>) -> #query_name<Executor, Caching, A> {
+                    #query_name {
+                        token_transfers: token_transfers.clone(),
+                        ..self
+                    }
+                }
+
+                /// Modifies the caller of the query.
+                /// By default, the query is made by the contract itself.
+                ///
+                /// # Parameters
+                /// * `caller`: A reference to the address making the query.
+                ///
+                /// # Returns
+                /// A new instance of `#query_name` with the updated caller.
+                pub fn with_caller(self, caller: &Address) -> #query_name<Executor, Caching, A> {
+                    #query_name {
+                        caller: Some(caller.clone()),
+                        ..self
+                    }
+                }
+
+                /// Executes the query against the state of the blockchain right after the block with the given nonce.
+                /// The block is part of the caching key, so results at different blocks are cached separately.
+                ///
+                /// # Parameters
+                /// * `block_nonce`: The nonce of the block.
+                ///
+                /// # Returns
+                /// A new instance of `#query_name` targeting the given block.
+                pub fn with_block_nonce(self, block_nonce: u64) -> #query_name<Executor, Caching, A> {
+                    #query_name {
+                        block: Some(QueryBlock::Nonce(block_nonce)),
+                        ..self
+                    }
+                }
+
+                /// Executes the query against the state of the blockchain right after the block with the given hash.
+                /// The block is part of the caching key, so results at different blocks are cached separately.
+                ///
+                /// # Parameters
+                /// * `block_hash`: The hex-encoded hash of the block.
+                ///
+                /// # Returns
+                /// A new instance of `#query_name` targeting the given block.
+                pub fn with_block_hash(self, block_hash: String) -> #query_name<Executor, Caching, A> {
+                    #query_name {
+                        block: Some(QueryBlock::Hash(block_hash)),
+                        ..self
+                    }
+                }
+
+                /// Refreshes outdated cached results in the background instead of waiting for the query.
+                /// The query is executed by a spawned task, so the executor must be `Clone + 'static`.
+                ///
+                /// # Returns
+                /// A new instance of `#background_query_name` with the same parameters.
+                pub fn with_background_refresh(self) -> #background_query_name<Executor, Caching, A> {
+                    #background_query_name {
+                        contract_address: self.contract_address,
+                        egld_value: self.egld_value,
+                        token_transfers: self.token_transfers,
+                        caller: self.caller,
+                        block: self.block,
+                        executor: self.executor,
+                        caching: self.caching
+                    }
+                }
+
                 // Other query implementations generated from the ABI
                 #queries_impls
             }
 
+            impl<Executor, Caching, A> #background_query_name<Executor, Caching, A>
+            where
+                Executor: QueryExecutor + Clone + 'static,
+                Caching: CachingStrategy,
+                A: Deref + Send + Sync,
+                Address: for<'a> From<&'a A::Target>
+            {
+                // Query implementations generated from the ABI, refreshed in the background
+                #background_queries_impls
+            }
+
             #events_structs_impls
             #events_filters_structs_impls
 
@@ -406,14 +525,15 @@ fn impl_proxy_endpoint(abi_endpoint: &AbiEndpoint, abi_types: &AbiTypes) -> Resu
     Ok(result)
 }
 
-// (TokenStream, TokenStream) = (calls, queries)
+// (TokenStream, TokenStream, TokenStream) = (calls, queries, background queries)
 fn impl_abi_endpoints(
     contract_info_name: &str,
     abi_endpoints: &AbiEndpoints,
     abi_types: &AbiTypes
-) -> Result<(TokenStream, TokenStream), BuildError> {
+) -> Result<(TokenStream, TokenStream, TokenStream), BuildError> {
     let mut calls_impls: Vec<TokenStream> = vec![];
     let mut queries_impls: Vec<TokenStream> = vec![];
+    let mut background_queries_impls: Vec<TokenStream> = vec![];
     for endpoint in abi_endpoints {
         let endpoint_impls = impl_abi_endpoint_call_query(
             contract_info_name,
@@ -423,6 +543,7 @@ fn impl_abi_endpoints(
 
         calls_impls.push(endpoint_impls.0);
         queries_impls.push(endpoint_impls.1);
+        background_queries_impls.push(endpoint_impls.2);
     }
 
     Ok(
@@ -432,6 +553,9 @@ fn impl_abi_endpoints(
             },
             quote!{
                 #(#queries_impls)*
+            },
+            quote!{
+                #(#background_queries_impls)*
             }
         )
     )
@@ -474,11 +598,12 @@ fn impl_abi_events(
     )
 }
 
+// (TokenStream, TokenStream, TokenStream) = (call, query, background query)
 fn impl_abi_endpoint_call_query(
     contract_info_name: &str,
     abi_endpoint: &AbiEndpoint,
     abi_types: &AbiTypes
-) -> Result<(TokenStream, TokenStream), BuildError> {
+) -> Result<(TokenStream, TokenStream, TokenStream), BuildError> {
     let debug_api = get_api_generic_ident();
     let contract_info_ident = format_ident!("{}", contract_info_name);
     let endpoint_name = abi_endpoint.name.as_str();
@@ -493,7 +618,7 @@ fn impl_abi_endpoint_call_query(
     }
     let (function_managed_outputs, function_native_outputs) = impl_endpoint_outputs(&abi_endpoint.outputs, abi_types, &debug_api)?;
 
-    let endpoint_query_key = impl_endpoint_key_for_query(&abi_endpoint.name, "query", &abi_endpoint.inputs);
+    let endpoint_query_key = impl_endpoint_key_for_query(&abi_endpoint.name, "query", &abi_endpoint.inputs, true);
 
     let (endpoint_args_let_statements, endpoint_args_inputs) = impl_endpoint_args_for_call(&abi_endpoint.inputs, abi_types)?;
 
@@ -566,7 +691,9 @@ fn impl_abi_endpoint_call_query(
                             #endpoint_name.to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         ).await;
 
                     if let Result::Ok(result) = result {
@@ -580,7 +707,54 @@ fn impl_abi_endpoint_call_query(
         }
     };
 
-    Ok((call_token, query_token))
+    let background_query_token = quote! {
+        /// Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background.
+        ///
+        /// # Description
+        /// #abi_description
+        ///
+        /// # Parameters
+        /// #function_inputs
+        ///
+        /// # Returns
+        /// A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails.
+        pub async fn #function_name_ident(#function_inputs) -> Result<#function_native_outputs, NovaXError> {
+            #common_token
+            #endpoint_query_key
+
+            // The getter owns its environment, so a caching strategy can run it in the background
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+
+            self.caching.get_or_refresh_cache_in_background(
+                _novax_key,
+                async move {
+                    let result = _novax_executor
+                        .execute::<#function_managed_outputs>(
+                            &_novax_contract_address,
+                            #endpoint_name.to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        ).await;
+
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                }
+            ).await
+        }
+    };
+
+    Ok((call_token, query_token, background_query_token))
 }
 
 // (TokenStream, TokenStream, TokenStream) = (event struct impls, event filter struct impls, function impl)
@@ -634,7 +808,7 @@ fn impl_abi_event_query(
 
     let (event_return_struct_type, event_return_struct_type_impls) = impl_abi_event_struct_type(event_identifier, event_field_managed_names_and_types.clone(), has_data)?;
     let (event_filters_struct_type, event_filters_struct_type_impls) = impl_abi_event_filter_struct_type(event_identifier, event_indexed_field_managed_names_and_types)?;
-    let endpoint_query_key = impl_endpoint_key_for_query(event_identifier, "query_events", &vec![]); // TODO
+    let endpoint_query_key = impl_endpoint_key_for_query(event_identifier, "query_events", &vec![], false); // TODO
 
     let event_query_token = quote! {
         pub async fn #event_identifier_ident(
@@ -780,6 +954,7 @@ fn impl_endpoint_key_for_query(
     endpoint_name: &str,
     key_suffix: &str,
     abi_inputs: &AbiInputs,
+    has_query_options: bool
 ) -> TokenStream {
     let mut inputs_hash_idents: Vec<TokenStream> = vec![];
     for input in abi_inputs {
@@ -788,6 +963,30 @@ fn impl_endpoint_key_for_query(
         inputs_hash_idents.push(quote!{#input_name_ident.hash(&mut _novax_hasher);})
     }
 
+    // Only hashed when set, so the keys of the queries made with the default options are left unchanged
+    if has_query_options {
+        inputs_hash_idents.push(quote! {
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+        })
+    }
+
     let endpoint_name_key = format!("{endpoint_name}{key_suffix}");
 
     quote! {
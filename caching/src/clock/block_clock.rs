@@ -0,0 +1,62 @@
+//! The block clock used to expire the values cached with `CachingDurationStrategy::EachBlock`.
+//!
+//! By default, a block is assumed every 6 seconds, aligned on the Unix epoch, and nothing changes it.
+//! Set the clock of the network at startup so the values expire exactly when a new round starts:
+//!
+//! ```ignore
+//! set_block_clock_from_gateway(&gateway_url, 0).await?;
+//! ```
+
+use novax::errors::NovaXError;
+use novax::executor::BlockClock;
+
+pub use implementation::{get_block_clock, set_block_clock};
+
+/// Anchors the block clock on the latest block of `shard`, with the round duration of the network, and returns it.
+pub async fn set_block_clock_from_gateway(gateway_url: &str, shard: u32) -> Result<BlockClock, NovaXError> {
+    let clock = BlockClock::from_gateway(&gateway_url, shard).await?;
+    set_block_clock(clock);
+
+    Ok(clock)
+}
+
+/// Restores the default block clock, 6 seconds aligned on the Unix epoch.
+pub fn reset_block_clock() {
+    set_block_clock(BlockClock::DEFAULT)
+}
+
+#[cfg(not(test))]
+mod implementation {
+    use std::sync::RwLock;
+    use novax::executor::BlockClock;
+
+    static BLOCK_CLOCK: RwLock<BlockClock> = RwLock::new(BlockClock::DEFAULT);
+
+    /// Returns the block clock used by all the caching strategies.
+    pub fn get_block_clock() -> BlockClock {
+        *BLOCK_CLOCK.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Replaces the block clock used by all the caching strategies.
+    pub fn set_block_clock(clock: BlockClock) {
+        *BLOCK_CLOCK.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = clock;
+    }
+}
+
+#[cfg(test)]
+mod implementation {
+    use std::cell::RefCell;
+    use novax::executor::BlockClock;
+
+    thread_local! {
+        static BLOCK_CLOCK: RefCell<BlockClock> = const { RefCell::new(BlockClock::DEFAULT) };
+    }
+
+    pub fn get_block_clock() -> BlockClock {
+        BLOCK_CLOCK.with(|value| *value.borrow())
+    }
+
+    pub fn set_block_clock(clock: BlockClock) {
+        BLOCK_CLOCK.with(|value| *value.borrow_mut() = clock);
+    }
+}
@@ -1,10 +1,5 @@
 pub(crate) fn get_timestamp_of_next_block(current_timestamp: &Duration) -> Result<Duration, NovaXError> {
-    let mut timestamp = current_timestamp.as_secs() + 1;
-    while timestamp % 6 != 0 {
-        timestamp += 1
-    }
-
-    Ok(Duration::from_secs(timestamp))
+    Ok(get_block_clock().get_timestamp_of_next_block(*current_timestamp))
 }
 
 pub(crate) trait GetDuration {
@@ -48,6 +43,7 @@ pub(crate) use implementation::get_current_timestamp;
 pub(crate) use implementation::set_mock_time;
 use novax::caching::CachingDurationStrategy;
 use novax::errors::NovaXError;
+use crate::clock::block_clock::get_block_clock;
 
 #[cfg(test)]
 mod implementation {
@@ -79,6 +75,8 @@ mod implementation {
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
+    use novax::executor::BlockClock;
+    use crate::clock::block_clock::{reset_block_clock, set_block_clock};
     use crate::date::get_current_timestamp::get_timestamp_of_next_block;
 
     #[test]
@@ -104,4 +102,16 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_get_timestamp_of_next_block_custom_clock() {
+        set_block_clock(BlockClock::new(Duration::from_millis(600), Duration::from_millis(100), 0));
+
+        let result = get_timestamp_of_next_block(&Duration::from_secs(8)).unwrap();
+        let expected = Duration::from_millis(8_500);
+
+        reset_block_clock();
+
+        assert_eq!(result, expected);
+    }
 }
\ No newline at end of file
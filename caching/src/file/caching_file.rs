@@ -0,0 +1,514 @@
+use std::future::Future;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use novax::caching::{CachingDurationStrategy, CachingStrategy};
+use novax::errors::{CachingError, NovaXError};
+
+use crate::date::get_current_timestamp::{get_current_timestamp, GetDuration};
+use crate::file::error::CachingFileError;
+use crate::serialization::envelope::{decode_envelope, encode_envelope};
+use crate::serialization::format::CachingSerializationFormat;
+
+const ENTRY_EXTENSION: &str = "cache";
+const EXPIRATION_HEADER_SIZE: usize = 8;
+
+static TEMPORARY_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A caching strategy persisting the values in a local directory, one file per key.
+///
+/// Each file holds the expiration timestamp followed by the serialized value,
+/// so expirations are honored across process restarts.
+///
+/// Files are written to a temporary path and then atomically renamed, several processes can therefore share the same directory:
+/// a reader always sees either the previous or the new value, never a partially written one.
+#[derive(Clone, Debug)]
+pub struct CachingFile {
+    pub(crate) directory: PathBuf,
+    pub duration_strategy: CachingDurationStrategy,
+    pub serialization_format: CachingSerializationFormat
+}
+
+impl CachingFile {
+    /// Creates a new `CachingFile` storing its values in `directory`, which is created if it doesn't exist.
+    pub async fn new<P: Into<PathBuf>>(
+        directory: P,
+        duration_strategy: CachingDurationStrategy
+    ) -> Result<Self, CachingFileError> {
+        let directory = directory.into();
+
+        if let Err(error) = tokio::fs::create_dir_all(&directory).await {
+            return Err(CachingFileError::CannotCreateDirectory { description: format!("{}", error) });
+        }
+
+        Ok(
+            CachingFile {
+                directory,
+                duration_strategy,
+                serialization_format: CachingSerializationFormat::default()
+            }
+        )
+    }
+
+    /// Sets the format used to serialize the values, MessagePack by default.
+    ///
+    /// Values written with another format can still be read.
+    pub fn with_serialization_format(mut self, serialization_format: CachingSerializationFormat) -> Self {
+        self.serialization_format = serialization_format;
+        self
+    }
+
+    /// Removes the files of all the expired entries.
+    ///
+    /// Expired entries are never returned, this function only reclaims disk space.
+    pub async fn perform_cleanup(&self) -> Result<(), NovaXError> {
+        let current_timestamp = get_current_timestamp()?;
+
+        for path in self.get_entry_paths().await.map_err(CachingError::from)? {
+            let Some((expiration_timestamp, _)) = read_entry(&path).await? else {
+                continue;
+            };
+
+            if current_timestamp >= expiration_timestamp {
+                remove_entry(&path).await.map_err(CachingError::from)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_entry_path(&self, key: u64) -> PathBuf {
+        self.directory.join(format!("{key:016x}.{ENTRY_EXTENSION}"))
+    }
+
+    async fn get_entry_paths(&self) -> Result<Vec<PathBuf>, CachingFileError> {
+        let mut read_dir = match tokio::fs::read_dir(&self.directory).await {
+            Ok(read_dir) => read_dir,
+            Err(error) => return Err(CachingFileError::CannotReadValue { description: format!("{}", error) })
+        };
+
+        let mut paths = vec![];
+        loop {
+            let dir_entry = match read_dir.next_entry().await {
+                Ok(Some(dir_entry)) => dir_entry,
+                Ok(None) => break,
+                Err(error) => return Err(CachingFileError::CannotReadValue { description: format!("{}", error) })
+            };
+
+            let path = dir_entry.path();
+            if path.extension().is_some_and(|extension| extension == ENTRY_EXTENSION) {
+                paths.push(path);
+            }
+        }
+
+        Ok(paths)
+    }
+
+    async fn write_entry(&self, key: u64, expiration_timestamp: Duration, value: &[u8]) -> Result<(), CachingFileError> {
+        let mut content = Vec::with_capacity(EXPIRATION_HEADER_SIZE + value.len());
+        content.extend_from_slice(&(expiration_timestamp.as_millis() as u64).to_be_bytes());
+        content.extend_from_slice(value);
+
+        let temporary_path = self.directory.join(format!(
+            ".{key:016x}.{}.{}.tmp",
+            std::process::id(),
+            TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        if let Err(error) = tokio::fs::write(&temporary_path, content).await {
+            return Err(CachingFileError::CannotWriteValue { description: format!("{}", error) });
+        }
+
+        if let Err(error) = tokio::fs::rename(&temporary_path, self.get_entry_path(key)).await {
+            let _ = tokio::fs::remove_file(&temporary_path).await;
+            return Err(CachingFileError::CannotWriteValue { description: format!("{}", error) });
+        }
+
+        Ok(())
+    }
+}
+
+async fn read_entry(path: &Path) -> Result<Option<(Duration, Vec<u8>)>, NovaXError> {
+    let mut content = match tokio::fs::read(path).await {
+        Ok(content) => content,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(CachingError::from(CachingFileError::CannotReadValue { description: format!("{}", error) }).into())
+    };
+
+    if content.len() < EXPIRATION_HEADER_SIZE {
+        return Err(CachingError::UnableToDeserialize.into());
+    }
+
+    let value = content.split_off(EXPIRATION_HEADER_SIZE);
+    let mut expiration_millis = [0u8; EXPIRATION_HEADER_SIZE];
+    expiration_millis.copy_from_slice(&content);
+
+    Ok(Some((Duration::from_millis(u64::from_be_bytes(expiration_millis)), value)))
+}
+
+async fn remove_entry(path: &Path) -> Result<(), CachingFileError> {
+    match tokio::fs::remove_file(path).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(CachingFileError::CannotRemoveValue { description: format!("{}", error) })
+    }
+}
+
+#[async_trait]
+impl CachingStrategy for CachingFile {
+    async fn get_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64) -> Result<Option<T>, NovaXError> {
+        let Some((expiration_timestamp, value_encoded)) = read_entry(&self.get_entry_path(key)).await? else {
+            return Ok(None);
+        };
+
+        if get_current_timestamp()? >= expiration_timestamp {
+            return Ok(None);
+        }
+
+        Ok(decode_envelope(&value_encoded)?)
+    }
+
+    async fn set_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64, value: &T) -> Result<(), NovaXError> {
+        let current_timestamp = get_current_timestamp()?;
+        let expiration_timestamp = self.duration_strategy.get_duration_timestamp(&current_timestamp)?;
+
+        if expiration_timestamp <= current_timestamp {
+            return Ok(());
+        }
+
+        let encoded = encode_envelope(self.serialization_format, value)?;
+
+        self.write_entry(key, expiration_timestamp, &encoded)
+            .await
+            .map_err(|e| {
+                CachingError::from(e).into()
+            })
+    }
+
+    async fn get_or_set_cache<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        FutureGetter: Future<Output=Result<T, Error>> + Send,
+        Error: From<NovaXError>
+    {
+        let opt_value = self.get_cache(key).await?;
+
+        match opt_value {
+            None => {
+                let value_to_set = getter.await?;
+                self.set_cache(key, &value_to_set).await?;
+                Ok(value_to_set)
+            },
+            Some(value) => {
+                Ok(value)
+            }
+        }
+    }
+
+    async fn clear(&self) -> Result<(), NovaXError> {
+        for path in self.get_entry_paths().await.map_err(CachingError::from)? {
+            remove_entry(&path).await.map_err(CachingError::from)?;
+        }
+
+        Ok(())
+    }
+
+    fn with_duration_strategy(&self, strategy: CachingDurationStrategy) -> Self {
+        CachingFile {
+            directory: self.directory.clone(),
+            duration_strategy: strategy,
+            serialization_format: self.serialization_format,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    use novax::caching::{CachingDurationStrategy, CachingStrategy};
+    use novax::errors::{CachingError, NovaXError};
+
+    use crate::date::get_current_timestamp::set_mock_time;
+    use crate::file::caching_file::CachingFile;
+    use crate::local::caching_local::CachingLocal;
+    use crate::multi::caching::CachingMulti;
+
+    static TEST_DIRECTORY_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    fn get_test_directory() -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "novax-caching-file-test-{}-{}",
+            std::process::id(),
+            TEST_DIRECTORY_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    async fn new_caching(duration_strategy: CachingDurationStrategy) -> CachingFile {
+        CachingFile::new(get_test_directory(), duration_strategy).await.unwrap()
+    }
+
+    async fn get_file_count(caching: &CachingFile) -> usize {
+        caching.get_entry_paths().await.unwrap().len()
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_key_not_found() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+        let key = 1;
+
+        let result = caching.get_cache::<()>(key).await?;
+
+        assert_eq!(result, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_cache() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+        let key = 1;
+        let value = "test".to_string();
+
+        caching.set_cache(key, &value).await?;
+
+        let result = caching.get_cache::<String>(key).await?;
+        let expected = Some("test".to_string());
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_cache_overwrite() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+        caching.set_cache(key, &"test".to_string()).await?;
+
+        let result = caching.get_cache::<String>(key).await?;
+        let expected = Some("test".to_string());
+
+        assert_eq!(result, expected);
+        assert_eq!(get_file_count(&caching).await, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_cache_zero_duration() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::Duration(Duration::from_secs(0))).await;
+
+        caching.set_cache(1, &"test".to_string()).await?;
+
+        assert_eq!(get_file_count(&caching).await, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_before_expiration() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::Duration(Duration::from_secs(10))).await;
+        let key = 1;
+        let value = "test".to_string();
+
+        caching.set_cache(key, &value).await?;
+
+        set_mock_time(Duration::from_secs(9));
+
+        let result = caching.get_cache::<String>(key).await?;
+        let expected = Some("test".to_string());
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_after_expiration() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::Duration(Duration::from_secs(10))).await;
+        let key = 1;
+        let value = "test".to_string();
+
+        caching.set_cache(key, &value).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let result = caching.get_cache::<String>(key).await?;
+        let expected = None;
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_next_block() -> Result<(), NovaXError> {
+        set_mock_time(Duration::from_secs(3));
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+        let key = 1;
+
+        caching.set_cache(key, &"test".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(5));
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+
+        set_mock_time(Duration::from_secs(6));
+        assert_eq!(caching.get_cache::<String>(key).await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_from_another_instance() -> Result<(), NovaXError> {
+        let directory = get_test_directory();
+        let key = 1;
+
+        {
+            let caching = CachingFile::new(directory.clone(), CachingDurationStrategy::Duration(Duration::from_secs(10))).await.unwrap();
+            caching.set_cache(key, &"test".to_string()).await?;
+        }
+
+        set_mock_time(Duration::from_secs(9));
+
+        let caching = CachingFile::new(directory, CachingDurationStrategy::EachBlock).await.unwrap();
+        let result = caching.get_cache::<String>(key).await?;
+        let expected = Some("test".to_string());
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_corrupted_file() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+        let key = 1;
+
+        tokio::fs::write(caching.get_entry_path(key), [0u8, 1u8]).await.unwrap();
+
+        let result = caching.get_cache::<String>(key).await.unwrap_err();
+        let expected = NovaXError::Caching(CachingError::UnableToDeserialize);
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_without_previous_value() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+        let key = 1;
+
+        let result = caching.get_or_set_cache(
+            key,
+            async {
+                Ok::<_, NovaXError>("test".to_string())
+            }
+        ).await?;
+
+        let expected = "test".to_string();
+
+        assert_eq!(result, expected);
+        assert_eq!(caching.get_cache::<String>(key).await?, Some(expected));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_with_previous_value() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        let result = caching.get_or_set_cache::<String, _, NovaXError>(
+            key,
+            async {
+                panic!("should not be executed");
+            }
+        ).await?;
+
+        let expected = "old value".to_string();
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_clear() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::EachBlock).await;
+
+        caching.set_cache(1, &"test".to_string()).await?;
+        caching.set_cache(2, &"test2".to_string()).await?;
+        caching.clear().await?;
+
+        assert_eq!(caching.get_cache::<String>(1).await?, None);
+        assert_eq!(caching.get_cache::<String>(2).await?, None);
+        assert_eq!(get_file_count(&caching).await, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_perform_cleanup_one_before_and_after_expiration() -> Result<(), NovaXError> {
+        let caching = new_caching(CachingDurationStrategy::Duration(Duration::from_secs(10))).await;
+
+        caching
+            .with_duration_strategy(CachingDurationStrategy::Duration(Duration::from_secs(100)))
+            .set_cache(1, &"test1".to_string())
+            .await?;
+
+        caching.set_cache(2, &"test2".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        caching.perform_cleanup().await?;
+
+        assert_eq!(get_file_count(&caching).await, 1);
+        assert_eq!(caching.get_cache::<String>(1).await?, Some("test1".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_as_second_tier_of_caching_multi() -> Result<(), NovaXError> {
+        let directory = get_test_directory();
+        let key = 1;
+
+        let first_run = CachingMulti::new(
+            CachingLocal::empty(CachingDurationStrategy::EachBlock),
+            CachingFile::new(directory.clone(), CachingDurationStrategy::EachBlock).await.unwrap()
+        );
+
+        first_run.set_cache(key, &"test".to_string()).await?;
+
+        let first_caching = CachingLocal::empty(CachingDurationStrategy::EachBlock);
+        let second_run = CachingMulti::new(
+            first_caching.clone(),
+            CachingFile::new(directory, CachingDurationStrategy::EachBlock).await.unwrap()
+        );
+
+        let result = second_run.get_or_set_cache::<String, _, NovaXError>(key, async {
+            panic!()
+        }).await?;
+
+        let expected = "test".to_string();
+
+        assert_eq!(result, expected);
+        assert_eq!(first_caching.get_cache::<String>(key).await?, Some(expected));
+
+        Ok(())
+    }
+}
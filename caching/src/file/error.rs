@@ -0,0 +1,51 @@
+use novax::errors::CachingError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub enum CachingFileError {
+    CannotCreateDirectory { description: String },
+    CannotReadValue { description: String },
+    CannotWriteValue { description: String },
+    CannotRemoveValue { description: String }
+}
+
+impl CachingFileError {
+    pub fn get_description(&self) -> String {
+        match self {
+            CachingFileError::CannotCreateDirectory { description } => {
+                format!("Cannot create the cache directory: {description}")
+            }
+            CachingFileError::CannotReadValue { description } => {
+                format!("Cannot read the value from the cache directory: {description}")
+            }
+            CachingFileError::CannotWriteValue { description } => {
+                format!("Cannot write the value to the cache directory: {description}")
+            }
+            CachingFileError::CannotRemoveValue { description } => {
+                format!("Cannot remove the value from the cache directory: {description}")
+            }
+        }
+    }
+    pub fn get_type(&self) -> String {
+        "CachingFileError".to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            CachingFileError::CannotCreateDirectory { .. } => 0,
+            CachingFileError::CannotReadValue { .. } => 1,
+            CachingFileError::CannotWriteValue { .. } => 2,
+            CachingFileError::CannotRemoveValue { .. } => 3
+        }
+    }
+}
+
+impl From<CachingFileError> for CachingError {
+    fn from(value: CachingFileError) -> Self {
+        CachingError::OtherError {
+            description: value.get_description(),
+            code: value.get_code(),
+            type_name: value.get_type(),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod caching_file;
+pub mod error;
\ No newline at end of file
@@ -1,6 +1,11 @@
 pub mod local;
 pub(crate) mod date;
+pub mod clock;
 pub mod multi;
 pub mod locked;
+pub mod stale;
 pub mod redis;
+pub mod file;
+pub mod metrics;
+pub mod serialization;
 pub mod utils;
@@ -11,89 +11,114 @@ use tokio::sync::{Mutex, RwLock};
 use tokio::task;
 
 use novax::caching::{CachingDurationStrategy, CachingStrategy};
-use novax::errors::CachingError;
 use novax::errors::NovaXError;
 
 use crate::date::get_current_timestamp::{get_current_timestamp, GetDuration};
+use crate::local::eviction::{CachingLocalLimits, CachingLocalStats, LruState};
+use crate::serialization::envelope::{decode_envelope, encode_envelope};
+use crate::serialization::format::CachingSerializationFormat;
 use crate::utils::lock::{Locker, MutexLike};
 
-pub type CachingLocal = BaseCachingLocal<RwLock<Vec<u8>>, RwLock<HashMap<u64, RwLock<Vec<u8>>>>, RwLock<Duration>, RwLock<HashMap<u64, RwLock<Duration>>>, Mutex<Duration>, Mutex<bool>>;
+pub type CachingLocal = BaseCachingLocal<RwLock<Vec<u8>>, RwLock<HashMap<u64, RwLock<Vec<u8>>>>, RwLock<Duration>, RwLock<HashMap<u64, RwLock<Duration>>>, Mutex<Duration>, Mutex<bool>, Mutex<LruState>>;
 
-pub struct BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted>
+pub struct BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState>
 where
     LockerValue: Locker<T = Vec<u8>> + Debug,
     LockerValueHashMap: Locker<T = HashMap<u64, LockerValue>> + Debug,
     LockerExpiration: Locker<T = Duration> + Debug,
     LockerExpirationHashMap: Locker<T = HashMap<u64, LockerExpiration>> + Debug,
     MutexCleanupInterval: MutexLike<T = Duration> + Debug,
-    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug
+    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug,
+    MutexLruState: MutexLike<T = LruState> + Debug
 {
     duration_strategy: CachingDurationStrategy,
+    serialization_format: CachingSerializationFormat,
     value_map: Arc<LockerValueHashMap>,
     expiration_timestamp_map: Arc<LockerExpirationHashMap>,
     cleanup_interval: Arc<MutexCleanupInterval>,
     is_cleanup_process_started: Arc<MutexIsCleanupProcessStarted>,
+    lru_state: Arc<MutexLruState>,
 }
 
-impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted> Clone for BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted>
+impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState> Clone for BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState>
 where
     LockerValue: Locker<T = Vec<u8>> + Debug,
     LockerValueHashMap: Locker<T = HashMap<u64, LockerValue>> + Debug,
     LockerExpiration: Locker<T = Duration> + Debug,
     LockerExpirationHashMap: Locker<T = HashMap<u64, LockerExpiration>> + Debug,
     MutexCleanupInterval: MutexLike<T = Duration> + Debug,
-    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug
+    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug,
+    MutexLruState: MutexLike<T = LruState> + Debug
 {
     fn clone(&self) -> Self {
         Self {
             duration_strategy: self.duration_strategy.clone(),
+            serialization_format: self.serialization_format,
             value_map: self.value_map.clone(),
             expiration_timestamp_map: self.expiration_timestamp_map.clone(),
             cleanup_interval: self.cleanup_interval.clone(),
             is_cleanup_process_started: self.is_cleanup_process_started.clone(),
+            lru_state: self.lru_state.clone(),
         }
     }
 }
 
-impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted> Debug for BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted>
+impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState> Debug for BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState>
 where
     LockerValue: Locker<T = Vec<u8>> + Debug,
     LockerValueHashMap: Locker<T = HashMap<u64, LockerValue>> + Debug,
     LockerExpiration: Locker<T = Duration> + Debug,
     LockerExpirationHashMap: Locker<T = HashMap<u64, LockerExpiration>> + Debug,
     MutexCleanupInterval: MutexLike<T = Duration> + Debug,
-    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug
+    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug,
+    MutexLruState: MutexLike<T = LruState> + Debug
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("BaseCachingLocal")
             .field("duration_strategy", &self.duration_strategy)
+            .field("serialization_format", &self.serialization_format)
             .field("value_map", &self.value_map)
             .field("expiration_timestamp_map", &self.expiration_timestamp_map)
             .field("cleanup_interval", &self.cleanup_interval)
             .field("is_cleanup_process_started", &self.is_cleanup_process_started)
+            .field("lru_state", &self.lru_state)
             .finish()
     }
 }
 
-impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted> BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted>
+impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState> BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState>
 where
     LockerValue: Locker<T = Vec<u8>> + Debug,
     LockerValueHashMap: Locker<T = HashMap<u64, LockerValue>> + Debug,
     LockerExpiration: Locker<T = Duration> + Debug,
     LockerExpirationHashMap: Locker<T = HashMap<u64, LockerExpiration>> + Debug,
     MutexCleanupInterval: MutexLike<T = Duration> + Debug,
-    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug
+    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug,
+    MutexLruState: MutexLike<T = LruState> + Debug
 {
     pub fn empty(duration_strategy: CachingDurationStrategy) -> Self {
+        Self::empty_with_limits(duration_strategy, CachingLocalLimits::unbounded())
+    }
+
+    /// Creates an empty cache which evicts the least recently used entries once one of the `limits` is exceeded.
+    pub fn empty_with_limits(duration_strategy: CachingDurationStrategy, limits: CachingLocalLimits) -> Self {
         BaseCachingLocal {
             duration_strategy,
+            serialization_format: CachingSerializationFormat::default(),
             value_map: Arc::new(LockerValueHashMap::new(HashMap::new())),
             expiration_timestamp_map: Arc::new(LockerExpirationHashMap::new(HashMap::new())),
             cleanup_interval: Arc::new(MutexCleanupInterval::new(Duration::from_secs(0))),
             is_cleanup_process_started: Arc::new(MutexIsCleanupProcessStarted::new(false)),
+            lru_state: Arc::new(MutexLruState::new(LruState::new(limits))),
         }
     }
 
+    /// Sets the format used to serialize the values, MessagePack by default.
+    pub fn with_serialization_format(mut self, serialization_format: CachingSerializationFormat) -> Self {
+        self.serialization_format = serialization_format;
+        self
+    }
+
     async fn remove_key(&self, key: u64) {
         let contains_key = {
             let expiration_timestamp_read_guard = self.expiration_timestamp_map.read().await;
@@ -106,6 +131,7 @@ where
 
             expiration_write_guard.remove(&key);
             value_map_write_guard.remove(&key);
+            self.lru_state.lock().await.remove(key);
         }
     }
 
@@ -116,8 +142,37 @@ where
         };
         
         let expiration_timestamp = self.duration_strategy.get_duration_timestamp(&get_current_timestamp()?)?;
-        let Ok(serialized) = rmp_serde::to_vec(value) else { return Err(CachingError::UnableToSerialize.into())};
-        
+        let serialized = encode_envelope(self.serialization_format, value)?;
+        let serialized_size = serialized.len();
+
+        let is_too_large = self.lru_state.lock().await.is_too_large(serialized_size);
+        if is_too_large {
+            // Caching this value would evict every other entry without fitting anyway.
+            self.remove_key(key).await;
+            return Ok(());
+        }
+
+        let Some(serialized) = self.set_existing_value(key, expiration_timestamp, serialized, contains_key).await else {
+            self.record_set(key, serialized_size).await;
+            return Ok(());
+        };
+
+        {
+            // The key is not found, we have to lock everything.
+            let mut expiration_map_write_guard = self.expiration_timestamp_map.write().await;
+            let mut value_map_write_guard = self.value_map.write().await;
+            expiration_map_write_guard.insert(key, LockerExpiration::new(expiration_timestamp));
+            value_map_write_guard.insert(key, LockerValue::new(serialized));
+        }
+
+        self.record_set(key, serialized_size).await;
+
+        Ok(())
+    }
+
+    /// Replaces the value of a key already present in the cache.
+    /// If the key is not found, the serialized value is given back to be inserted by the caller.
+    async fn set_existing_value(&self, key: u64, expiration_timestamp: Duration, serialized: Vec<u8>, contains_key: bool) -> Option<Vec<u8>> {
         if contains_key {
             let expiration_timestamp_map_read_guard = self.expiration_timestamp_map.read().await;
             // Important: the key might have been removed since the contains_key assignment.
@@ -133,18 +188,47 @@ where
                     *expiration_timestamp_write = expiration_timestamp;
                     *value_write = serialized;
 
-                    return Ok(());
+                    return None;
                 };
             };
         }
 
-        // The key is not found, we have to lock everything.
+        Some(serialized)
+    }
+
+    async fn record_set(&self, key: u64, size: usize) {
+        let is_over_limits = {
+            let mut lru_state = self.lru_state.lock().await;
+            lru_state.record_set(key, size);
+            lru_state.is_over_limits()
+        };
+
+        if is_over_limits {
+            self.evict().await;
+        }
+    }
+
+    async fn evict(&self) {
+        // The maps have to be locked before the LRU state to avoid deadlocks.
         let mut expiration_map_write_guard = self.expiration_timestamp_map.write().await;
         let mut value_map_write_guard = self.value_map.write().await;
-        expiration_map_write_guard.insert(key, LockerExpiration::new(expiration_timestamp));
-        value_map_write_guard.insert(key, LockerValue::new(serialized));
+        let mut lru_state = self.lru_state.lock().await;
 
-        Ok(())
+        for key in lru_state.evict() {
+            expiration_map_write_guard.remove(&key);
+            value_map_write_guard.remove(&key);
+        }
+    }
+
+    /// Set the size limits for self and all the cloned instances, evicting entries if the new limits are exceeded.
+    pub async fn set_limits(&self, limits: CachingLocalLimits) {
+        self.lru_state.lock().await.set_limits(limits);
+        self.evict().await;
+    }
+
+    /// Returns the current size of the cache, shared by self and all the cloned instances.
+    pub async fn get_stats(&self) -> CachingLocalStats {
+        self.lru_state.lock().await.stats()
     }
 
     /// Set the cleanup duration for self and all the cloned instances.
@@ -235,6 +319,7 @@ impl CachingLocal
             if should_remove {
                 value_map_write_guard.remove(&key);
                 expiration_map_write_guard.remove(&key);
+                self.lru_state.lock().await.remove(key);
             }
         }
 
@@ -243,14 +328,15 @@ impl CachingLocal
 }
 
 #[async_trait]
-impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted> CachingStrategy for BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted>
+impl<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState> CachingStrategy for BaseCachingLocal<LockerValue, LockerValueHashMap, LockerExpiration, LockerExpirationHashMap, MutexCleanupInterval, MutexIsCleanupProcessStarted, MutexLruState>
 where
     LockerValue: Locker<T = Vec<u8>> + Debug,
     LockerValueHashMap: Locker<T = HashMap<u64, LockerValue>> + Debug,
     LockerExpiration: Locker<T = Duration> + Debug,
     LockerExpirationHashMap: Locker<T = HashMap<u64, LockerExpiration>> + Debug,
     MutexCleanupInterval: MutexLike<T = Duration> + Debug,
-    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug
+    MutexIsCleanupProcessStarted: MutexLike<T = bool> + Debug,
+    MutexLruState: MutexLike<T = LruState> + Debug
 {
     async fn get_cache<T: Serialize + DeserializeOwned + Send>(&self, key: u64) -> Result<Option<T>, NovaXError> {
         {
@@ -277,10 +363,18 @@ where
 
         let encoded_value = encoded_value_locked.read().await;
 
-        let Ok(value) = rmp_serde::from_slice(&encoded_value) else {
-            return Err(CachingError::UnableToDeserialize.into())
+        let value = decode_envelope(&encoded_value)?;
+
+        drop(encoded_value);
+        drop(value_map_read_guard);
+
+        let Some(value) = value else {
+            // Written for another type or schema
+            return Ok(None);
         };
 
+        self.lru_state.lock().await.record_access(key);
+
         Ok(Some(value))
     }
 
@@ -309,6 +403,7 @@ where
 
         expiration_map_write_guard.clear();
         value_map_write_guard.clear();
+        self.lru_state.lock().await.clear();
 
         Ok(())
     }
@@ -316,10 +411,12 @@ where
     fn with_duration_strategy(&self, strategy: CachingDurationStrategy) -> Self {
         Self {
             duration_strategy: strategy,
+            serialization_format: self.serialization_format,
             value_map: self.value_map.clone(),
             expiration_timestamp_map: self.expiration_timestamp_map.clone(),
             cleanup_interval: self.cleanup_interval.clone(),
-            is_cleanup_process_started: self.is_cleanup_process_started.clone()
+            is_cleanup_process_started: self.is_cleanup_process_started.clone(),
+            lru_state: self.lru_state.clone()
         }
     }
 }
@@ -333,6 +430,7 @@ mod test {
 
     use crate::date::get_current_timestamp::set_mock_time;
     use crate::local::caching_local::CachingLocal;
+    use crate::local::eviction::{CachingLocalLimits, CachingLocalStats};
 
     #[tokio::test]
     async fn test_get_cache_key_not_found() -> Result<(), NovaXError> {
@@ -615,4 +713,124 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_max_entries_evicts_least_recently_used() -> Result<(), NovaXError> {
+        let caching = CachingLocal::empty_with_limits(
+            CachingDurationStrategy::EachBlock,
+            CachingLocalLimits::unbounded().with_max_entries(2)
+        );
+
+        caching.set_cache(1, &"test1".to_string()).await?;
+        caching.set_cache(2, &"test2".to_string()).await?;
+        caching.get_cache::<String>(1).await?;
+        caching.set_cache(3, &"test3".to_string()).await?;
+
+        assert_eq!(caching.get_cache::<String>(1).await?, Some("test1".to_string()));
+        assert_eq!(caching.get_cache::<String>(2).await?, None);
+        assert_eq!(caching.get_cache::<String>(3).await?, Some("test3".to_string()));
+        assert_eq!(caching.value_map.read().await.len(), 2);
+        assert_eq!(caching.expiration_timestamp_map.read().await.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_bytes_evicts_least_recently_used() -> Result<(), NovaXError> {
+        // "test1" is serialized into 6 bytes, plus the 13 bytes of the envelope header
+        let caching = CachingLocal::empty_with_limits(
+            CachingDurationStrategy::EachBlock,
+            CachingLocalLimits::unbounded().with_max_bytes(38)
+        );
+
+        caching.set_cache(1, &"test1".to_string()).await?;
+        caching.set_cache(2, &"test2".to_string()).await?;
+        caching.set_cache(3, &"test3".to_string()).await?;
+
+        let result = caching.get_stats().await;
+        let expected = CachingLocalStats {
+            entries: 2,
+            bytes: 38,
+            evictions: 1,
+        };
+
+        assert_eq!(result, expected);
+        assert_eq!(caching.get_cache::<String>(1).await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_value_larger_than_max_bytes_is_not_cached() -> Result<(), NovaXError> {
+        let caching = CachingLocal::empty_with_limits(
+            CachingDurationStrategy::EachBlock,
+            CachingLocalLimits::unbounded().with_max_bytes(25)
+        );
+
+        caching.set_cache(1, &"test1".to_string()).await?;
+        caching.set_cache(2, &"a too large value".to_string()).await?;
+
+        assert_eq!(caching.get_cache::<String>(1).await?, Some("test1".to_string()));
+        assert_eq!(caching.get_cache::<String>(2).await?, None);
+        assert_eq!(caching.get_stats().await.evictions, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_limits_evicts_exceeding_entries() -> Result<(), NovaXError> {
+        let caching = CachingLocal::empty(CachingDurationStrategy::EachBlock);
+
+        caching.set_cache(1, &"test1".to_string()).await?;
+        caching.set_cache(2, &"test2".to_string()).await?;
+        caching.set_cache(3, &"test3".to_string()).await?;
+
+        caching
+            .with_duration_strategy(CachingDurationStrategy::Duration(Duration::from_secs(10)))
+            .set_limits(CachingLocalLimits::unbounded().with_max_entries(1))
+            .await;
+
+        assert_eq!(caching.get_stats().await.entries, 1);
+        assert_eq!(caching.get_cache::<String>(3).await?, Some("test3".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stats_after_overwrite_and_clear() -> Result<(), NovaXError> {
+        let caching = CachingLocal::empty(CachingDurationStrategy::EachBlock);
+
+        caching.set_cache(1, &"test1".to_string()).await?;
+        caching.set_cache(1, &"test".to_string()).await?;
+
+        let result = caching.get_stats().await;
+        let expected = CachingLocalStats {
+            entries: 1,
+            bytes: 18,
+            evictions: 0,
+        };
+
+        assert_eq!(result, expected);
+
+        caching.clear().await?;
+
+        assert_eq!(caching.get_stats().await, CachingLocalStats::default());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_perform_cleanup_updates_stats() -> Result<(), NovaXError> {
+        let caching = CachingLocal::empty(CachingDurationStrategy::Duration(Duration::from_secs(10)));
+
+        caching.set_cache(1, &"test1".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        caching.perform_cleanup().await?;
+
+        assert_eq!(caching.get_stats().await, CachingLocalStats::default());
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,231 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// The size limits of a `CachingLocal` instance.
+///
+/// When a limit is exceeded, the least recently used entries are evicted until the cache fits again.
+/// A `None` limit means unbounded.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct CachingLocalLimits {
+    /// The maximum number of entries kept in the cache.
+    pub max_entries: Option<usize>,
+    /// The maximum total size, in bytes, of the serialized values kept in the cache.
+    pub max_bytes: Option<usize>,
+}
+
+impl CachingLocalLimits {
+    pub fn unbounded() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+}
+
+/// A snapshot of the current size of a `CachingLocal` instance.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct CachingLocalStats {
+    /// The number of entries currently stored, expired ones not yet cleaned up included.
+    pub entries: usize,
+    /// The total size, in bytes, of the serialized values currently stored.
+    pub bytes: usize,
+    /// The number of entries evicted because a limit was exceeded, since the creation of the cache.
+    pub evictions: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct EntryUsage {
+    last_access: u64,
+    size: usize,
+}
+
+/// Keeps track of the entries' sizes and access order to perform LRU eviction.
+#[derive(Default, Debug)]
+pub struct LruState {
+    limits: CachingLocalLimits,
+    entries: HashMap<u64, EntryUsage>,
+    access_order: BTreeMap<u64, u64>,
+    next_access: u64,
+    bytes: usize,
+    evictions: u64,
+}
+
+impl LruState {
+    pub fn new(limits: CachingLocalLimits) -> Self {
+        LruState {
+            limits,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_limits(&mut self, limits: CachingLocalLimits) {
+        self.limits = limits;
+    }
+
+    pub fn stats(&self) -> CachingLocalStats {
+        CachingLocalStats {
+            entries: self.entries.len(),
+            bytes: self.bytes,
+            evictions: self.evictions,
+        }
+    }
+
+    /// Returns `true` if a value of `size` bytes can never fit in the cache, whatever is evicted.
+    pub fn is_too_large(&self, size: usize) -> bool {
+        matches!(self.limits.max_bytes, Some(max_bytes) if size > max_bytes)
+    }
+
+    pub fn is_over_limits(&self) -> bool {
+        let too_many_entries = matches!(self.limits.max_entries, Some(max_entries) if self.entries.len() > max_entries);
+        let too_many_bytes = matches!(self.limits.max_bytes, Some(max_bytes) if self.bytes > max_bytes);
+
+        too_many_entries || too_many_bytes
+    }
+
+    /// Records a newly set value, marking it as the most recently used entry.
+    pub fn record_set(&mut self, key: u64, size: usize) {
+        self.remove(key);
+
+        let last_access = self.next_access();
+        self.entries.insert(key, EntryUsage { last_access, size });
+        self.access_order.insert(last_access, key);
+        self.bytes += size;
+    }
+
+    /// Marks an existing entry as the most recently used one.
+    pub fn record_access(&mut self, key: u64) {
+        let Some(usage) = self.entries.get(&key).copied() else {
+            return;
+        };
+
+        self.access_order.remove(&usage.last_access);
+        let last_access = self.next_access();
+        self.access_order.insert(last_access, key);
+        self.entries.insert(key, EntryUsage { last_access, size: usage.size });
+    }
+
+    pub fn remove(&mut self, key: u64) {
+        if let Some(usage) = self.entries.remove(&key) {
+            self.access_order.remove(&usage.last_access);
+            self.bytes -= usage.size;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.access_order.clear();
+        self.bytes = 0;
+    }
+
+    /// Removes the least recently used entries until the limits are respected, and returns their keys.
+    pub fn evict(&mut self) -> Vec<u64> {
+        let mut evicted = vec![];
+
+        while self.is_over_limits() {
+            let Some((_, key)) = self.access_order.pop_first() else {
+                break;
+            };
+
+            if let Some(usage) = self.entries.remove(&key) {
+                self.bytes -= usage.size;
+            }
+
+            self.evictions += 1;
+            evicted.push(key);
+        }
+
+        evicted
+    }
+
+    fn next_access(&mut self) -> u64 {
+        let access = self.next_access;
+        self.next_access += 1;
+
+        access
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::local::eviction::{CachingLocalLimits, CachingLocalStats, LruState};
+
+    #[test]
+    fn test_evict_no_limits() {
+        let mut state = LruState::new(CachingLocalLimits::unbounded());
+        state.record_set(1, 10);
+        state.record_set(2, 10);
+
+        assert!(state.evict().is_empty());
+        assert_eq!(state.stats(), CachingLocalStats { entries: 2, bytes: 20, evictions: 0 });
+    }
+
+    #[test]
+    fn test_evict_max_entries_least_recently_set() {
+        let mut state = LruState::new(CachingLocalLimits::unbounded().with_max_entries(2));
+        state.record_set(1, 10);
+        state.record_set(2, 10);
+        state.record_set(3, 10);
+
+        assert_eq!(state.evict(), vec![1]);
+        assert_eq!(state.stats(), CachingLocalStats { entries: 2, bytes: 20, evictions: 1 });
+    }
+
+    #[test]
+    fn test_evict_max_entries_least_recently_accessed() {
+        let mut state = LruState::new(CachingLocalLimits::unbounded().with_max_entries(2));
+        state.record_set(1, 10);
+        state.record_set(2, 10);
+        state.record_access(1);
+        state.record_set(3, 10);
+
+        assert_eq!(state.evict(), vec![2]);
+    }
+
+    #[test]
+    fn test_evict_max_bytes() {
+        let mut state = LruState::new(CachingLocalLimits::unbounded().with_max_bytes(25));
+        state.record_set(1, 10);
+        state.record_set(2, 10);
+        state.record_set(3, 10);
+
+        assert_eq!(state.evict(), vec![1]);
+        assert_eq!(state.stats(), CachingLocalStats { entries: 2, bytes: 20, evictions: 1 });
+    }
+
+    #[test]
+    fn test_record_set_existing_key_replaces_size() {
+        let mut state = LruState::new(CachingLocalLimits::unbounded());
+        state.record_set(1, 10);
+        state.record_set(1, 4);
+
+        assert_eq!(state.stats(), CachingLocalStats { entries: 1, bytes: 4, evictions: 0 });
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        let mut state = LruState::new(CachingLocalLimits::unbounded());
+        state.record_set(1, 10);
+        state.record_set(2, 10);
+        state.remove(1);
+
+        assert_eq!(state.stats(), CachingLocalStats { entries: 1, bytes: 10, evictions: 0 });
+
+        state.clear();
+
+        assert_eq!(state.stats(), CachingLocalStats::default());
+    }
+
+    #[test]
+    fn test_is_too_large() {
+        let state = LruState::new(CachingLocalLimits::unbounded().with_max_bytes(10));
+
+        assert!(!state.is_too_large(10));
+        assert!(state.is_too_large(11));
+    }
+}
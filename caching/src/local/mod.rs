@@ -1 +1,2 @@
-pub mod caching_local;
\ No newline at end of file
+pub mod caching_local;
+pub mod eviction;
\ No newline at end of file
@@ -130,6 +130,22 @@ where
         result
     }
 
+    /// The lock is only held until the wrapped strategy returns, a value refreshed in the background isn't awaited while holding it.
+    async fn get_or_refresh_cache_in_background<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        FutureGetter: Future<Output=Result<T, Error>> + Send + 'static,
+        Error: From<NovaXError> + Send + 'static
+    {
+        let locker = self.get_locker(key).await?;
+        let lock_value = locker.write().await;
+
+        let result = self.caching.get_or_refresh_cache_in_background(key, getter).await;
+
+        drop(lock_value);
+        result
+    }
+
     async fn clear(&self) -> Result<(), NovaXError> {
         self.caching.clear().await
     }
@@ -156,6 +172,7 @@ mod test {
     use crate::date::get_current_timestamp::set_mock_time;
     use crate::local::caching_local::CachingLocal;
     use crate::locked::caching::CachingLocked;
+    use crate::stale::caching::CachingStale;
 
     #[derive(Clone, Debug)]
     struct CachingLocalDelayedSet {
@@ -544,4 +561,39 @@ mod test {
         assert_eq!(get_or_set_cache_result, expected);
         assert_eq!(get_cache_result, expected);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_get_or_refresh_cache_in_background_serves_stale_value() -> Result<(), NovaXError> {
+        let caching = CachingLocked::new(
+            CachingStale::new(
+                CachingLocal::empty(CachingDurationStrategy::EachBlock),
+                CachingDurationStrategy::Duration(Duration::from_secs(10)),
+                Duration::from_secs(20)
+            )
+        );
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let (sender, receiver) = tokio::sync::oneshot::channel::<String>();
+
+        // The getter only completes once the caller got its result
+        let result = caching.get_or_refresh_cache_in_background(key, async {
+            Ok::<_, NovaXError>(receiver.await.unwrap())
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        // The lock isn't held by the refresh
+        assert_eq!(caching.get_cache::<String>(key).await?, None);
+
+        sender.send("test".to_string()).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+
+        Ok(())
+    }
+}
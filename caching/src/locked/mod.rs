@@ -1 +1,2 @@
-pub mod caching;
\ No newline at end of file
+pub mod caching;
+pub mod redis;
\ No newline at end of file
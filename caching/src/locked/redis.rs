@@ -0,0 +1,598 @@
+use std::future::Future;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use novax::caching::{CachingDurationStrategy, CachingStrategy};
+use novax::errors::{CachingError, NovaXError};
+
+use crate::date::get_current_timestamp::{get_current_timestamp, GetDuration};
+use crate::redis::caching_redis::BaseCachingRedis;
+use crate::redis::client::ReconnectingRedisClient;
+use crate::redis::error::CachingRedisError;
+use crate::redis::lock::RedisLockClient;
+use crate::redis::IntoConnectionInfo;
+use crate::serialization::envelope::encode_envelope;
+
+const DEFAULT_LEASE_DURATION: Duration = Duration::from_secs(10);
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[allow(type_alias_bounds)]
+pub type CachingLockedRedis<C: FencedCachingStrategy> = BaseCachingLockedRedis<C, ReconnectingRedisClient>;
+
+/// A caching strategy able to write a value only if a Redis lock is still held, atomically.
+#[async_trait]
+pub trait FencedCachingStrategy: CachingStrategy {
+    /// Caches the value if the lock is still held with `token`, returns `false` if the lock has been lost.
+    async fn set_cache_if_lock_held<T: Serialize + DeserializeOwned + Send + Sync>(&self, lock_key: &str, token: u64, key: u64, value: &T) -> Result<bool, NovaXError>;
+
+    /// Returns the cached value even if it is outdated, e.g. a stale value kept by a `CachingStale`.
+    async fn get_stale_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64) -> Result<Option<T>, NovaXError> {
+        self.get_cache(key).await
+    }
+}
+
+#[async_trait]
+impl<Client: RedisLockClient> FencedCachingStrategy for BaseCachingRedis<Client> {
+    async fn set_cache_if_lock_held<T: Serialize + DeserializeOwned + Send + Sync>(&self, lock_key: &str, token: u64, key: u64, value: &T) -> Result<bool, NovaXError> {
+        let duration = self.duration_strategy.get_duration_from_now(&get_current_timestamp()?)?.as_secs();
+
+        // Like `set_cache`, a value expiring now isn't written.
+        if duration == 0 {
+            return Ok(true);
+        }
+
+        let encoded = encode_envelope(self.serialization_format, value)?;
+
+        self.client
+            .set_if_lock_held(lock_key, token, key, encoded, duration)
+            .await
+            .map_err(to_novax_error)
+    }
+}
+
+/// A caching strategy wrapper deduplicating the concurrent identical queries across processes, using a Redis lock.
+///
+/// On a cache miss, only the process acquiring the lock runs the getter, renewing its lease until the getter completes.
+/// The other ones poll the wrapped caching strategy until the value is set, or try to acquire the lock again if it has been released without a value.
+/// The wrapped caching strategy must therefore be shared between the processes: a `CachingRedis` storing its values in the Redis holding the locks.
+///
+/// Each acquisition is identified by a fencing token: a holder which lost its lock, e.g. because its lease expired, doesn't write its value to the cache.
+/// The token is checked by the same script writing the value, so the lock can't be lost between the check and the write.
+#[derive(Clone, Debug)]
+pub struct BaseCachingLockedRedis<C, Client>
+where
+    C: FencedCachingStrategy,
+    Client: RedisLockClient
+{
+    pub caching: C,
+    pub(crate) client: Client,
+    pub lease_duration: Duration,
+    pub poll_interval: Duration
+}
+
+/// Releases the lock when dropped, including when the getter fails or the future is cancelled.
+struct LockGuard<Client: RedisLockClient> {
+    client: Client,
+    lock_key: String,
+    token: u64,
+    is_released: bool
+}
+
+impl<Client: RedisLockClient> LockGuard<Client> {
+    async fn release(mut self) -> Result<(), CachingRedisError> {
+        self.is_released = true;
+        self.client.release_lock(&self.lock_key, self.token).await?;
+
+        Ok(())
+    }
+}
+
+impl<Client: RedisLockClient> Drop for LockGuard<Client> {
+    fn drop(&mut self) {
+        if self.is_released {
+            return;
+        }
+
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            // The lock will be released once its lease expires.
+            return;
+        };
+
+        let client = self.client.clone();
+        let lock_key = std::mem::take(&mut self.lock_key);
+        let token = self.token;
+
+        handle.spawn(async move {
+            let _ = client.release_lock(&lock_key, token).await;
+        });
+    }
+}
+
+impl<C, Client> BaseCachingLockedRedis<C, Client>
+where
+    C: FencedCachingStrategy,
+    Client: RedisLockClient
+{
+    pub async fn new<Info: IntoConnectionInfo + Send + Sync>(
+        caching: C,
+        info: Info
+    ) -> Result<Self, CachingRedisError> {
+        let client = Client::open(info).await?;
+
+        Ok(Self::from_client(caching, client))
+    }
+
+    /// Uses an already opened client, e.g. a `ClusterRedisClient` connected to several nodes.
+    pub fn from_client(caching: C, client: Client) -> Self {
+        BaseCachingLockedRedis {
+            caching,
+            client,
+            lease_duration: DEFAULT_LEASE_DURATION,
+            poll_interval: DEFAULT_POLL_INTERVAL
+        }
+    }
+
+    /// Sets the duration after which a lock expires if its holder stops renewing it, e.g. because the process crashed.
+    pub fn with_lease_duration(mut self, lease_duration: Duration) -> Self {
+        self.lease_duration = lease_duration;
+        self
+    }
+
+    /// Sets the interval at which the waiting processes check whether the value has been cached.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The key is hash tagged with the cached key, so both are in the same hash slot on a cluster, as required by the fenced write.
+    fn get_lock_key(key: u64) -> String {
+        format!("novax:lock:{{{key}}}")
+    }
+
+    /// Renews the lease periodically, only returns once the lock has been lost.
+    async fn keep_lease(&self, lock_key: &str, token: u64) {
+        loop {
+            tokio::time::sleep(self.lease_duration / 3).await;
+
+            if !matches!(self.client.renew_lock(lock_key, token, self.lease_duration).await, Ok(true)) {
+                return;
+            }
+        }
+    }
+
+    async fn get_or_set_cache_holding_lock<T, FutureGetter, Error>(&self, key: u64, lock_guard: LockGuard<Client>, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        FutureGetter: Future<Output=Result<T, Error>> + Send,
+        Error: From<NovaXError>
+    {
+        // The previous holder might have set the value between our cache check and the lock acquisition.
+        if let Some(cached_value) = self.caching.get_cache(key).await? {
+            lock_guard.release().await.map_err(to_novax_error)?;
+            return Ok(cached_value);
+        }
+
+        let keep_lease = async {
+            self.keep_lease(&lock_guard.lock_key, lock_guard.token).await;
+
+            // The lock is lost, the getter is still awaited but its value won't be cached.
+            std::future::pending::<()>().await
+        };
+
+        let result = tokio::select! {
+            result = getter => result,
+            _ = keep_lease => unreachable!()
+        };
+
+        let value = result?;
+
+        self.caching.set_cache_if_lock_held(&lock_guard.lock_key, lock_guard.token, key, &value).await?;
+
+        lock_guard.release().await.map_err(to_novax_error)?;
+
+        Ok(value)
+    }
+}
+
+fn to_novax_error(error: CachingRedisError) -> NovaXError {
+    CachingError::from(error).into()
+}
+
+#[async_trait]
+impl<C, Client> CachingStrategy for BaseCachingLockedRedis<C, Client>
+where
+    C: FencedCachingStrategy + 'static,
+    Client: RedisLockClient
+{
+    async fn get_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64) -> Result<Option<T>, NovaXError> {
+        self.caching.get_cache(key).await
+    }
+
+    async fn set_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64, value: &T) -> Result<(), NovaXError> {
+        self.caching.set_cache(key, value).await
+    }
+
+    async fn get_or_set_cache<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        FutureGetter: Future<Output=Result<T, Error>> + Send,
+        Error: From<NovaXError>
+    {
+        if let Some(cached_value) = self.caching.get_cache(key).await? {
+            return Ok(cached_value);
+        }
+
+        let lock_key = Self::get_lock_key(key);
+
+        loop {
+            let token = self.client.next_fencing_token().await.map_err(to_novax_error)?;
+
+            if self.client.try_acquire_lock(&lock_key, token, self.lease_duration).await.map_err(to_novax_error)? {
+                let lock_guard = LockGuard {
+                    client: self.client.clone(),
+                    lock_key,
+                    token,
+                    is_released: false
+                };
+
+                return self.get_or_set_cache_holding_lock(key, lock_guard, getter).await;
+            }
+
+            // Another process is running the getter, let's wait for its value.
+            loop {
+                tokio::time::sleep(self.poll_interval).await;
+
+                if let Some(cached_value) = self.caching.get_cache(key).await? {
+                    return Ok(cached_value);
+                }
+
+                if self.client.get_lock_holder(&lock_key).await.map_err(to_novax_error)?.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// A stale value of the wrapped strategy, e.g. a `CachingStale`, is returned right away,
+    /// the refresh going through `get_or_set_cache` in a spawned task so that only one process runs the getter.
+    async fn get_or_refresh_cache_in_background<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        FutureGetter: Future<Output=Result<T, Error>> + Send + 'static,
+        Error: From<NovaXError> + Send + 'static
+    {
+        if let Some(cached_value) = self.caching.get_cache(key).await? {
+            return Ok(cached_value);
+        }
+
+        let Some(stale_value) = self.caching.get_stale_cache(key).await? else {
+            return self.get_or_set_cache(key, getter).await;
+        };
+
+        let self_value = self.clone();
+        tokio::spawn(async move {
+            let _ = self_value.get_or_set_cache(key, getter).await;
+        });
+
+        Ok(stale_value)
+    }
+
+    async fn clear(&self) -> Result<(), NovaXError> {
+        self.caching.clear().await
+    }
+
+    fn with_duration_strategy(&self, strategy: CachingDurationStrategy) -> Self {
+        BaseCachingLockedRedis {
+            caching: self.caching.with_duration_strategy(strategy),
+            client: self.client.clone(),
+            lease_duration: self.lease_duration,
+            poll_interval: self.poll_interval
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use async_trait::async_trait;
+    use redis::{FromRedisValue, IntoConnectionInfo, ToRedisArgs};
+
+    use novax::caching::{CachingDurationStrategy, CachingStrategy};
+    use novax::errors::{CachingError, NovaXError};
+
+    use crate::date::get_current_timestamp::set_mock_time;
+    use crate::locked::redis::BaseCachingLockedRedis;
+    use crate::redis::caching_redis::BaseCachingRedis;
+    use crate::redis::client::RedisClient;
+    use crate::redis::error::CachingRedisError;
+    use crate::redis::lock::RedisLockClient;
+    use crate::stale::caching::CachingStale;
+
+    #[derive(Default, Debug)]
+    struct MockRedisState {
+        fencing_token: u64,
+        locks: HashMap<String, (u64, Instant)>,
+        values: HashMap<Vec<Vec<u8>>, Vec<u8>>
+    }
+
+    impl MockRedisState {
+        fn get_holder(&mut self, key: &str) -> Option<u64> {
+            let (token, expiration) = *self.locks.get(key)?;
+
+            if Instant::now() >= expiration {
+                self.locks.remove(key);
+                return None;
+            }
+
+            Some(token)
+        }
+    }
+
+    #[derive(Clone, Default, Debug)]
+    struct MockRedisClient {
+        state: Arc<Mutex<MockRedisState>>
+    }
+
+    #[async_trait]
+    impl RedisClient for MockRedisClient {
+        async fn open<Info: IntoConnectionInfo + Send + Sync>(_info: Info) -> Result<Self, CachingRedisError> {
+            Ok(MockRedisClient::default())
+        }
+
+        async fn set<K: ToRedisArgs + Send + Sync, V: ToRedisArgs + Send + Sync>(&self, key: K, value: V, _duration: u64) -> Result<(), CachingRedisError> {
+            self.state.lock().unwrap().values.insert(key.to_redis_args(), value.to_redis_args().concat());
+
+            Ok(())
+        }
+
+        async fn get<K: ToRedisArgs + Send + Sync, RV: FromRedisValue + Send + Sync>(&self, key: K) -> Result<Option<RV>, CachingRedisError> {
+            let state = self.state.lock().unwrap();
+            let Some(value) = state.values.get(&key.to_redis_args()) else {
+                return Ok(None);
+            };
+
+            Ok(RV::from_byte_vec(value).unwrap().into_iter().next())
+        }
+
+        async fn clear(&self) -> Result<(), CachingRedisError> {
+            unreachable!()
+        }
+    }
+
+    #[async_trait]
+    impl RedisLockClient for MockRedisClient {
+        async fn next_fencing_token(&self) -> Result<u64, CachingRedisError> {
+            let mut state = self.state.lock().unwrap();
+            state.fencing_token += 1;
+
+            Ok(state.fencing_token)
+        }
+
+        async fn try_acquire_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+            let mut state = self.state.lock().unwrap();
+
+            if state.get_holder(key).is_some() {
+                return Ok(false);
+            }
+
+            state.locks.insert(key.to_string(), (token, Instant::now() + lease));
+
+            Ok(true)
+        }
+
+        async fn renew_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+            let mut state = self.state.lock().unwrap();
+
+            if state.get_holder(key) != Some(token) {
+                return Ok(false);
+            }
+
+            state.locks.insert(key.to_string(), (token, Instant::now() + lease));
+
+            Ok(true)
+        }
+
+        async fn set_if_lock_held(&self, lock_key: &str, token: u64, key: u64, value: Vec<u8>, _duration: u64) -> Result<bool, CachingRedisError> {
+            let mut state = self.state.lock().unwrap();
+
+            if state.get_holder(lock_key) != Some(token) {
+                return Ok(false);
+            }
+
+            state.values.insert(key.to_redis_args(), value);
+
+            Ok(true)
+        }
+
+        async fn release_lock(&self, key: &str, token: u64) -> Result<bool, CachingRedisError> {
+            let mut state = self.state.lock().unwrap();
+
+            if state.get_holder(key) != Some(token) {
+                return Ok(false);
+            }
+
+            state.locks.remove(key);
+
+            Ok(true)
+        }
+
+        async fn get_lock_holder(&self, key: &str) -> Result<Option<u64>, CachingRedisError> {
+            Ok(self.state.lock().unwrap().get_holder(key))
+        }
+    }
+
+    async fn new_caching(lease_duration: Duration) -> BaseCachingLockedRedis<BaseCachingRedis<MockRedisClient>, MockRedisClient> {
+        let client = MockRedisClient::default();
+        let caching_redis = BaseCachingRedis::from_client(client.clone(), CachingDurationStrategy::Duration(Duration::from_secs(60)));
+
+        BaseCachingLockedRedis::from_client(caching_redis, client)
+            .with_lease_duration(lease_duration)
+            .with_poll_interval(Duration::from_millis(50))
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_with_previous_value() -> Result<(), NovaXError> {
+        let caching = new_caching(Duration::from_secs(10)).await;
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        let result = caching.get_or_set_cache::<String, _, NovaXError>(key, async {
+            panic!("should not be executed");
+        }).await?;
+
+        assert_eq!(result, "old value");
+        assert_eq!(caching.client.state.lock().unwrap().fencing_token, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_without_previous_value() -> Result<(), NovaXError> {
+        let caching = new_caching(Duration::from_secs(10)).await;
+        let key = 1;
+
+        let result = caching.get_or_set_cache(key, async {
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        assert_eq!(result, "test");
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+        assert!(caching.client.state.lock().unwrap().locks.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_single_flight() {
+        let caching = new_caching(Duration::from_secs(10)).await;
+        let key = 1u64;
+        let getter_calls = Arc::new(AtomicU64::new(0));
+
+        let mut handles = vec![];
+        for _ in 0..5 {
+            let caching_cloned = caching.clone();
+            let getter_calls_cloned = getter_calls.clone();
+            handles.push(tokio::spawn(async move {
+                caching_cloned.get_or_set_cache(key, async {
+                    getter_calls_cloned.fetch_add(1, Ordering::Relaxed);
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                    Ok::<_, NovaXError>("test".to_string())
+                }).await
+            }));
+        }
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().unwrap(), "test");
+        }
+
+        assert_eq!(getter_calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_lease_renewed() {
+        let caching = new_caching(Duration::from_millis(150)).await;
+        let key = 1u64;
+
+        let caching_cloned = caching.clone();
+        let holder_handle = tokio::spawn(async move {
+            caching_cloned.get_or_set_cache(key, async {
+                tokio::time::sleep(Duration::from_millis(600)).await;
+                Ok::<_, NovaXError>("test".to_string())
+            }).await
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let waiter_result = caching.get_or_set_cache::<String, _, NovaXError>(key, async {
+            panic!("should not be executed");
+        }).await.unwrap();
+
+        assert_eq!(waiter_result, "test");
+        assert_eq!(holder_handle.await.unwrap().unwrap(), "test");
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_holder_error_releases_lock() {
+        let caching = new_caching(Duration::from_secs(10)).await;
+        let key = 1u64;
+
+        let caching_cloned = caching.clone();
+        let holder_handle = tokio::spawn(async move {
+            caching_cloned.get_or_set_cache(key, async {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                Err::<String, _>(NovaXError::Caching(CachingError::ErrorInGetter))
+            }).await
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let waiter_result = caching.get_or_set_cache(key, async {
+            Ok::<_, NovaXError>("test".to_string())
+        }).await.unwrap();
+
+        assert_eq!(waiter_result, "test");
+        assert_eq!(holder_handle.await.unwrap(), Err(NovaXError::Caching(CachingError::ErrorInGetter)));
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_lost_lock_does_not_set_value() -> Result<(), NovaXError> {
+        let caching = new_caching(Duration::from_secs(10)).await;
+        let key = 1u64;
+        let client = caching.client.clone();
+
+        let result = caching.get_or_set_cache(key, async {
+            // Simulates an expired lease acquired by another process.
+            client.state.lock().unwrap().locks.insert("novax:lock:{1}".to_string(), (1000, Instant::now() + Duration::from_secs(10)));
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        assert_eq!(result, "test");
+        assert_eq!(caching.get_cache::<String>(key).await?, None);
+        assert_eq!(caching.client.get_lock_holder("novax:lock:{1}").await.unwrap(), Some(1000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_refresh_cache_in_background_serves_stale_value() -> Result<(), NovaXError> {
+        let client = MockRedisClient::default();
+        let caching_redis = BaseCachingRedis::from_client(client.clone(), CachingDurationStrategy::Duration(Duration::from_secs(60)));
+        let caching_stale = CachingStale::new(
+            caching_redis,
+            CachingDurationStrategy::Duration(Duration::from_secs(10)),
+            Duration::from_secs(20)
+        );
+        let caching = BaseCachingLockedRedis::from_client(caching_stale, client)
+            .with_poll_interval(Duration::from_millis(50));
+        let key = 1u64;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let (sender, receiver) = tokio::sync::oneshot::channel::<String>();
+
+        // The getter only completes once the caller got its result
+        let result = caching.get_or_refresh_cache_in_background(key, async {
+            Ok::<_, NovaXError>(receiver.await.unwrap())
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        sender.send("test".to_string()).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+        assert!(caching.client.state.lock().unwrap().locks.is_empty());
+
+        Ok(())
+    }
+}
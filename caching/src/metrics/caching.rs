@@ -0,0 +1,398 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use novax::caching::{CachingDurationStrategy, CachingStrategy};
+use novax::errors::NovaXError;
+
+use crate::metrics::registry::{CachingMetricsCounters, CachingMetricsSnapshot};
+
+const GETTER_NOT_CALLED: u8 = 0;
+const GETTER_SUCCEEDED: u8 = 1;
+const GETTER_FAILED: u8 = 2;
+
+/// A caching strategy wrapper counting the hits, misses, sets and errors of the wrapped strategy,
+/// as well as the calls and latency of the getters.
+///
+/// Use a `CachingMetricsRegistry` to instrument each tier of a `CachingMulti` and collect all the metrics at once.
+#[derive(Clone, Debug)]
+pub struct CachingMetrics<C>
+where
+    C: CachingStrategy
+{
+    pub caching: C,
+    pub(crate) tier: String,
+    pub(crate) counters: Arc<CachingMetricsCounters>
+}
+
+impl<C> CachingMetrics<C>
+where
+    C: CachingStrategy
+{
+    /// Wraps `caching` with its own counters, not attached to any registry.
+    pub fn new(tier: &str, caching: C) -> Self {
+        Self {
+            caching,
+            tier: tier.to_string(),
+            counters: Arc::new(CachingMetricsCounters::default())
+        }
+    }
+
+    pub(crate) fn with_counters(tier: &str, caching: C, counters: Arc<CachingMetricsCounters>) -> Self {
+        Self {
+            caching,
+            tier: tier.to_string(),
+            counters
+        }
+    }
+
+    /// Returns the current counters of this strategy, shared with all its clones.
+    pub fn snapshot(&self) -> CachingMetricsSnapshot {
+        self.counters.snapshot(&self.tier)
+    }
+
+    fn record_getter_outcome(&self, getter_outcome: u8, is_ok: bool) {
+        match (getter_outcome, is_ok) {
+            (GETTER_NOT_CALLED, true) => self.counters.record_hit(),
+            (GETTER_NOT_CALLED, false) => self.counters.record_error(),
+            (GETTER_SUCCEEDED, true) => {
+                self.counters.record_miss();
+                self.counters.record_set();
+            },
+            (GETTER_SUCCEEDED, false) => {
+                self.counters.record_miss();
+                self.counters.record_error();
+            },
+            _ => self.counters.record_miss()
+        }
+    }
+}
+
+#[async_trait]
+impl<C> CachingStrategy for CachingMetrics<C>
+where
+    C: CachingStrategy
+{
+    async fn get_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64) -> Result<Option<T>, NovaXError> {
+        let result = self.caching.get_cache(key).await;
+
+        match &result {
+            Ok(Some(_)) => self.counters.record_hit(),
+            Ok(None) => self.counters.record_miss(),
+            Err(_) => self.counters.record_error()
+        }
+
+        result
+    }
+
+    async fn set_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64, value: &T) -> Result<(), NovaXError> {
+        let result = self.caching.set_cache(key, value).await;
+
+        match &result {
+            Ok(_) => self.counters.record_set(),
+            Err(_) => self.counters.record_error()
+        }
+
+        result
+    }
+
+    async fn get_or_set_cache<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        FutureGetter: Future<Output=Result<T, Error>> + Send,
+        Error: From<NovaXError>
+    {
+        let getter_outcome = AtomicU8::new(GETTER_NOT_CALLED);
+        let timed_getter = async {
+            let start = Instant::now();
+            let result = getter.await;
+            let is_error = result.is_err();
+
+            self.counters.record_getter_call(start.elapsed(), is_error);
+            getter_outcome.store(if is_error { GETTER_FAILED } else { GETTER_SUCCEEDED }, Ordering::Relaxed);
+
+            result
+        };
+
+        let result = self.caching.get_or_set_cache(key, timed_getter).await;
+
+        self.record_getter_outcome(getter_outcome.load(Ordering::Relaxed), result.is_ok());
+
+        result
+    }
+
+    /// A stale value served by the wrapped strategy, e.g. a `CachingStale`, counts as a hit, the getter refreshing it is timed once it completes.
+    async fn get_or_refresh_cache_in_background<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        FutureGetter: Future<Output=Result<T, Error>> + Send + 'static,
+        Error: From<NovaXError> + Send + 'static
+    {
+        let getter_outcome = Arc::new(AtomicU8::new(GETTER_NOT_CALLED));
+        let timed_getter = {
+            let counters = self.counters.clone();
+            let getter_outcome = getter_outcome.clone();
+
+            async move {
+                let start = Instant::now();
+                let result = getter.await;
+                let is_error = result.is_err();
+
+                counters.record_getter_call(start.elapsed(), is_error);
+                getter_outcome.store(if is_error { GETTER_FAILED } else { GETTER_SUCCEEDED }, Ordering::Relaxed);
+
+                result
+            }
+        };
+
+        let result = self.caching.get_or_refresh_cache_in_background(key, timed_getter).await;
+
+        self.record_getter_outcome(getter_outcome.load(Ordering::Relaxed), result.is_ok());
+
+        result
+    }
+
+    async fn clear(&self) -> Result<(), NovaXError> {
+        let result = self.caching.clear().await;
+
+        if result.is_err() {
+            self.counters.record_error();
+        }
+
+        result
+    }
+
+    fn with_duration_strategy(&self, strategy: CachingDurationStrategy) -> Self {
+        Self {
+            caching: self.caching.with_duration_strategy(strategy),
+            tier: self.tier.clone(),
+            counters: self.counters.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use novax::caching::{CachingDurationStrategy, CachingStrategy};
+    use novax::errors::{CachingError, NovaXError};
+
+    use crate::date::get_current_timestamp::set_mock_time;
+    use crate::local::caching_local::CachingLocal;
+    use crate::metrics::caching::CachingMetrics;
+    use crate::metrics::registry::CachingMetricsRegistry;
+    use crate::multi::caching::CachingMulti;
+    use crate::serialization::format::CachingSerializationFormat;
+    use crate::stale::caching::CachingStale;
+
+    fn new_caching() -> CachingMetrics<CachingLocal> {
+        CachingMetrics::new("local", CachingLocal::empty(CachingDurationStrategy::EachBlock))
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_hit_and_miss() -> Result<(), NovaXError> {
+        let caching = new_caching();
+
+        caching.set_cache(1, &"test".to_string()).await?;
+        caching.get_cache::<String>(1).await?;
+        caching.get_cache::<String>(2).await?;
+
+        let snapshot = caching.snapshot();
+
+        assert_eq!(snapshot.hits, 1);
+        assert_eq!(snapshot.misses, 1);
+        assert_eq!(snapshot.sets, 1);
+        assert_eq!(snapshot.errors, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_cache_error() -> Result<(), NovaXError> {
+        let caching = CachingMetrics::new(
+            "local",
+            CachingLocal::empty(CachingDurationStrategy::EachBlock).with_serialization_format(CachingSerializationFormat::Json)
+        );
+
+        // JSON objects only have string keys
+        let value = HashMap::from([(vec![1u8], 1u8)]);
+        let result = caching.set_cache(1, &value).await;
+
+        assert_eq!(result, Err(NovaXError::Caching(CachingError::UnableToSerialize)));
+        assert_eq!(caching.snapshot().errors, 1);
+        assert_eq!(caching.snapshot().sets, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_other_type_is_miss() -> Result<(), NovaXError> {
+        let caching = new_caching();
+
+        caching.set_cache(1, &"test".to_string()).await?;
+        let result = caching.get_cache::<u64>(1).await?;
+
+        assert_eq!(result, None);
+        assert_eq!(caching.snapshot().misses, 1);
+        assert_eq!(caching.snapshot().errors, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_hit() -> Result<(), NovaXError> {
+        let caching = new_caching();
+
+        caching.set_cache(1, &"test".to_string()).await?;
+        caching.get_or_set_cache::<String, _, NovaXError>(1, async {
+            panic!("should not be executed");
+        }).await?;
+
+        let snapshot = caching.snapshot();
+
+        assert_eq!(snapshot.hits, 1);
+        assert_eq!(snapshot.misses, 0);
+        assert_eq!(snapshot.getter_calls, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_miss() -> Result<(), NovaXError> {
+        let caching = new_caching();
+
+        caching.get_or_set_cache(1, async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        let snapshot = caching.snapshot();
+
+        assert_eq!(snapshot.hits, 0);
+        assert_eq!(snapshot.misses, 1);
+        assert_eq!(snapshot.sets, 1);
+        assert_eq!(snapshot.getter_calls, 1);
+        assert_eq!(snapshot.getter_errors, 0);
+        assert!(snapshot.getter_total_duration >= Duration::from_millis(50));
+        assert_eq!(snapshot.getter_total_duration, snapshot.getter_max_duration);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_getter_error() -> Result<(), NovaXError> {
+        let caching = new_caching();
+
+        let result = caching.get_or_set_cache(1, async {
+            Err::<String, _>(NovaXError::Caching(CachingError::ErrorInGetter))
+        }).await;
+
+        let snapshot = caching.snapshot();
+
+        assert!(result.is_err());
+        assert_eq!(snapshot.misses, 1);
+        assert_eq!(snapshot.sets, 0);
+        assert_eq!(snapshot.errors, 0);
+        assert_eq!(snapshot.getter_calls, 1);
+        assert_eq!(snapshot.getter_errors, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_duration_strategy_shares_counters() -> Result<(), NovaXError> {
+        let caching = new_caching();
+
+        caching
+            .with_duration_strategy(CachingDurationStrategy::Duration(Duration::from_secs(10)))
+            .set_cache(1, &"test".to_string())
+            .await?;
+
+        assert_eq!(caching.snapshot().sets, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_caching_multi_per_tier() -> Result<(), NovaXError> {
+        let registry = CachingMetricsRegistry::new();
+        let first = registry.instrument("first", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+        let second = registry.instrument("second", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+        let caching = registry.instrument("total", CachingMulti::new(first, second.clone()));
+
+        second.set_cache(1, &"test".to_string()).await?;
+
+        // Hit in the second tier, then in the first one
+        for _ in 0..2 {
+            caching.get_or_set_cache::<String, _, NovaXError>(1, async {
+                panic!("should not be executed");
+            }).await?;
+        }
+
+        // Miss in both tiers
+        caching.get_or_set_cache(2, async {
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        let snapshots = registry.snapshot();
+        let tiers: Vec<(&str, u64, u64, u64)> = snapshots
+            .iter()
+            .map(|snapshot| (snapshot.tier.as_str(), snapshot.hits, snapshot.misses, snapshot.sets))
+            .collect();
+
+        let expected = vec![
+            ("first", 1, 2, 2),
+            ("second", 1, 1, 2),
+            ("total", 2, 1, 1),
+        ];
+
+        assert_eq!(tiers, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_refresh_cache_in_background_serves_stale_value() -> Result<(), NovaXError> {
+        let caching = CachingMetrics::new(
+            "stale",
+            CachingStale::new(
+                CachingLocal::empty(CachingDurationStrategy::EachBlock),
+                CachingDurationStrategy::Duration(Duration::from_secs(10)),
+                Duration::from_secs(20)
+            )
+        );
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let (sender, receiver) = tokio::sync::oneshot::channel::<String>();
+
+        // The getter only completes once the caller got its result
+        let result = caching.get_or_refresh_cache_in_background(key, async {
+            Ok::<_, NovaXError>(receiver.await.unwrap())
+        }).await?;
+
+        assert_eq!(result, "old value");
+        assert_eq!(caching.snapshot().hits, 1);
+
+        sender.send("test".to_string()).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let snapshot = caching.snapshot();
+
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+        assert_eq!(snapshot.getter_calls, 1);
+        assert_eq!(snapshot.getter_errors, 0);
+
+        Ok(())
+    }
+}
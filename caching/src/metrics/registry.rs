@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use novax::caching::CachingStrategy;
+
+use crate::metrics::caching::CachingMetrics;
+
+/// The counters of an instrumented caching strategy, shared by all its clones.
+#[derive(Default, Debug)]
+pub struct CachingMetricsCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    sets: AtomicU64,
+    errors: AtomicU64,
+    getter_calls: AtomicU64,
+    getter_errors: AtomicU64,
+    getter_total_micros: AtomicU64,
+    getter_max_micros: AtomicU64,
+}
+
+impl CachingMetricsCounters {
+    pub(crate) fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_set(&self) {
+        self.sets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_getter_call(&self, duration: Duration, is_error: bool) {
+        let micros = duration.as_micros() as u64;
+
+        self.getter_calls.fetch_add(1, Ordering::Relaxed);
+        self.getter_total_micros.fetch_add(micros, Ordering::Relaxed);
+        self.getter_max_micros.fetch_max(micros, Ordering::Relaxed);
+
+        if is_error {
+            self.getter_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn snapshot(&self, tier: &str) -> CachingMetricsSnapshot {
+        CachingMetricsSnapshot {
+            tier: tier.to_string(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            sets: self.sets.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            getter_calls: self.getter_calls.load(Ordering::Relaxed),
+            getter_errors: self.getter_errors.load(Ordering::Relaxed),
+            getter_total_duration: Duration::from_micros(self.getter_total_micros.load(Ordering::Relaxed)),
+            getter_max_duration: Duration::from_micros(self.getter_max_micros.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// A point-in-time copy of the counters of an instrumented caching strategy.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CachingMetricsSnapshot {
+    /// The name given to the instrumented caching strategy.
+    pub tier: String,
+    /// The number of values found in the cache.
+    pub hits: u64,
+    /// The number of values not found in the cache.
+    pub misses: u64,
+    /// The number of values written to the cache.
+    pub sets: u64,
+    /// The number of failed cache operations, the getter errors excluded.
+    pub errors: u64,
+    /// The number of times the getter has been called because of a miss.
+    pub getter_calls: u64,
+    /// The number of times the getter returned an error.
+    pub getter_errors: u64,
+    /// The cumulated time spent in the getter.
+    pub getter_total_duration: Duration,
+    /// The longest time spent in a single getter call.
+    pub getter_max_duration: Duration,
+}
+
+impl CachingMetricsSnapshot {
+    /// The ratio of hits over the total number of lookups, `None` if there was no lookup.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+
+        if lookups == 0 {
+            None
+        } else {
+            Some(self.hits as f64 / lookups as f64)
+        }
+    }
+}
+
+/// Collects the metrics of several instrumented caching strategies, typically the tiers of a `CachingMulti`.
+///
+/// ```ignore
+/// let registry = CachingMetricsRegistry::new();
+/// let caching = registry.instrument(
+///     "total",
+///     CachingMulti::new(
+///         registry.instrument("local", caching_local),
+///         registry.instrument("redis", caching_redis)
+///     )
+/// );
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct CachingMetricsRegistry {
+    counters: Arc<RwLock<BTreeMap<String, Arc<CachingMetricsCounters>>>>
+}
+
+impl CachingMetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps `caching` so its operations are counted under the `tier` name.
+    /// Instrumenting several strategies with the same name makes them share the same counters.
+    pub fn instrument<C: CachingStrategy>(&self, tier: &str, caching: C) -> CachingMetrics<C> {
+        CachingMetrics::with_counters(tier, caching, self.get_or_insert_counters(tier))
+    }
+
+    /// Returns the current counters of every tier, sorted by tier name.
+    pub fn snapshot(&self) -> Vec<CachingMetricsSnapshot> {
+        let counters = self.counters.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        counters
+            .iter()
+            .map(|(tier, counters)| counters.snapshot(tier))
+            .collect()
+    }
+
+    /// Renders the current counters of every tier in the Prometheus text exposition format.
+    pub fn to_prometheus_text(&self) -> String {
+        let snapshots = self.snapshot();
+        let mut text = String::new();
+
+        write_metric(&mut text, &snapshots, "novax_caching_hits_total", "The number of values found in the cache.", "counter", |snapshot| snapshot.hits.to_string());
+        write_metric(&mut text, &snapshots, "novax_caching_misses_total", "The number of values not found in the cache.", "counter", |snapshot| snapshot.misses.to_string());
+        write_metric(&mut text, &snapshots, "novax_caching_sets_total", "The number of values written to the cache.", "counter", |snapshot| snapshot.sets.to_string());
+        write_metric(&mut text, &snapshots, "novax_caching_errors_total", "The number of failed cache operations.", "counter", |snapshot| snapshot.errors.to_string());
+        write_metric(&mut text, &snapshots, "novax_caching_getter_calls_total", "The number of getter calls.", "counter", |snapshot| snapshot.getter_calls.to_string());
+        write_metric(&mut text, &snapshots, "novax_caching_getter_errors_total", "The number of getter calls which returned an error.", "counter", |snapshot| snapshot.getter_errors.to_string());
+        write_metric(&mut text, &snapshots, "novax_caching_getter_duration_seconds_total", "The cumulated time spent in the getter.", "counter", |snapshot| snapshot.getter_total_duration.as_secs_f64().to_string());
+        write_metric(&mut text, &snapshots, "novax_caching_getter_duration_seconds_max", "The longest time spent in a single getter call.", "gauge", |snapshot| snapshot.getter_max_duration.as_secs_f64().to_string());
+
+        text
+    }
+
+    fn get_or_insert_counters(&self, tier: &str) -> Arc<CachingMetricsCounters> {
+        let mut counters = self.counters.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        counters
+            .entry(tier.to_string())
+            .or_default()
+            .clone()
+    }
+}
+
+fn write_metric<F>(text: &mut String, snapshots: &[CachingMetricsSnapshot], name: &str, help: &str, metric_type: &str, get_value: F)
+where
+    F: Fn(&CachingMetricsSnapshot) -> String
+{
+    let _ = writeln!(text, "# HELP {name} {help}");
+    let _ = writeln!(text, "# TYPE {name} {metric_type}");
+    for snapshot in snapshots {
+        let _ = writeln!(text, "{name}{{tier=\"{}\"}} {}", escape_label_value(&snapshot.tier), get_value(snapshot));
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use novax::caching::CachingDurationStrategy;
+
+    use crate::local::caching_local::CachingLocal;
+    use crate::metrics::registry::{CachingMetricsRegistry, CachingMetricsSnapshot};
+
+    #[test]
+    fn test_snapshot_empty() {
+        let registry = CachingMetricsRegistry::new();
+
+        assert!(registry.snapshot().is_empty());
+    }
+
+    #[test]
+    fn test_same_tier_shares_counters() {
+        let registry = CachingMetricsRegistry::new();
+        let first = registry.instrument("local", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+        let second = registry.instrument("local", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+
+        first.counters.record_hit();
+        second.counters.record_hit();
+
+        assert_eq!(registry.snapshot().len(), 1);
+        assert_eq!(registry.snapshot()[0].hits, 2);
+    }
+
+    #[test]
+    fn test_hit_ratio() {
+        let registry = CachingMetricsRegistry::new();
+        let caching = registry.instrument("local", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+
+        assert_eq!(registry.snapshot()[0].hit_ratio(), None);
+
+        caching.counters.record_hit();
+        caching.counters.record_hit();
+        caching.counters.record_hit();
+        caching.counters.record_miss();
+
+        assert_eq!(registry.snapshot()[0].hit_ratio(), Some(0.75));
+    }
+
+    #[test]
+    fn test_getter_durations() {
+        let registry = CachingMetricsRegistry::new();
+        let caching = registry.instrument("local", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+
+        caching.counters.record_getter_call(Duration::from_millis(10), false);
+        caching.counters.record_getter_call(Duration::from_millis(30), true);
+
+        let result = registry.snapshot().remove(0);
+        let expected = CachingMetricsSnapshot {
+            tier: "local".to_string(),
+            hits: 0,
+            misses: 0,
+            sets: 0,
+            errors: 0,
+            getter_calls: 2,
+            getter_errors: 1,
+            getter_total_duration: Duration::from_millis(40),
+            getter_max_duration: Duration::from_millis(30),
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_to_prometheus_text() {
+        let registry = CachingMetricsRegistry::new();
+        let local = registry.instrument("local", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+        let redis = registry.instrument("re\"dis", CachingLocal::empty(CachingDurationStrategy::EachBlock));
+
+        local.counters.record_hit();
+        redis.counters.record_getter_call(Duration::from_millis(1500), false);
+
+        let result = registry.to_prometheus_text();
+
+        assert!(result.contains("# TYPE novax_caching_hits_total counter\n"));
+        assert!(result.contains("novax_caching_hits_total{tier=\"local\"} 1\n"));
+        assert!(result.contains("novax_caching_hits_total{tier=\"re\\\"dis\"} 0\n"));
+        assert!(result.contains("novax_caching_getter_duration_seconds_total{tier=\"re\\\"dis\"} 1.5\n"));
+        assert!(result.contains("# TYPE novax_caching_getter_duration_seconds_max gauge\n"));
+    }
+}
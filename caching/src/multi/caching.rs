@@ -35,13 +35,13 @@ impl<C1, C2> CachingMulti<C1, C2>
 impl<C1, C2> CachingStrategy for CachingMulti<C1, C2>
 where
     C1: CachingStrategy,
-    C2: CachingStrategy
+    C2: CachingStrategy + 'static
 {
     async fn get_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64) -> Result<Option<T>, NovaXError> {
-        let first_cached_value = self.first.get_cache(key).await?;
+        let first_cached_value = self.first.get_cache::<T>(key).await?;
 
-        if let Some(value) = first_cached_value {
-            return Ok(value)
+        if first_cached_value.is_some() {
+            return Ok(first_cached_value)
         }
 
         self.second.get_cache(key).await
@@ -77,6 +77,27 @@ where
         }
     }
 
+    /// The first strategy serves its value, fresh or stale, the second one being checked by the getter before the actual one.
+    /// A value obtained by the getter, including in a background refresh, is set in both strategies.
+    async fn get_or_refresh_cache_in_background<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        FutureGetter: Future<Output=Result<T, Error>> + Send + 'static,
+        Error: From<NovaXError> + Send + 'static
+    {
+        let second = self.second.clone();
+
+        self.first.get_or_refresh_cache_in_background(key, async move {
+            if let Some(cached_value_from_second) = second.get_cache(key).await? {
+                return Ok(cached_value_from_second);
+            }
+
+            let value = getter.await?;
+            second.set_cache(key, &value).await?;
+            Ok(value)
+        }).await
+    }
+
     async fn clear(&self) -> Result<(), NovaXError> {
         let (
             first_clear_result,
@@ -110,6 +131,7 @@ mod test {
     use crate::date::get_current_timestamp::set_mock_time;
     use crate::local::caching_local::CachingLocal;
     use crate::multi::caching::CachingMulti;
+    use crate::stale::caching::CachingStale;
 
     #[tokio::test]
     async fn test_get_cache_no_cache() -> Result<(), NovaXError> {
@@ -410,4 +432,37 @@ mod test {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_get_or_refresh_cache_in_background_stale_first() -> Result<(), NovaXError> {
+        let key = 1u64;
+        let first_caching = CachingStale::new(
+            CachingLocal::empty(CachingDurationStrategy::EachBlock),
+            CachingDurationStrategy::Duration(Duration::from_secs(10)),
+            Duration::from_secs(20)
+        );
+        let second_caching = CachingLocal::empty(CachingDurationStrategy::Duration(Duration::from_secs(10)));
+
+        let caching = CachingMulti::new(first_caching.clone(), second_caching.clone());
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let (sender, receiver) = tokio::sync::oneshot::channel::<String>();
+
+        // The getter only completes once the caller got its result
+        let result = caching.get_or_refresh_cache_in_background(key, async {
+            Ok::<_, NovaXError>(receiver.await.unwrap())
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        sender.send("test".to_string()).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(first_caching.get_cache::<String>(key).await?, Some("test".to_string()));
+        assert_eq!(second_caching.get_cache::<String>(key).await?, Some("test".to_string()));
+
+        Ok(())
+    }
+}
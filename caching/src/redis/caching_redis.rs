@@ -8,16 +8,19 @@ use novax::caching::{CachingDurationStrategy, CachingStrategy};
 use novax::errors::{CachingError, NovaXError};
 
 use crate::date::get_current_timestamp::{get_current_timestamp, GetDuration};
-use crate::redis::client::{RedisClient, SingleMultiplexedConnectionRedisClient};
+use crate::redis::client::{RedisClient, ReconnectingRedisClient};
 use crate::redis::error::CachingRedisError;
 use crate::redis::IntoConnectionInfo;
+use crate::serialization::envelope::{decode_envelope, encode_envelope};
+use crate::serialization::format::CachingSerializationFormat;
 
-pub type CachingRedis = BaseCachingRedis<SingleMultiplexedConnectionRedisClient>;
+pub type CachingRedis = BaseCachingRedis<ReconnectingRedisClient>;
 
 #[derive(Clone, Debug)]
 pub struct BaseCachingRedis<Client: RedisClient> {
     pub(crate) client: Client,
-    pub duration_strategy: CachingDurationStrategy
+    pub duration_strategy: CachingDurationStrategy,
+    pub serialization_format: CachingSerializationFormat
 }
 
 impl<Client: RedisClient> BaseCachingRedis<Client> {
@@ -27,12 +30,27 @@ impl<Client: RedisClient> BaseCachingRedis<Client> {
     ) -> Result<Self, CachingRedisError> {
         let client = Client::open(info).await?;
 
-        Ok(
-            BaseCachingRedis {
-                client,
-                duration_strategy
-            }
-        )
+        Ok(Self::from_client(client, duration_strategy))
+    }
+
+    /// Uses an already opened client, e.g. a `SentinelRedisClient` with a custom master name.
+    pub fn from_client(
+        client: Client,
+        duration_strategy: CachingDurationStrategy
+    ) -> Self {
+        BaseCachingRedis {
+            client,
+            duration_strategy,
+            serialization_format: CachingSerializationFormat::default()
+        }
+    }
+
+    /// Sets the format used to serialize the values, MessagePack by default.
+    ///
+    /// Values written by other processes with another format can still be read.
+    pub fn with_serialization_format(mut self, serialization_format: CachingSerializationFormat) -> Self {
+        self.serialization_format = serialization_format;
+        self
     }
 }
 
@@ -50,17 +68,11 @@ impl<Client: RedisClient> CachingStrategy for BaseCachingRedis<Client> {
             return Ok(None);
         };
 
-        let Ok(decoded) = rmp_serde::from_slice(&value_encoded) else {
-            return Err(CachingError::UnableToDeserialize.into())
-        };
-
-        Ok(Some(decoded))
+        Ok(decode_envelope(&value_encoded)?)
     }
 
     async fn set_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64, value: &T) -> Result<(), NovaXError> {
-        let Ok(encoded) = rmp_serde::to_vec(value) else {
-            return Err(CachingError::UnableToSerialize.into())
-        };
+        let encoded = encode_envelope(self.serialization_format, value)?;
 
         self.client
             .set(key, encoded, self.duration_strategy.get_duration_from_now(&get_current_timestamp()?)?.as_secs())
@@ -103,6 +115,7 @@ impl<Client: RedisClient> CachingStrategy for BaseCachingRedis<Client> {
         BaseCachingRedis {
             client: self.client.clone(),
             duration_strategy: strategy,
+            serialization_format: self.serialization_format,
         }
     }
 }
@@ -120,6 +133,8 @@ mod test {
     use crate::redis::client::RedisClient;
     use crate::redis::error::CachingRedisError;
     use crate::redis::caching_redis::BaseCachingRedis;
+    use crate::serialization::envelope::encode_envelope;
+    use crate::serialization::format::CachingSerializationFormat;
 
     #[derive(Clone, Debug)]
     struct MockRedisClient;
@@ -134,6 +149,9 @@ mod test {
             if key.to_redis_args() == 1.to_redis_args() { // Not found
                 Ok(None)
             } else if key.to_redis_args() == 2.to_redis_args() { // Found
+                let encoded = encode_envelope(CachingSerializationFormat::MessagePack, &vec![0u8, 1]).unwrap();
+                Ok(Some(RV::from_byte_vec(&encoded).unwrap().into_iter().next().unwrap()))
+            } else if key.to_redis_args() == 3.to_redis_args() { // Found, written before the envelopes
                 Ok(Some(RV::from_byte_vec(&[146, 0, 1]).unwrap().into_iter().next().unwrap()))
             } else {
                 Ok(None)
@@ -141,7 +159,7 @@ mod test {
         }
 
         async fn set<K: ToRedisArgs + Send + Sync, V: ToRedisArgs + Send + Sync>(&self, key: K, value: V, duration: u64) -> Result<(), CachingRedisError> {
-            if value.to_redis_args() != rmp_serde::to_vec("test").unwrap().to_redis_args() {
+            if value.to_redis_args() != encode_envelope(CachingSerializationFormat::MessagePack, &"test".to_string()).unwrap().to_redis_args() {
                 panic!();
             }
 
@@ -191,6 +209,42 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_get_cache_key_found_other_type() -> Result<(), NovaXError> {
+        let caching = BaseCachingRedis::<MockRedisClient>::new("", CachingDurationStrategy::EachBlock).await.unwrap();
+        let key = 2;
+
+        let result = caching.get_cache::<Vec<u64>>(key).await?;
+
+        assert_eq!(result, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_key_found_without_envelope() -> Result<(), NovaXError> {
+        let caching = BaseCachingRedis::<MockRedisClient>::new("", CachingDurationStrategy::EachBlock).await.unwrap();
+        let key = 3;
+
+        let result = caching.get_cache::<Vec<u8>>(key).await?;
+
+        assert_eq!(result, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_from_client() -> Result<(), NovaXError> {
+        let caching = BaseCachingRedis::from_client(MockRedisClient, CachingDurationStrategy::EachBlock);
+        let key = 2;
+
+        let result = caching.get_cache::<Vec<u8>>(key).await?;
+
+        assert_eq!(result, Some([0, 1].to_vec()));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_set_cache() -> Result<(), NovaXError> {
         let caching = BaseCachingRedis::<MockRedisClient>::new("", CachingDurationStrategy::EachBlock).await.unwrap();
@@ -1,9 +1,9 @@
 use crate::redis::error::CachingRedisError;
 use async_trait::async_trait;
-use redis::aio::MultiplexedConnection;
+use redis::aio::{ConnectionLike, ConnectionManager, MultiplexedConnection};
 use redis::AsyncCommands;
-use redis::{AsyncConnectionConfig, FromRedisValue, IntoConnectionInfo, SetExpiry, ToRedisArgs};
-use std::fmt::Debug;
+use redis::{AsyncConnectionConfig, FromRedisValue, IntoConnectionInfo, RedisResult, SetExpiry, ToRedisArgs};
+use std::fmt::{Debug, Formatter};
 
 #[async_trait]
 pub trait RedisClient: Clone + Debug + Send + Sync {
@@ -13,6 +13,9 @@ pub trait RedisClient: Clone + Debug + Send + Sync {
     async fn clear(&self) -> Result<(), CachingRedisError>;
 }
 
+/// A client holding a single multiplexed connection to a Redis node.
+///
+/// The connection is not reopened if it drops, prefer `ReconnectingRedisClient` for long-running processes.
 #[derive(Clone, Debug)]
 pub struct SingleMultiplexedConnectionRedisClient {
     pub connexion: MultiplexedConnection,
@@ -39,36 +42,102 @@ impl RedisClient for SingleMultiplexedConnectionRedisClient {
     }
 
     async fn set<K: ToRedisArgs + Send + Sync, V: ToRedisArgs + Send + Sync>(&self, key: K, value: V, duration: u64) -> Result<(), CachingRedisError> {
-        if duration == 0 {
-            return Ok(());
-        }
+        set_value(&mut self.connexion.clone(), key, value, duration).await.map_err(set_error)
+    }
+
+    async fn get<K: ToRedisArgs + Send + Sync, RV: FromRedisValue + Send + Sync>(&self, key: K) -> Result<Option<RV>, CachingRedisError> {
+        get_value(&mut self.connexion.clone(), key).await.map_err(get_error)
+    }
 
-        let mut connection = self.connexion.clone();
+    async fn clear(&self) -> Result<(), CachingRedisError> {
+        clear_values(&mut self.connexion.clone()).await.map_err(clear_error)
+    }
+}
 
-        let options = redis::SetOptions::default()
-            .with_expiration(SetExpiry::EX(duration));
+/// A client holding a multiplexed connection to a Redis node, transparently reopened when it drops.
+///
+/// The command being executed when the connection drops fails, the following ones use the new connection.
+#[derive(Clone)]
+pub struct ReconnectingRedisClient {
+    pub connexion: ConnectionManager,
+}
 
-        match connection.set_options::<_, _, ()>(key, value, options).await {
-            Ok(_) => Ok(()),
-            Err(error) => Err(CachingRedisError::CannotSetValue { description: format!("{}", error) })
-        }
+impl Debug for ReconnectingRedisClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectingRedisClient").finish_non_exhaustive()
     }
+}
 
-    async fn get<K: ToRedisArgs + Send + Sync, RV: FromRedisValue + Send + Sync>(&self, key: K) -> Result<Option<RV>, CachingRedisError> {
-        let mut connection = self.connexion.clone();
+#[async_trait]
+impl RedisClient for ReconnectingRedisClient {
+    async fn open<Info: IntoConnectionInfo + Send + Sync>(info: Info) -> Result<Self, CachingRedisError> {
+        let redis_client = match redis::Client::open(info) {
+            Ok(client) => Ok(client),
+            Err(error) => Err(CachingRedisError::CannotOpenConnection { description: format!("{}", error) })
+        }?;
 
-        match connection.get(key).await {
-            Ok(value) => Ok(value),
-            Err(error) => Err(CachingRedisError::CannotGetValue { description: format!("{}", error) })
-        }
+        let connection = match redis_client.get_connection_manager().await {
+            Ok(connection) => connection,
+            Err(error) => return Err(CachingRedisError::CannotGetConnection { description: format!("{}", error) })
+        };
+
+        Ok(
+            ReconnectingRedisClient {
+                connexion: connection
+            }
+        )
+    }
+
+    async fn set<K: ToRedisArgs + Send + Sync, V: ToRedisArgs + Send + Sync>(&self, key: K, value: V, duration: u64) -> Result<(), CachingRedisError> {
+        set_value(&mut self.connexion.clone(), key, value, duration).await.map_err(set_error)
+    }
+
+    async fn get<K: ToRedisArgs + Send + Sync, RV: FromRedisValue + Send + Sync>(&self, key: K) -> Result<Option<RV>, CachingRedisError> {
+        get_value(&mut self.connexion.clone(), key).await.map_err(get_error)
     }
 
     async fn clear(&self) -> Result<(), CachingRedisError> {
-        let mut connection = self.connexion.clone();
+        clear_values(&mut self.connexion.clone()).await.map_err(clear_error)
+    }
+}
 
-        match redis::cmd("FLUSHALL").exec_async(&mut connection).await {
-            Ok(_) => Ok(()),
-            Err(error) => Err(CachingRedisError::CannotClearAllValues { description: format!("{}", error) })
-        }
+pub(crate) async fn set_value<C, K, V>(connection: &mut C, key: K, value: V, duration: u64) -> RedisResult<()>
+where
+    C: ConnectionLike + Send,
+    K: ToRedisArgs + Send + Sync,
+    V: ToRedisArgs + Send + Sync
+{
+    if duration == 0 {
+        return Ok(());
     }
-}
\ No newline at end of file
+
+    let options = redis::SetOptions::default()
+        .with_expiration(SetExpiry::EX(duration));
+
+    connection.set_options(key, value, options).await
+}
+
+pub(crate) async fn get_value<C, K, RV>(connection: &mut C, key: K) -> RedisResult<Option<RV>>
+where
+    C: ConnectionLike + Send,
+    K: ToRedisArgs + Send + Sync,
+    RV: FromRedisValue + Send + Sync
+{
+    connection.get(key).await
+}
+
+pub(crate) async fn clear_values<C: ConnectionLike + Send>(connection: &mut C) -> RedisResult<()> {
+    redis::cmd("FLUSHALL").exec_async(connection).await
+}
+
+pub(crate) fn set_error(error: redis::RedisError) -> CachingRedisError {
+    CachingRedisError::CannotSetValue { description: format!("{}", error) }
+}
+
+pub(crate) fn get_error(error: redis::RedisError) -> CachingRedisError {
+    CachingRedisError::CannotGetValue { description: format!("{}", error) }
+}
+
+pub(crate) fn clear_error(error: redis::RedisError) -> CachingRedisError {
+    CachingRedisError::CannotClearAllValues { description: format!("{}", error) }
+}
@@ -0,0 +1,66 @@
+use std::fmt::{Debug, Formatter};
+
+use async_trait::async_trait;
+use redis::cluster::ClusterClient;
+use redis::cluster_async::ClusterConnection;
+use redis::{FromRedisValue, IntoConnectionInfo, ToRedisArgs};
+
+use crate::redis::client::{clear_error, clear_values, get_error, get_value, set_error, set_value, RedisClient};
+use crate::redis::error::CachingRedisError;
+
+/// A client connected to a Redis Cluster.
+///
+/// The topology is discovered from the given nodes, commands are routed to the node owning the key
+/// and the connections to the nodes are reopened if they drop.
+#[derive(Clone)]
+pub struct ClusterRedisClient {
+    pub connexion: ClusterConnection,
+}
+
+impl ClusterRedisClient {
+    /// Connects to the cluster, discovering its topology from any of the given nodes.
+    pub async fn open_with_nodes<Info: IntoConnectionInfo>(nodes: Vec<Info>) -> Result<Self, CachingRedisError> {
+        let cluster_client = match ClusterClient::new(nodes) {
+            Ok(client) => Ok(client),
+            Err(error) => Err(CachingRedisError::CannotOpenConnection { description: format!("{}", error) })
+        }?;
+
+        let connection = match cluster_client.get_async_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return Err(CachingRedisError::CannotGetConnection { description: format!("{}", error) })
+        };
+
+        Ok(
+            ClusterRedisClient {
+                connexion: connection
+            }
+        )
+    }
+}
+
+impl Debug for ClusterRedisClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClusterRedisClient").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl RedisClient for ClusterRedisClient {
+    /// Connects to the cluster, discovering its topology from a single node.
+    async fn open<Info: IntoConnectionInfo + Send + Sync>(info: Info) -> Result<Self, CachingRedisError> {
+        Self::open_with_nodes(vec![info]).await
+    }
+
+    async fn set<K: ToRedisArgs + Send + Sync, V: ToRedisArgs + Send + Sync>(&self, key: K, value: V, duration: u64) -> Result<(), CachingRedisError> {
+        set_value(&mut self.connexion.clone(), key, value, duration).await.map_err(set_error)
+    }
+
+    async fn get<K: ToRedisArgs + Send + Sync, RV: FromRedisValue + Send + Sync>(&self, key: K) -> Result<Option<RV>, CachingRedisError> {
+        get_value(&mut self.connexion.clone(), key).await.map_err(get_error)
+    }
+
+    /// Flushes every primary node of the cluster.
+    async fn clear(&self) -> Result<(), CachingRedisError> {
+        clear_values(&mut self.connexion.clone()).await.map_err(clear_error)
+    }
+}
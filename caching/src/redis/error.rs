@@ -7,7 +7,8 @@ pub enum CachingRedisError {
     CannotGetConnection { description: String },
     CannotGetValue { description: String },
     CannotSetValue { description: String },
-    CannotClearAllValues { description: String }
+    CannotClearAllValues { description: String },
+    CannotUseLock { description: String }
 }
 
 impl CachingRedisError {
@@ -28,6 +29,9 @@ impl CachingRedisError {
             CachingRedisError::CannotClearAllValues { description } => {
                 format!("Cannot clear all the values in the redis server: {description}")
             }
+            CachingRedisError::CannotUseLock { description } => {
+                format!("Cannot use the lock on the redis server: {description}")
+            }
         }
     }
     pub fn get_type(&self) -> String {
@@ -40,7 +44,8 @@ impl CachingRedisError {
             CachingRedisError::CannotGetConnection { .. } => 1,
             CachingRedisError::CannotGetValue { .. } => 2,
             CachingRedisError::CannotSetValue { .. } => 3,
-            CachingRedisError::CannotClearAllValues { .. } => 4
+            CachingRedisError::CannotClearAllValues { .. } => 4,
+            CachingRedisError::CannotUseLock { .. } => 5
         }
     }
 }
@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::aio::ConnectionLike;
+use redis::{AsyncCommands, ExistenceCheck, RedisError, RedisResult, Script, SetExpiry};
+
+use crate::redis::client::{ReconnectingRedisClient, RedisClient, SingleMultiplexedConnectionRedisClient};
+use crate::redis::cluster::ClusterRedisClient;
+use crate::redis::error::CachingRedisError;
+use crate::redis::sentinel::SentinelRedisClient;
+
+const FENCING_TOKEN_KEY: &str = "novax:lock:fencing";
+
+const RENEW_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("PEXPIRE", KEYS[1], ARGV[2])
+else
+    return 0
+end
+"#;
+
+const SET_IF_HELD_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    redis.call("SET", KEYS[2], ARGV[2], "EX", ARGV[3])
+    return 1
+else
+    return 0
+end
+"#;
+
+const RELEASE_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+else
+    return 0
+end
+"#;
+
+/// The Redis operations needed to implement a distributed lock.
+///
+/// A lock is a key holding a fencing token, set only if it doesn't exist and expiring after a lease duration.
+/// Only the holder of the token can renew or release the lock.
+#[async_trait]
+pub trait RedisLockClient: RedisClient + 'static {
+    /// Returns a new fencing token, strictly greater than all the previous ones.
+    async fn next_fencing_token(&self) -> Result<u64, CachingRedisError>;
+    /// Sets `key` to `token` if it doesn't exist, returns `true` if the lock has been acquired.
+    async fn try_acquire_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError>;
+    /// Extends the lease of the lock if it is still held with `token`, returns `false` if the lock has been lost.
+    async fn renew_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError>;
+    /// Sets `key` to `value` for `duration` seconds if the lock is still held with `token`, returns `false` if the lock has been lost.
+    ///
+    /// The check and the write are a single script, so the lock can't be lost between them. Both keys must therefore be in the same hash slot on a cluster.
+    async fn set_if_lock_held(&self, lock_key: &str, token: u64, key: u64, value: Vec<u8>, duration: u64) -> Result<bool, CachingRedisError>;
+    /// Deletes the lock if it is still held with `token`, returns `false` if the lock had already been lost.
+    async fn release_lock(&self, key: &str, token: u64) -> Result<bool, CachingRedisError>;
+    /// Returns the token of the current holder of the lock, if any.
+    async fn get_lock_holder(&self, key: &str) -> Result<Option<u64>, CachingRedisError>;
+}
+
+#[async_trait]
+impl RedisLockClient for SingleMultiplexedConnectionRedisClient {
+    async fn next_fencing_token(&self) -> Result<u64, CachingRedisError> {
+        next_fencing_token(&mut self.connexion.clone()).await.map_err(lock_error)
+    }
+
+    async fn try_acquire_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        try_acquire_lock(&mut self.connexion.clone(), key, token, lease).await.map_err(lock_error)
+    }
+
+    async fn renew_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        renew_lock(&mut self.connexion.clone(), key, token, lease).await.map_err(lock_error)
+    }
+
+    async fn set_if_lock_held(&self, lock_key: &str, token: u64, key: u64, value: Vec<u8>, duration: u64) -> Result<bool, CachingRedisError> {
+        set_if_lock_held(&mut self.connexion.clone(), lock_key, token, key, value, duration).await.map_err(lock_error)
+    }
+
+    async fn release_lock(&self, key: &str, token: u64) -> Result<bool, CachingRedisError> {
+        release_lock(&mut self.connexion.clone(), key, token).await.map_err(lock_error)
+    }
+
+    async fn get_lock_holder(&self, key: &str) -> Result<Option<u64>, CachingRedisError> {
+        get_lock_holder(&mut self.connexion.clone(), key).await.map_err(lock_error)
+    }
+}
+
+#[async_trait]
+impl RedisLockClient for ReconnectingRedisClient {
+    async fn next_fencing_token(&self) -> Result<u64, CachingRedisError> {
+        next_fencing_token(&mut self.connexion.clone()).await.map_err(lock_error)
+    }
+
+    async fn try_acquire_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        try_acquire_lock(&mut self.connexion.clone(), key, token, lease).await.map_err(lock_error)
+    }
+
+    async fn renew_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        renew_lock(&mut self.connexion.clone(), key, token, lease).await.map_err(lock_error)
+    }
+
+    async fn set_if_lock_held(&self, lock_key: &str, token: u64, key: u64, value: Vec<u8>, duration: u64) -> Result<bool, CachingRedisError> {
+        set_if_lock_held(&mut self.connexion.clone(), lock_key, token, key, value, duration).await.map_err(lock_error)
+    }
+
+    async fn release_lock(&self, key: &str, token: u64) -> Result<bool, CachingRedisError> {
+        release_lock(&mut self.connexion.clone(), key, token).await.map_err(lock_error)
+    }
+
+    async fn get_lock_holder(&self, key: &str) -> Result<Option<u64>, CachingRedisError> {
+        get_lock_holder(&mut self.connexion.clone(), key).await.map_err(lock_error)
+    }
+}
+
+#[async_trait]
+impl RedisLockClient for ClusterRedisClient {
+    async fn next_fencing_token(&self) -> Result<u64, CachingRedisError> {
+        next_fencing_token(&mut self.connexion.clone()).await.map_err(lock_error)
+    }
+
+    async fn try_acquire_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        try_acquire_lock(&mut self.connexion.clone(), key, token, lease).await.map_err(lock_error)
+    }
+
+    async fn renew_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        renew_lock(&mut self.connexion.clone(), key, token, lease).await.map_err(lock_error)
+    }
+
+    async fn set_if_lock_held(&self, lock_key: &str, token: u64, key: u64, value: Vec<u8>, duration: u64) -> Result<bool, CachingRedisError> {
+        set_if_lock_held(&mut self.connexion.clone(), lock_key, token, key, value, duration).await.map_err(lock_error)
+    }
+
+    async fn release_lock(&self, key: &str, token: u64) -> Result<bool, CachingRedisError> {
+        release_lock(&mut self.connexion.clone(), key, token).await.map_err(lock_error)
+    }
+
+    async fn get_lock_holder(&self, key: &str) -> Result<Option<u64>, CachingRedisError> {
+        get_lock_holder(&mut self.connexion.clone(), key).await.map_err(lock_error)
+    }
+}
+
+#[async_trait]
+impl RedisLockClient for SentinelRedisClient {
+    async fn next_fencing_token(&self) -> Result<u64, CachingRedisError> {
+        self.run(|mut connection| async move { next_fencing_token(&mut connection).await })
+            .await
+            .map_err(lock_error)
+    }
+
+    async fn try_acquire_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        self.run(|mut connection| async move { try_acquire_lock(&mut connection, key, token, lease).await })
+            .await
+            .map_err(lock_error)
+    }
+
+    async fn renew_lock(&self, key: &str, token: u64, lease: Duration) -> Result<bool, CachingRedisError> {
+        self.run(|mut connection| async move { renew_lock(&mut connection, key, token, lease).await })
+            .await
+            .map_err(lock_error)
+    }
+
+    async fn set_if_lock_held(&self, lock_key: &str, token: u64, key: u64, value: Vec<u8>, duration: u64) -> Result<bool, CachingRedisError> {
+        self.run(|mut connection| {
+            let value = value.clone();
+            async move { set_if_lock_held(&mut connection, lock_key, token, key, value, duration).await }
+        })
+            .await
+            .map_err(lock_error)
+    }
+
+    async fn release_lock(&self, key: &str, token: u64) -> Result<bool, CachingRedisError> {
+        self.run(|mut connection| async move { release_lock(&mut connection, key, token).await })
+            .await
+            .map_err(lock_error)
+    }
+
+    async fn get_lock_holder(&self, key: &str) -> Result<Option<u64>, CachingRedisError> {
+        self.run(|mut connection| async move { get_lock_holder(&mut connection, key).await })
+            .await
+            .map_err(lock_error)
+    }
+}
+
+async fn next_fencing_token<C: ConnectionLike + Send>(connection: &mut C) -> RedisResult<u64> {
+    connection.incr(FENCING_TOKEN_KEY, 1u64).await
+}
+
+async fn try_acquire_lock<C: ConnectionLike + Send>(connection: &mut C, key: &str, token: u64, lease: Duration) -> RedisResult<bool> {
+    let options = redis::SetOptions::default()
+        .conditional_set(ExistenceCheck::NX)
+        .with_expiration(SetExpiry::PX(lease.as_millis() as u64));
+
+    let result: Option<String> = connection.set_options(key, token, options).await?;
+
+    Ok(result.is_some())
+}
+
+async fn renew_lock<C: ConnectionLike + Send>(connection: &mut C, key: &str, token: u64, lease: Duration) -> RedisResult<bool> {
+    let result: i64 = Script::new(RENEW_SCRIPT)
+        .key(key)
+        .arg(token)
+        .arg(lease.as_millis() as u64)
+        .invoke_async(connection)
+        .await?;
+
+    Ok(result == 1)
+}
+
+async fn set_if_lock_held<C: ConnectionLike + Send>(connection: &mut C, lock_key: &str, token: u64, key: u64, value: Vec<u8>, duration: u64) -> RedisResult<bool> {
+    let result: i64 = Script::new(SET_IF_HELD_SCRIPT)
+        .key(lock_key)
+        .key(key)
+        .arg(token)
+        .arg(value)
+        .arg(duration)
+        .invoke_async(connection)
+        .await?;
+
+    Ok(result == 1)
+}
+
+async fn release_lock<C: ConnectionLike + Send>(connection: &mut C, key: &str, token: u64) -> RedisResult<bool> {
+    let result: i64 = Script::new(RELEASE_SCRIPT)
+        .key(key)
+        .arg(token)
+        .invoke_async(connection)
+        .await?;
+
+    Ok(result == 1)
+}
+
+async fn get_lock_holder<C: ConnectionLike + Send>(connection: &mut C, key: &str) -> RedisResult<Option<u64>> {
+    connection.get(key).await
+}
+
+fn lock_error(error: RedisError) -> CachingRedisError {
+    CachingRedisError::CannotUseLock { description: format!("{}", error) }
+}
@@ -1,8 +1,12 @@
 pub mod caching_redis;
 pub mod client;
+pub mod cluster;
+pub mod sentinel;
 pub mod error;
+pub mod lock;
 
 pub use redis::ConnectionInfo;
 pub use redis::IntoConnectionInfo;
 pub use redis::RedisConnectionInfo;
-pub use redis::RedisError;
\ No newline at end of file
+pub use redis::RedisError;
+pub use redis::sentinel::SentinelNodeConnectionInfo;
\ No newline at end of file
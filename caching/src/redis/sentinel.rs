@@ -0,0 +1,159 @@
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use redis::aio::MultiplexedConnection;
+use redis::sentinel::{SentinelClient, SentinelNodeConnectionInfo, SentinelServerType};
+use redis::{ErrorKind, FromRedisValue, IntoConnectionInfo, RedisError, RedisResult, ToRedisArgs};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::redis::client::{clear_error, clear_values, get_error, get_value, set_error, set_value, RedisClient};
+use crate::redis::error::CachingRedisError;
+
+/// The master name used by `SentinelRedisClient::open`, the default one of the Redis sentinels.
+pub const DEFAULT_SENTINEL_SERVICE_NAME: &str = "mymaster";
+
+/// A client connected to the master of a Redis deployment monitored by sentinels.
+///
+/// When a command fails because the connection dropped or because the node has been demoted to a replica,
+/// the master is resolved again through the sentinels and the command is retried once.
+#[derive(Clone)]
+pub struct SentinelRedisClient {
+    sentinel: Arc<Mutex<SentinelClient>>,
+    connexion: Arc<RwLock<(u64, MultiplexedConnection)>>,
+}
+
+impl SentinelRedisClient {
+    /// Connects to the master named `service_name`, resolved through the given sentinel nodes.
+    pub async fn open_sentinel<Info: IntoConnectionInfo>(
+        sentinels: Vec<Info>,
+        service_name: &str,
+        node_connection_info: Option<SentinelNodeConnectionInfo>
+    ) -> Result<Self, CachingRedisError> {
+        let mut sentinel = match SentinelClient::build(sentinels, service_name.to_string(), node_connection_info, SentinelServerType::Master) {
+            Ok(sentinel) => sentinel,
+            Err(error) => return Err(CachingRedisError::CannotOpenConnection { description: format!("{}", error) })
+        };
+
+        let connection = match sentinel.get_async_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return Err(CachingRedisError::CannotGetConnection { description: format!("{}", error) })
+        };
+
+        Ok(
+            SentinelRedisClient {
+                sentinel: Arc::new(Mutex::new(sentinel)),
+                connexion: Arc::new(RwLock::new((0, connection)))
+            }
+        )
+    }
+
+    /// Runs `command` on the current master connection, reconnecting and retrying once if the master is unreachable.
+    pub(crate) async fn run<T, F, Fut>(&self, command: F) -> RedisResult<T>
+    where
+        F: Fn(MultiplexedConnection) -> Fut + Send + Sync,
+        Fut: Future<Output = RedisResult<T>> + Send
+    {
+        let (generation, connection) = self.connexion.read().await.clone();
+
+        match command(connection).await {
+            Err(error) if should_reconnect(&error) => {
+                let connection = self.reconnect(generation).await?;
+                command(connection).await
+            },
+            result => result
+        }
+    }
+
+    async fn reconnect(&self, failed_generation: u64) -> RedisResult<MultiplexedConnection> {
+        let mut sentinel = self.sentinel.lock().await;
+
+        {
+            let (generation, connection) = &*self.connexion.read().await;
+            if *generation != failed_generation {
+                // Another task already reconnected while we were waiting for the sentinel
+                return Ok(connection.clone());
+            }
+        }
+
+        let connection = sentinel.get_async_connection().await?;
+        *self.connexion.write().await = (failed_generation + 1, connection.clone());
+
+        Ok(connection)
+    }
+}
+
+impl Debug for SentinelRedisClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SentinelRedisClient").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl RedisClient for SentinelRedisClient {
+    /// Connects to the master named `DEFAULT_SENTINEL_SERVICE_NAME` through a single sentinel node.
+    /// Use `SentinelRedisClient::open_sentinel` to provide several sentinels or another master name.
+    async fn open<Info: IntoConnectionInfo + Send + Sync>(info: Info) -> Result<Self, CachingRedisError> {
+        Self::open_sentinel(vec![info], DEFAULT_SENTINEL_SERVICE_NAME, None).await
+    }
+
+    async fn set<K: ToRedisArgs + Send + Sync, V: ToRedisArgs + Send + Sync>(&self, key: K, value: V, duration: u64) -> Result<(), CachingRedisError> {
+        self.run(|mut connection| {
+            let (key, value) = (&key, &value);
+            async move { set_value(&mut connection, key, value, duration).await }
+        })
+            .await
+            .map_err(set_error)
+    }
+
+    async fn get<K: ToRedisArgs + Send + Sync, RV: FromRedisValue + Send + Sync>(&self, key: K) -> Result<Option<RV>, CachingRedisError> {
+        self.run(|mut connection| {
+            let key = &key;
+            async move { get_value(&mut connection, key).await }
+        })
+            .await
+            .map_err(get_error)
+    }
+
+    async fn clear(&self) -> Result<(), CachingRedisError> {
+        self.run(|mut connection| async move { clear_values(&mut connection).await })
+            .await
+            .map_err(clear_error)
+    }
+}
+
+fn should_reconnect(error: &RedisError) -> bool {
+    error.is_io_error()
+        || error.is_connection_dropped()
+        || error.is_connection_refusal()
+        || error.kind() == ErrorKind::ReadOnly
+}
+
+#[cfg(test)]
+mod tests {
+    use redis::{ErrorKind, RedisError};
+
+    use crate::redis::sentinel::should_reconnect;
+
+    #[test]
+    fn test_should_reconnect_connection_dropped() {
+        let error = RedisError::from(std::io::Error::from(std::io::ErrorKind::BrokenPipe));
+
+        assert!(should_reconnect(&error));
+    }
+
+    #[test]
+    fn test_should_reconnect_demoted_master() {
+        let error = RedisError::from((ErrorKind::ReadOnly, "You can't write against a read only replica."));
+
+        assert!(should_reconnect(&error));
+    }
+
+    #[test]
+    fn test_should_not_reconnect_type_error() {
+        let error = RedisError::from((ErrorKind::TypeError, "Response was of incompatible type"));
+
+        assert!(!should_reconnect(&error));
+    }
+}
@@ -0,0 +1,113 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use novax::errors::CachingError;
+
+use crate::serialization::fingerprint::schema_fingerprint;
+use crate::serialization::format::CachingSerializationFormat;
+
+const ENVELOPE_MAGIC: &[u8; 3] = b"NVX";
+const ENVELOPE_VERSION: u8 = 1;
+const ENVELOPE_HEADER_LENGTH: usize = ENVELOPE_MAGIC.len() + 1 + 1 + 8;
+
+/// Serializes `value` with `format`, prefixed by a header holding the format and the schema fingerprint of `T`.
+///
+/// The header is made of the `NVX` magic bytes, the envelope version, the format identifier and the big-endian fingerprint.
+pub fn encode_envelope<T: Serialize + DeserializeOwned>(format: CachingSerializationFormat, value: &T) -> Result<Vec<u8>, CachingError> {
+    let payload = format.serialize(value)?;
+
+    let mut encoded = Vec::with_capacity(ENVELOPE_HEADER_LENGTH + payload.len());
+    encoded.extend_from_slice(ENVELOPE_MAGIC);
+    encoded.push(ENVELOPE_VERSION);
+    encoded.push(format.get_id());
+    encoded.extend_from_slice(&schema_fingerprint::<T>().to_be_bytes());
+    encoded.extend_from_slice(&payload);
+
+    Ok(encoded)
+}
+
+/// Deserializes an entry written by `encode_envelope`, with the format it has been written with.
+///
+/// Returns `None` if the entry has no envelope, has been written by an unknown envelope version or format,
+/// or has been written for a type whose schema differs from `T`'s.
+pub fn decode_envelope<T: DeserializeOwned>(encoded: &[u8]) -> Result<Option<T>, CachingError> {
+    if encoded.len() < ENVELOPE_HEADER_LENGTH || &encoded[..ENVELOPE_MAGIC.len()] != ENVELOPE_MAGIC {
+        return Ok(None);
+    }
+
+    let (header, payload) = encoded.split_at(ENVELOPE_HEADER_LENGTH);
+
+    if header[3] != ENVELOPE_VERSION {
+        return Ok(None);
+    }
+
+    let Some(format) = CachingSerializationFormat::from_id(header[4]) else {
+        return Ok(None);
+    };
+
+    let mut fingerprint = [0u8; 8];
+    fingerprint.copy_from_slice(&header[5..]);
+
+    if u64::from_be_bytes(fingerprint) != schema_fingerprint::<T>() {
+        return Ok(None);
+    }
+
+    format.deserialize(payload).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use novax::errors::CachingError;
+
+    use crate::serialization::envelope::{decode_envelope, encode_envelope, ENVELOPE_HEADER_LENGTH};
+    use crate::serialization::format::CachingSerializationFormat;
+
+    #[test]
+    fn test_round_trip() {
+        for format in [CachingSerializationFormat::MessagePack, CachingSerializationFormat::Bincode, CachingSerializationFormat::Json] {
+            let encoded = encode_envelope(format, &"test".to_string()).unwrap();
+
+            let result = decode_envelope::<String>(&encoded).unwrap();
+
+            assert_eq!(result, Some("test".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_other_type_is_a_miss() {
+        let encoded = encode_envelope(CachingSerializationFormat::MessagePack, &"test".to_string()).unwrap();
+
+        let result = decode_envelope::<u64>(&encoded).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_legacy_entry_is_a_miss() {
+        let encoded = rmp_serde::to_vec(&"test".to_string()).unwrap();
+
+        let result = decode_envelope::<String>(&encoded).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_unknown_format_is_a_miss() {
+        let mut encoded = encode_envelope(CachingSerializationFormat::Json, &"test".to_string()).unwrap();
+        encoded[4] = 42;
+
+        let result = decode_envelope::<String>(&encoded).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_corrupted_payload() {
+        let mut encoded = encode_envelope(CachingSerializationFormat::Json, &"test".to_string()).unwrap();
+        encoded.truncate(ENVELOPE_HEADER_LENGTH + 2);
+
+        let result = decode_envelope::<String>(&encoded);
+
+        assert_eq!(result, Err(CachingError::UnableToDeserialize));
+    }
+}
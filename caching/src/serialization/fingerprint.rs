@@ -0,0 +1,718 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use serde::de::{DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::Deserializer;
+
+/// The nested types are only traced until this depth, which also stops the recursive types.
+const MAX_DEPTH: usize = 16;
+
+/// Written in place of the types nested deeper than `MAX_DEPTH`.
+const DEPTH_LIMIT_MARKER: &str = "...;";
+
+/// The maximum number of times all the variants are traced again, each time knowing more about the enums.
+const MAX_PASSES: usize = 8;
+
+/// The maximum number of times a run is traced again, each time skipping the struct fields rejecting the traced values.
+const MAX_SKIPPING_PASSES: usize = 64;
+
+/// Written after the partial trace of a skipped struct field.
+const SKIPPED_FIELD_MARKER: &str = "!;";
+
+static FINGERPRINTS: RwLock<BTreeMap<&'static str, u64>> = RwLock::new(BTreeMap::new());
+
+static SCHEMA_VERSION: AtomicU64 = AtomicU64::new(0);
+
+/// Sets the schema version included in the fingerprints of the types which can't be fully traced, 0 by default.
+///
+/// A type with a custom `Deserialize` implementation rejecting the traced values, such as `novax_data::Address`, can't be traced.
+/// The fingerprint of the types containing it then also depends on their `std::any::type_name` and on this version,
+/// which has to be bumped when such a type changes without its name changing, so the entries written before the change are ignored.
+pub fn set_schema_version(version: u64) {
+    SCHEMA_VERSION.store(version, Ordering::Relaxed);
+
+    FINGERPRINTS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+/// Returns a fingerprint of the shape of `T`: the names and fields of the structs and enums it is made of, and the payload of every variant.
+///
+/// The shape is traced by deserializing `T` from a deserializer recording what it is asked for, once for each variant of each enum.
+/// A struct field rejecting the traced values is skipped and its struct traced again, so the following fields are still traced.
+///
+/// The fingerprint only depends on the traced shape, not on `std::any::type_name` which isn't guaranteed to be stable,
+/// so it is stable across processes, builds and toolchains, and can be stored alongside shared cache entries.
+/// The only exception are the types for which a custom `Deserialize` implementation rejected a traced value, see [`set_schema_version`].
+pub fn schema_fingerprint<T: DeserializeOwned>() -> u64 {
+    let type_name = std::any::type_name::<T>();
+
+    if let Some(fingerprint) = FINGERPRINTS.read().unwrap_or_else(|poisoned| poisoned.into_inner()).get(type_name) {
+        return *fingerprint;
+    }
+
+    let (trace, is_complete) = trace_schema_completeness::<T>();
+
+    let fingerprint = if is_complete {
+        fnv1a(trace.as_bytes())
+    } else {
+        fnv1a(format!("{trace}|{type_name}|{}", SCHEMA_VERSION.load(Ordering::Relaxed)).as_bytes())
+    };
+
+    FINGERPRINTS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(type_name, fingerprint);
+
+    fingerprint
+}
+
+#[cfg(test)]
+fn trace_schema<T: DeserializeOwned>() -> String {
+    trace_schema_completeness::<T>().0
+}
+
+/// Traces `T` once, then once for each variant of each discovered enum, until no more enums or terminating variants are discovered.
+///
+/// Also returns whether no traced value has been rejected.
+fn trace_schema_completeness<T: DeserializeOwned>() -> (String, bool) {
+    let mut context = TraceContext::default();
+    let mut traces = vec![trace_run::<T>(&mut context, BTreeMap::new())];
+
+    for _ in 0..MAX_PASSES {
+        if context.discovered.is_empty() {
+            break;
+        }
+
+        let previous_knowledge = (context.discovered.clone(), context.terminating.clone());
+
+        traces = vec![];
+        for (name, (variants_count, path)) in previous_knowledge.0.iter() {
+            for index in 0..*variants_count {
+                let mut selections = path.clone();
+                selections.insert(name, index);
+
+                traces.push(trace_run::<T>(&mut context, selections));
+            }
+        }
+
+        if previous_knowledge == (context.discovered.clone(), context.terminating.clone()) {
+            break;
+        }
+    }
+
+    (traces.join("|"), !context.has_rejection)
+}
+
+/// Traces `T` with the given enum variants, again as long as new struct fields to skip are discovered.
+fn trace_run<T: DeserializeOwned>(context: &mut TraceContext, selections: BTreeMap<&'static str, usize>) -> String {
+    context.selections = selections;
+
+    for _ in 0..MAX_SKIPPING_PASSES {
+        let skipped_fields_count = context.skipped_fields.len();
+
+        context.trace.clear();
+        context.stack.clear();
+
+        let result = T::deserialize(SchemaTracer { context: &mut *context, depth: 0 });
+
+        if matches!(result, Err(TraceError { kind: TraceErrorKind::Rejected, .. })) {
+            context.has_rejection = true;
+        }
+
+        if context.skipped_fields.len() == skipped_fields_count {
+            break;
+        }
+    }
+
+    std::mem::take(&mut context.trace)
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    hash
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum TraceErrorKind {
+    /// The tracing stopped on purpose, e.g. at the maximum depth.
+    Stopped,
+    /// A `Deserialize` implementation rejected a traced value.
+    Rejected,
+    /// A struct misses the fields skipped because they rejected the traced values.
+    MissingField
+}
+
+#[derive(Debug)]
+struct TraceError {
+    kind: TraceErrorKind,
+    message: String
+}
+
+impl TraceError {
+    fn stopped(message: String) -> Self {
+        TraceError {
+            kind: TraceErrorKind::Stopped,
+            message
+        }
+    }
+}
+
+impl Display for TraceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+impl serde::de::Error for TraceError {
+    fn custom<M: Display>(msg: M) -> Self {
+        TraceError {
+            kind: TraceErrorKind::Rejected,
+            message: msg.to_string()
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        TraceError {
+            kind: TraceErrorKind::MissingField,
+            message: format!("missing field `{field}`")
+        }
+    }
+}
+
+/// What is known about the traced type, kept across the runs.
+#[derive(Default)]
+struct TraceContext {
+    trace: String,
+    /// The variant traced for each enum during the current run.
+    selections: BTreeMap<&'static str, usize>,
+    /// The enums being traced, from the outermost one.
+    stack: Vec<&'static str>,
+    /// The number of variants of each discovered enum, and the selections leading to it.
+    discovered: BTreeMap<&'static str, (usize, BTreeMap<&'static str, usize>)>,
+    /// A variant of each enum whose payload has been fully traced, used for the enums nested in themselves.
+    terminating: BTreeMap<&'static str, usize>,
+    /// The struct fields rejecting the traced values, by struct and field names, with their trace up to the rejection.
+    skipped_fields: BTreeMap<(&'static str, &'static str), String>,
+    /// Whether a `Deserialize` implementation rejected a traced value.
+    has_rejection: bool
+}
+
+struct SchemaTracer<'a> {
+    context: &'a mut TraceContext,
+    depth: usize
+}
+
+impl SchemaTracer<'_> {
+    fn nested(&mut self) -> SchemaTracer<'_> {
+        SchemaTracer {
+            context: self.context,
+            depth: self.depth + 1
+        }
+    }
+
+    fn can_go_deeper(&self) -> bool {
+        self.depth < MAX_DEPTH
+    }
+
+    fn depth_limit_reached(self) -> TraceError {
+        self.context.trace.push_str(DEPTH_LIMIT_MARKER);
+
+        TraceError::stopped("maximum depth reached".to_string())
+    }
+}
+
+macro_rules! trace_primitive {
+    ($method:ident, $visit:ident, $name:literal, $value:expr) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            self.context.trace.push_str(concat!($name, ";"));
+            visitor.$visit($value)
+        }
+    };
+}
+
+impl<'de> Deserializer<'de> for SchemaTracer<'_> {
+    type Error = TraceError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.context.trace.push_str("any;");
+        visitor.visit_unit()
+    }
+
+    trace_primitive!(deserialize_bool, visit_bool, "bool", false);
+    trace_primitive!(deserialize_i8, visit_i8, "i8", 0);
+    trace_primitive!(deserialize_i16, visit_i16, "i16", 0);
+    trace_primitive!(deserialize_i32, visit_i32, "i32", 0);
+    trace_primitive!(deserialize_i64, visit_i64, "i64", 0);
+    trace_primitive!(deserialize_i128, visit_i128, "i128", 0);
+    trace_primitive!(deserialize_u8, visit_u8, "u8", 0);
+    trace_primitive!(deserialize_u16, visit_u16, "u16", 0);
+    trace_primitive!(deserialize_u32, visit_u32, "u32", 0);
+    trace_primitive!(deserialize_u64, visit_u64, "u64", 0);
+    trace_primitive!(deserialize_u128, visit_u128, "u128", 0);
+    trace_primitive!(deserialize_f32, visit_f32, "f32", 0.0);
+    trace_primitive!(deserialize_f64, visit_f64, "f64", 0.0);
+    trace_primitive!(deserialize_char, visit_char, "char", '\0');
+    trace_primitive!(deserialize_str, visit_str, "str", "");
+    trace_primitive!(deserialize_string, visit_str, "str", "");
+    trace_primitive!(deserialize_bytes, visit_bytes, "bytes", &[]);
+    trace_primitive!(deserialize_byte_buf, visit_bytes, "bytes", &[]);
+    trace_primitive!(deserialize_identifier, visit_str, "identifier", "");
+
+    fn deserialize_option<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Self::Error> {
+        if !self.can_go_deeper() {
+            self.context.trace.push_str("option;");
+            return visitor.visit_none();
+        }
+
+        self.context.trace.push_str("option<");
+        let result = visitor.visit_some(self.nested());
+        self.context.trace.push('>');
+
+        result
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.context.trace.push_str("unit;");
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        self.context.trace.push_str(name);
+        self.context.trace.push(';');
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(mut self, name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        if !self.can_go_deeper() {
+            return Err(self.depth_limit_reached());
+        }
+
+        self.context.trace.push_str(name);
+        self.context.trace.push('(');
+        let result = visitor.visit_newtype_struct(self.nested());
+        self.context.trace.push(')');
+
+        result
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Self::Error> {
+        let remaining = if self.can_go_deeper() { 1 } else { 0 };
+
+        self.context.trace.push_str("seq<");
+        let result = visitor.visit_seq(SeqTracer { tracer: &mut self, remaining });
+        self.context.trace.push('>');
+
+        result
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(mut self, len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        if !self.can_go_deeper() {
+            return Err(self.depth_limit_reached());
+        }
+
+        self.context.trace.push_str(&format!("tuple{len}<"));
+        let result = visitor.visit_seq(SeqTracer { tracer: &mut self, remaining: len });
+        self.context.trace.push('>');
+
+        result
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(mut self, name: &'static str, len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        if !self.can_go_deeper() {
+            return Err(self.depth_limit_reached());
+        }
+
+        self.context.trace.push_str(&format!("{name}{len}<"));
+        let result = visitor.visit_seq(SeqTracer { tracer: &mut self, remaining: len });
+        self.context.trace.push('>');
+
+        result
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Self::Error> {
+        let remaining = if self.can_go_deeper() { 1 } else { 0 };
+
+        self.context.trace.push_str("map<");
+        let result = visitor.visit_map(MapTracer { tracer: &mut self, remaining });
+        self.context.trace.push('>');
+
+        result
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(mut self, name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        if !self.can_go_deeper() {
+            return Err(self.depth_limit_reached());
+        }
+
+        self.context.trace.push_str(&format!("{name}{{{}}}<", fields.join(",")));
+        let result = visitor.visit_map(StructTracer { tracer: &mut self, name, fields: fields.iter(), current_field: "", current_field_start: 0 });
+        self.context.trace.push('>');
+
+        result
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, name: &'static str, variants: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        if !self.can_go_deeper() {
+            return Err(self.depth_limit_reached());
+        }
+
+        if variants.is_empty() {
+            return Err(TraceError::stopped(format!("{name} has no variant")));
+        }
+
+        let context = self.context;
+
+        context.discovered
+            .entry(name)
+            .or_insert_with(|| (variants.len(), context.selections.clone()));
+
+        // An enum nested in itself uses a variant known to terminate, so the recursion stops
+        let opt_index = if context.stack.contains(&name) {
+            context.terminating.get(name).or(context.selections.get(name))
+        } else {
+            context.selections.get(name)
+        };
+        let index = opt_index.copied().unwrap_or(0).min(variants.len() - 1);
+        let variant = variants[index];
+
+        context.trace.push_str(&format!("{name}[{}]<{variant}:", variants.join(",")));
+
+        context.stack.push(name);
+        let result = visitor.visit_enum(EnumTracer { tracer: SchemaTracer { context: &mut *context, depth: self.depth }, variant });
+        context.stack.pop();
+
+        if result.is_ok() {
+            context.terminating.entry(name).or_insert(index);
+        }
+
+        result
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
+
+/// Yields `remaining` traced elements.
+struct SeqTracer<'b, 'a> {
+    tracer: &'b mut SchemaTracer<'a>,
+    remaining: usize
+}
+
+impl<'de> SeqAccess<'de> for SeqTracer<'_, '_> {
+    type Error = TraceError;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(self.tracer.nested()).map(Some)
+    }
+}
+
+/// Yields `remaining` traced entries.
+struct MapTracer<'b, 'a> {
+    tracer: &'b mut SchemaTracer<'a>,
+    remaining: usize
+}
+
+impl<'de> MapAccess<'de> for MapTracer<'_, '_> {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(self.tracer.nested()).map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, Self::Error> {
+        seed.deserialize(self.tracer.nested())
+    }
+}
+
+/// Yields each field of a struct with a traced value, except the ones known to reject the traced values.
+struct StructTracer<'b, 'a> {
+    tracer: &'b mut SchemaTracer<'a>,
+    name: &'static str,
+    fields: std::slice::Iter<'static, &'static str>,
+    current_field: &'static str,
+    current_field_start: usize
+}
+
+impl<'de> MapAccess<'de> for StructTracer<'_, '_> {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        loop {
+            let Some(field) = self.fields.next() else {
+                return Ok(None);
+            };
+
+            // A skipped field is left missing, the struct then fails once all the other fields are traced
+            let context = &mut *self.tracer.context;
+            if let Some(skipped_trace) = context.skipped_fields.get(&(self.name, *field)) {
+                context.trace.push_str(skipped_trace);
+                context.trace.push_str(SKIPPED_FIELD_MARKER);
+                continue;
+            }
+
+            self.current_field = field;
+
+            return seed.deserialize((*field).into_deserializer()).map(Some);
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, Self::Error> {
+        self.current_field_start = self.tracer.context.trace.len();
+
+        let result = seed.deserialize(self.tracer.nested());
+
+        if let Err(error) = &result {
+            if error.kind != TraceErrorKind::Stopped {
+                let context = &mut *self.tracer.context;
+                let field_trace = context.trace[self.current_field_start..].to_string();
+
+                context.has_rejection |= error.kind == TraceErrorKind::Rejected;
+                context.skipped_fields.entry((self.name, self.current_field)).or_insert(field_trace);
+            }
+        }
+
+        result
+    }
+}
+
+/// Selects a variant of an enum and traces its content.
+struct EnumTracer<'a> {
+    tracer: SchemaTracer<'a>,
+    variant: &'static str
+}
+
+impl<'de> EnumAccess<'de> for EnumTracer<'_> {
+    type Error = TraceError;
+    type Variant = Self;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> Result<(S::Value, Self::Variant), Self::Error> {
+        let value = seed.deserialize(self.variant.into_deserializer())?;
+
+        Ok((value, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for EnumTracer<'_> {
+    type Error = TraceError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.tracer.context.trace.push('>');
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(mut self, seed: S) -> Result<S::Value, Self::Error> {
+        let result = seed.deserialize(self.tracer.nested());
+        self.tracer.context.trace.push('>');
+
+        result
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(mut self, len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        let result = visitor.visit_seq(SeqTracer { tracer: &mut self.tracer, remaining: len });
+        self.tracer.context.trace.push('>');
+
+        result
+    }
+
+    fn struct_variant<V: Visitor<'de>>(mut self, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        self.tracer.context.trace.push_str(&format!("{{{}}}", fields.join(",")));
+        let result = visitor.visit_map(StructTracer { tracer: &mut self.tracer, name: self.variant, fields: fields.iter(), current_field: "", current_field_start: 0 });
+        self.tracer.context.trace.push('>');
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use novax::Address;
+    use serde::Deserialize;
+
+    use crate::serialization::fingerprint::{schema_fingerprint, set_schema_version, trace_schema, trace_schema_completeness};
+
+    mod v1 {
+        use novax::Address;
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        pub struct Pair {
+            pub _first: u64,
+            pub _second: String
+        }
+
+        #[derive(Deserialize)]
+        pub enum Status {
+            _Active,
+            _Paused
+        }
+
+        #[allow(dead_code)]
+        #[derive(Deserialize)]
+        pub enum Message {
+            _Ping,
+            _Data(u64)
+        }
+
+        #[derive(Deserialize)]
+        pub struct Owned {
+            pub _owner: Address,
+            pub _amount: u64
+        }
+    }
+
+    mod v2 {
+        use novax::Address;
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        pub struct Pair {
+            pub _first: u64,
+            pub _second: String,
+            pub _third: Option<bool>
+        }
+
+        #[derive(Deserialize)]
+        pub enum Status {
+            _Active,
+            _Paused,
+            _Closed
+        }
+
+        #[allow(dead_code)]
+        #[derive(Deserialize)]
+        pub enum Message {
+            _Ping,
+            _Data(String)
+        }
+
+        #[derive(Deserialize)]
+        pub struct Owned {
+            pub _owner: Address,
+            pub _amount: String
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Tree {
+        _value: u8,
+        _children: Vec<Tree>,
+        _parent: Option<Box<Tree>>
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    enum Expr {
+        _Neg(Box<Expr>),
+        _Lit(u64)
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct Wrapper(Box<Wrapper>);
+
+    #[test]
+    fn test_fingerprint_is_deterministic() {
+        assert_eq!(schema_fingerprint::<HashMap<String, Vec<u64>>>(), schema_fingerprint::<HashMap<String, Vec<u64>>>());
+    }
+
+    #[test]
+    fn test_fingerprint_differs_between_types() {
+        assert_ne!(schema_fingerprint::<u64>(), schema_fingerprint::<String>());
+        assert_ne!(schema_fingerprint::<Vec<u64>>(), schema_fingerprint::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_trace_struct() {
+        assert_eq!(trace_schema::<v1::Pair>(), "Pair{_first,_second}<u64;str;>");
+        assert_eq!(trace_schema::<v2::Pair>(), "Pair{_first,_second,_third}<u64;str;option<bool;>>");
+    }
+
+    #[test]
+    fn test_trace_enum() {
+        assert_eq!(trace_schema::<v1::Status>(), "Status[_Active,_Paused]<_Active:>|Status[_Active,_Paused]<_Paused:>");
+        assert_ne!(schema_fingerprint::<v1::Status>(), schema_fingerprint::<v2::Status>());
+    }
+
+    #[test]
+    fn test_trace_collections() {
+        assert_eq!(trace_schema::<HashMap<String, Vec<u64>>>(), "map<str;seq<u64;>>");
+        assert_eq!(trace_schema::<(u8, bool)>(), "tuple2<u8;bool;>");
+    }
+
+    #[test]
+    fn test_trace_recursive_type() {
+        let result = trace_schema::<Tree>();
+
+        assert!(result.starts_with("Tree{_value,_children,_parent}<u8;seq<Tree{_value,_children,_parent}<u8;seq<"));
+    }
+
+    #[test]
+    fn test_trace_every_variant_payload() {
+        assert_eq!(trace_schema::<v1::Message>(), "Message[_Ping,_Data]<_Ping:>|Message[_Ping,_Data]<_Data:u64;>");
+        assert_ne!(schema_fingerprint::<v1::Message>(), schema_fingerprint::<v2::Message>());
+    }
+
+    #[test]
+    fn test_trace_recursive_enum() {
+        let result = trace_schema::<Expr>();
+
+        assert_eq!(result, "Expr[_Neg,_Lit]<_Neg:Expr[_Neg,_Lit]<_Lit:u64;>>|Expr[_Neg,_Lit]<_Lit:u64;>");
+    }
+
+    #[test]
+    fn test_trace_stops_at_max_depth() {
+        let result = trace_schema::<Wrapper>();
+
+        assert!(result.ends_with("Wrapper(...;))))))))))))))))"));
+    }
+
+    #[test]
+    fn test_trace_fields_after_rejected_field() {
+        let (result, is_complete) = trace_schema_completeness::<v1::Owned>();
+
+        assert_eq!(result, "Owned{_owner,_amount}<str;!;u64;>");
+        assert!(!is_complete);
+        assert_ne!(schema_fingerprint::<v1::Owned>(), schema_fingerprint::<v2::Owned>());
+    }
+
+    #[test]
+    fn test_fingerprint_of_rejected_tuple_uses_type_name() {
+        assert_ne!(schema_fingerprint::<(Address, u64)>(), schema_fingerprint::<(Address, String)>());
+    }
+
+    #[test]
+    fn test_fingerprint_of_rejected_type_uses_schema_version() {
+        let before = schema_fingerprint::<Address>();
+
+        set_schema_version(1);
+        let after = schema_fingerprint::<Address>();
+        set_schema_version(0);
+
+        assert_ne!(before, after);
+        assert_eq!(schema_fingerprint::<u64>(), schema_fingerprint::<u64>());
+    }
+}
@@ -0,0 +1,103 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use novax::errors::CachingError;
+
+/// The format used to serialize the cached values.
+///
+/// Each entry records the format it has been written with, so entries written with another format can still be read.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum CachingSerializationFormat {
+    /// Compact and self-describing, the default one.
+    #[default]
+    MessagePack,
+    /// The most compact one, but not self-describing.
+    Bincode,
+    /// Human-readable, handy to inspect the entries of a shared cache.
+    Json
+}
+
+impl CachingSerializationFormat {
+    /// The identifier of the format stored in the envelope of each entry.
+    pub fn get_id(&self) -> u8 {
+        match self {
+            CachingSerializationFormat::MessagePack => 1,
+            CachingSerializationFormat::Bincode => 2,
+            CachingSerializationFormat::Json => 3
+        }
+    }
+
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(CachingSerializationFormat::MessagePack),
+            2 => Some(CachingSerializationFormat::Bincode),
+            3 => Some(CachingSerializationFormat::Json),
+            _ => None
+        }
+    }
+
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, CachingError> {
+        let result = match self {
+            CachingSerializationFormat::MessagePack => rmp_serde::to_vec(value).ok(),
+            CachingSerializationFormat::Bincode => bincode::serialize(value).ok(),
+            CachingSerializationFormat::Json => serde_json::to_vec(value).ok()
+        };
+
+        result.ok_or(CachingError::UnableToSerialize)
+    }
+
+    pub fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, CachingError> {
+        let result = match self {
+            CachingSerializationFormat::MessagePack => rmp_serde::from_slice(bytes).ok(),
+            CachingSerializationFormat::Bincode => bincode::deserialize(bytes).ok(),
+            CachingSerializationFormat::Json => serde_json::from_slice(bytes).ok()
+        };
+
+        result.ok_or(CachingError::UnableToDeserialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use novax::errors::CachingError;
+
+    use crate::serialization::format::CachingSerializationFormat;
+
+    const FORMATS: [CachingSerializationFormat; 3] = [
+        CachingSerializationFormat::MessagePack,
+        CachingSerializationFormat::Bincode,
+        CachingSerializationFormat::Json
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for format in FORMATS {
+            let value = (1u64, "test".to_string(), vec![Some(true), None]);
+            let encoded = format.serialize(&value).unwrap();
+
+            let result: (u64, String, Vec<Option<bool>>) = format.deserialize(&encoded).unwrap();
+
+            assert_eq!(result, value);
+        }
+    }
+
+    #[test]
+    fn test_id_round_trip() {
+        for format in FORMATS {
+            assert_eq!(CachingSerializationFormat::from_id(format.get_id()), Some(format));
+        }
+
+        assert_eq!(CachingSerializationFormat::from_id(0), None);
+    }
+
+    #[test]
+    fn test_json_unable_to_serialize() {
+        let value = HashMap::from([(vec![1u8], 1u8)]);
+
+        let result = CachingSerializationFormat::Json.serialize(&value);
+
+        assert_eq!(result, Err(CachingError::UnableToSerialize));
+    }
+}
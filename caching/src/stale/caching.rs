@@ -0,0 +1,587 @@
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use novax::caching::{CachingDurationStrategy, CachingStrategy};
+use novax::errors::NovaXError;
+
+use crate::date::get_current_timestamp::{get_current_timestamp, GetDuration};
+use crate::locked::redis::FencedCachingStrategy;
+use crate::serialization::envelope::{decode_envelope, encode_envelope};
+use crate::serialization::format::CachingSerializationFormat;
+use crate::utils::lock::MutexLike;
+
+#[allow(type_alias_bounds)]
+pub type CachingStale<C: CachingStrategy> = BaseCachingStale<C, Mutex<HashSet<u64>>>;
+
+/// The value stored in the wrapped caching strategy, alongside the timestamp until which it is fresh.
+///
+/// The value is enveloped with MessagePack, the wrapped strategy then serializes the whole entry with its own format.
+#[derive(Serialize, Deserialize)]
+struct StaleEntry {
+    fresh_until: Duration,
+    value: Vec<u8>
+}
+
+enum Lookup<T> {
+    Missing,
+    Fresh(T),
+    Stale(T)
+}
+
+/// A caching strategy wrapper that keeps the expired values for a grace period.
+///
+/// A value is fresh until the duration strategy expires, then stale until the grace period is over.
+/// When a stale value is found, `get_or_refresh_cache_in_background` returns it right away and refreshes it in a spawned task,
+/// which is what the generated queries do.
+///
+/// The getter given to `get_or_set_cache` borrows its environment and cannot outlive the call, so with this method
+/// the stale value is returned right away to all the callers but one, which runs the getter to refresh it.
+/// If the getter fails, the stale value is returned instead of the error.
+///
+/// `get_cache` only returns fresh values, a wrapper serves the stale ones through `get_or_refresh_cache_in_background`,
+/// or through `get_stale_cache` for a `CachingLockedRedis`.
+pub struct BaseCachingStale<C, M>
+where
+    C: CachingStrategy,
+    M: MutexLike<T = HashSet<u64>>,
+{
+    pub caching: C,
+    pub duration_strategy: CachingDurationStrategy,
+    pub grace_period: Duration,
+    refreshing_keys: Arc<M>
+}
+
+impl<C, M> Clone for BaseCachingStale<C, M>
+where
+    C: CachingStrategy,
+    M: MutexLike<T = HashSet<u64>>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            caching: self.caching.clone(),
+            duration_strategy: self.duration_strategy.clone(),
+            grace_period: self.grace_period,
+            refreshing_keys: self.refreshing_keys.clone(),
+        }
+    }
+}
+
+impl<C, M> Debug for BaseCachingStale<C, M>
+where
+    C: CachingStrategy,
+    M: MutexLike<T = HashSet<u64>>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BaseCachingStale")
+            .field("caching", &self.caching)
+            .field("duration_strategy", &self.duration_strategy)
+            .field("grace_period", &self.grace_period)
+            .field("refreshing_keys", &self.refreshing_keys)
+            .finish()
+    }
+}
+
+impl<C, M> BaseCachingStale<C, M>
+where
+    C: CachingStrategy,
+    M: MutexLike<T = HashSet<u64>>,
+{
+    /// Wraps `caching`, values are fresh according to `duration_strategy` then kept stale for `grace_period`.
+    pub fn new(caching: C, duration_strategy: CachingDurationStrategy, grace_period: Duration) -> BaseCachingStale<C, M> {
+        BaseCachingStale {
+            caching,
+            duration_strategy,
+            grace_period,
+            refreshing_keys: Arc::new(M::new(HashSet::new()))
+        }
+    }
+
+    async fn lookup<T: DeserializeOwned>(&self, key: u64) -> Result<Lookup<T>, NovaXError> {
+        let Some(entry) = self.caching.get_cache::<StaleEntry>(key).await? else {
+            return Ok(Lookup::Missing);
+        };
+
+        let Some(value) = decode_envelope(&entry.value)? else {
+            return Ok(Lookup::Missing);
+        };
+
+        if get_current_timestamp()? < entry.fresh_until {
+            Ok(Lookup::Fresh(value))
+        } else {
+            Ok(Lookup::Stale(value))
+        }
+    }
+
+    /// Returns the entry to store for `value`, and the wrapped caching strategy keeping it until the end of the grace period.
+    fn new_entry<T: Serialize + DeserializeOwned>(&self, value: &T) -> Result<(StaleEntry, C), NovaXError> {
+        let current_timestamp = get_current_timestamp()?;
+        let fresh_until = self.duration_strategy.get_duration_timestamp(&current_timestamp)?;
+        let encoded = encode_envelope(CachingSerializationFormat::MessagePack, value)?;
+
+        let entry = StaleEntry {
+            fresh_until,
+            value: encoded
+        };
+        let caching = self.caching
+            .with_duration_strategy(CachingDurationStrategy::Duration(fresh_until - current_timestamp + self.grace_period));
+
+        Ok((entry, caching))
+    }
+
+    async fn set_value<T: Serialize + DeserializeOwned>(&self, key: u64, value: &T) -> Result<(), NovaXError> {
+        let (entry, caching) = self.new_entry(value)?;
+
+        caching.set_cache(key, &entry).await
+    }
+
+    /// Returns a guard if the caller is in charge of refreshing the key, `None` if another caller already is.
+    async fn try_begin_refresh(&self, key: u64) -> Option<RefreshGuard<M>>
+    where
+        M: 'static
+    {
+        if !self.refreshing_keys.lock().await.insert(key) {
+            return None;
+        }
+
+        Some(RefreshGuard {
+            refreshing_keys: self.refreshing_keys.clone(),
+            key,
+            is_released: false
+        })
+    }
+
+    async fn refresh<T, FutureGetter, Error>(&self, refresh_guard: RefreshGuard<M>, getter: FutureGetter, stale_value: T) -> Result<T, Error>
+    where
+        M: 'static,
+        T: Serialize + DeserializeOwned,
+        FutureGetter: Future<Output=Result<T, Error>>,
+        Error: From<NovaXError>
+    {
+        let Some(value) = getter.await.ok() else {
+            refresh_guard.release().await;
+            return Ok(stale_value);
+        };
+
+        let result = self.set_value(refresh_guard.key, &value).await;
+        refresh_guard.release().await;
+        result?;
+
+        Ok(value)
+    }
+}
+
+/// Ends the refresh of a key when dropped, including when the refreshing future is cancelled.
+struct RefreshGuard<M>
+where
+    M: MutexLike<T = HashSet<u64>> + 'static,
+{
+    refreshing_keys: Arc<M>,
+    key: u64,
+    is_released: bool
+}
+
+impl<M> RefreshGuard<M>
+where
+    M: MutexLike<T = HashSet<u64>> + 'static,
+{
+    async fn release(mut self) {
+        self.is_released = true;
+        self.refreshing_keys.lock().await.remove(&self.key);
+    }
+}
+
+impl<M> Drop for RefreshGuard<M>
+where
+    M: MutexLike<T = HashSet<u64>> + 'static,
+{
+    fn drop(&mut self) {
+        if self.is_released {
+            return;
+        }
+
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let refreshing_keys = self.refreshing_keys.clone();
+        let key = self.key;
+
+        handle.spawn(async move {
+            refreshing_keys.lock().await.remove(&key);
+        });
+    }
+}
+
+#[async_trait]
+impl<C, M> CachingStrategy for BaseCachingStale<C, M>
+where
+    C: CachingStrategy + 'static,
+    M: MutexLike<T = HashSet<u64>> + 'static,
+{
+    async fn get_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64) -> Result<Option<T>, NovaXError> {
+        match self.lookup(key).await? {
+            Lookup::Fresh(value) => Ok(Some(value)),
+            Lookup::Missing | Lookup::Stale(_) => Ok(None)
+        }
+    }
+
+    async fn set_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64, value: &T) -> Result<(), NovaXError> {
+        self.set_value(key, value).await
+    }
+
+    async fn get_or_set_cache<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        FutureGetter: Future<Output=Result<T, Error>> + Send,
+        Error: From<NovaXError>
+    {
+        match self.lookup(key).await? {
+            Lookup::Fresh(value) => Ok(value),
+            Lookup::Missing => {
+                let value = getter.await?;
+                self.set_value(key, &value).await?;
+                Ok(value)
+            },
+            Lookup::Stale(value) => {
+                if let Some(refresh_guard) = self.try_begin_refresh(key).await {
+                    self.refresh(refresh_guard, getter, value).await
+                } else {
+                    Ok(value)
+                }
+            }
+        }
+    }
+
+    /// Same as `get_or_set_cache`, except that a stale value is always returned right away,
+    /// the refresh being done in a spawned task.
+    async fn get_or_refresh_cache_in_background<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+        FutureGetter: Future<Output=Result<T, Error>> + Send + 'static,
+        Error: From<NovaXError> + Send + 'static
+    {
+        match self.lookup(key).await? {
+            Lookup::Fresh(value) => Ok(value),
+            Lookup::Missing => {
+                let value = getter.await?;
+                self.set_value(key, &value).await?;
+                Ok(value)
+            },
+            Lookup::Stale(value) => {
+                if let Some(refresh_guard) = self.try_begin_refresh(key).await {
+                    let self_value = self.clone();
+                    tokio::spawn(async move {
+                        if let Ok(refreshed_value) = getter.await {
+                            let _ = self_value.set_value(key, &refreshed_value).await;
+                        }
+
+                        refresh_guard.release().await;
+                    });
+                }
+
+                Ok(value)
+            }
+        }
+    }
+
+    async fn clear(&self) -> Result<(), NovaXError> {
+        self.caching.clear().await
+    }
+
+    fn with_duration_strategy(&self, strategy: CachingDurationStrategy) -> Self {
+        Self {
+            caching: self.caching.clone(),
+            duration_strategy: strategy,
+            grace_period: self.grace_period,
+            refreshing_keys: self.refreshing_keys.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C, M> FencedCachingStrategy for BaseCachingStale<C, M>
+where
+    C: FencedCachingStrategy + 'static,
+    M: MutexLike<T = HashSet<u64>> + 'static,
+{
+    async fn set_cache_if_lock_held<T: Serialize + DeserializeOwned + Send + Sync>(&self, lock_key: &str, token: u64, key: u64, value: &T) -> Result<bool, NovaXError> {
+        let (entry, caching) = self.new_entry(value)?;
+
+        caching.set_cache_if_lock_held(lock_key, token, key, &entry).await
+    }
+
+    async fn get_stale_cache<T: Serialize + DeserializeOwned + Send + Sync>(&self, key: u64) -> Result<Option<T>, NovaXError> {
+        match self.lookup(key).await? {
+            Lookup::Fresh(value) | Lookup::Stale(value) => Ok(Some(value)),
+            Lookup::Missing => Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use novax::caching::{CachingDurationStrategy, CachingStrategy};
+    use novax::errors::{CachingError, NovaXError};
+
+    use crate::date::get_current_timestamp::set_mock_time;
+    use crate::local::caching_local::CachingLocal;
+    use crate::stale::caching::CachingStale;
+
+    fn new_caching() -> CachingStale<CachingLocal> {
+        let caching_local = CachingLocal::empty(CachingDurationStrategy::EachBlock);
+
+        CachingStale::new(
+            caching_local,
+            CachingDurationStrategy::Duration(Duration::from_secs(10)),
+            Duration::from_secs(20)
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_key_not_found() -> Result<(), NovaXError> {
+        let caching = new_caching();
+
+        let result = caching.get_cache::<()>(1).await?;
+
+        assert_eq!(result, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_before_expiration() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"test".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(9));
+
+        let result = caching.get_cache::<String>(key).await?;
+        let expected = Some("test".to_string());
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_stale_value() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"test".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let result = caching.get_cache::<String>(key).await?;
+
+        assert_eq!(result, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_fresh_value() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(9));
+
+        let result = caching.get_or_set_cache::<String, _, NovaXError>(key, async {
+            panic!("should not be executed");
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_stale_value_refreshed() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let result = caching.get_or_set_cache(key, async {
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        assert_eq!(result, "test");
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_stale_value_getter_error() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let result = caching.get_or_set_cache(key, async {
+            Err::<String, _>(NovaXError::Caching(CachingError::ErrorInGetter))
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        let result = caching.get_or_set_cache(key, async {
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        assert_eq!(result, "test");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_stale_value_already_refreshing() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let _refresh_guard = caching.try_begin_refresh(key).await.unwrap();
+
+        let result = caching.get_or_set_cache::<String, _, NovaXError>(key, async {
+            panic!("should not be executed");
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_stale_value_cancelled_refresh() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(50),
+            caching.get_or_set_cache::<String, _, NovaXError>(key, std::future::pending())
+        ).await;
+
+        assert!(cancelled.is_err());
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let result = caching.get_or_set_cache(key, async {
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        assert_eq!(result, "test");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_set_cache_after_grace_period() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(31));
+
+        let result = caching.get_or_set_cache(key, async {
+            Err::<String, _>(NovaXError::Caching(CachingError::ErrorInGetter))
+        }).await;
+
+        assert_eq!(result, Err(NovaXError::Caching(CachingError::ErrorInGetter)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_refresh_cache_in_background_stale_value() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let result = caching.get_or_refresh_cache_in_background(key, async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok::<_, NovaXError>("test".to_string())
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_refresh_cache_in_background_does_not_wait_for_getter() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching.set_cache(key, &"old value".to_string()).await?;
+
+        set_mock_time(Duration::from_secs(11));
+
+        let (sender, receiver) = tokio::sync::oneshot::channel::<String>();
+
+        // The getter only completes once the caller got its result
+        let result = caching.get_or_refresh_cache_in_background(key, async {
+            Ok::<_, NovaXError>(receiver.await.unwrap())
+        }).await?;
+
+        assert_eq!(result, "old value");
+
+        sender.send("test".to_string()).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(caching.get_cache::<String>(key).await?, Some("test".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_duration_strategy() -> Result<(), NovaXError> {
+        let caching = new_caching();
+        let key = 1;
+
+        caching
+            .with_duration_strategy(CachingDurationStrategy::Duration(Duration::from_secs(100)))
+            .set_cache(key, &"test".to_string())
+            .await?;
+
+        set_mock_time(Duration::from_secs(99));
+
+        let result = caching.get_cache::<String>(key).await?;
+
+        assert_eq!(result, Some("test".to_string()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod caching;
\ No newline at end of file
@@ -19,7 +19,11 @@ pub struct AccountInfosAccountData {
     /// Optionally holds the owner address of the account, if any.
     /// This field is skipped during serialization if it holds a `None` value.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner_address: Option<String>
+    pub owner_address: Option<String>,
+    /// Optionally holds the root hash of the account's storage, which changes whenever the storage changes.
+    /// This field is skipped during serialization if it holds a `None` value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_hash: Option<String>
 }
 
 /// Struct to hold the `AccountInfosAccountData` struct.
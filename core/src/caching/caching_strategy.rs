@@ -61,6 +61,21 @@ pub trait CachingStrategy: Clone + Send + Sync + Debug {
             FutureGetter: Future<Output=Result<T, Error>> + Send,
             Error: From<NovaXError>;
 
+    /// Same as `get_or_set_cache`, except that the getter owns its environment.
+    ///
+    /// This allows strategies serving outdated values to return them right away, and to refresh them in the background
+    /// instead of making the caller wait for the getter. The generated queries use this method.
+    ///
+    /// By default, this method behaves as `get_or_set_cache`.
+    async fn get_or_refresh_cache_in_background<T, FutureGetter, Error>(&self, key: u64, getter: FutureGetter) -> Result<T, Error>
+        where
+            T: Serialize + DeserializeOwned + Send + Sync + 'static,
+            FutureGetter: Future<Output=Result<T, Error>> + Send + 'static,
+            Error: From<NovaXError> + Send + 'static
+    {
+        self.get_or_set_cache(key, getter).await
+    }
+
     /// Clear the entire cache.
     ///
     /// # Returns
@@ -5,7 +5,7 @@ use num_bigint::BigUint;
 use tokio::sync::Mutex;
 use novax_data::{Address, NativeConvertible};
 use crate::error::executor::ExecutorError;
-use crate::TokenTransfer;
+use crate::{QueryBlock, TokenTransfer};
 
 /// A trait representing the execution of smart contract queries.
 ///
@@ -13,19 +13,36 @@ use crate::TokenTransfer;
 /// like a real blockchain or a mocked one. The trait's associated function, `execute`, is responsible for
 /// sending a query request, executing the query on the blockchain or mocked environment,
 /// and returning the result of the query.
+///
+/// A query is made by `caller` when provided, otherwise each executor uses its own default caller.
+/// The `egld_value` and the `esdt_transfers` are sent along with the query, as the payments of a transaction would be.
+/// Executors which cannot honor the caller or the payments must return an error instead of silently ignoring them.
+///
+/// A query is executed against the latest state, unless a `QueryBlock` is provided. Executors which cannot read
+/// the state at a given block must return an error instead of silently querying the latest state.
 #[async_trait]
 pub trait QueryExecutor: Send + Sync {
     /// Executes a smart contract query and returns the result.
+    #[allow(clippy::too_many_arguments)]
     async fn execute<OutputManaged>(
         &self,
         to: &Address,
         function: String,
         arguments: Vec<Vec<u8>>,
         egld_value: BigUint,
-        esdt_transfers: Vec<TokenTransfer>
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>
     ) -> Result<OutputManaged::Native, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync;
+
+    /// Returns the block every query made through this executor is executed at, if the executor pins them to a block.
+    ///
+    /// The generated queries include it in their caching keys, so the results read at different blocks are cached separately.
+    async fn get_pinned_block(&self) -> Option<QueryBlock> {
+        None
+    }
 }
 
 /// An implementation of `QueryExecutor` for `Arc<T>` where `T: QueryExecutor`.
@@ -39,7 +56,9 @@ impl<T: QueryExecutor> QueryExecutor for Arc<T> {
         function: String,
         arguments: Vec<Vec<u8>>,
         egld_value: BigUint,
-        esdt_transfers: Vec<TokenTransfer>
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>
     ) -> Result<OutputManaged::Native, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
@@ -50,9 +69,15 @@ impl<T: QueryExecutor> QueryExecutor for Arc<T> {
             function,
             arguments,
             egld_value,
-            esdt_transfers
+            esdt_transfers,
+            caller,
+            block
         ).await
     }
+
+    async fn get_pinned_block(&self) -> Option<QueryBlock> {
+        T::get_pinned_block(self).await
+    }
 }
 
 /// An implementation of `QueryExecutor` for `Arc<Mutex<T>>` where `T: QueryExecutor`.
@@ -66,7 +91,9 @@ impl<T: QueryExecutor> QueryExecutor for Arc<Mutex<T>> {
         function: String,
         arguments: Vec<Vec<u8>>,
         egld_value: BigUint,
-        esdt_transfers: Vec<TokenTransfer>
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>
     ) -> Result<OutputManaged::Native, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
@@ -78,8 +105,14 @@ impl<T: QueryExecutor> QueryExecutor for Arc<Mutex<T>> {
                 function,
                 arguments,
                 egld_value,
-                esdt_transfers
+                esdt_transfers,
+                caller,
+                block
             ).await
         }
     }
+
+    async fn get_pinned_block(&self) -> Option<QueryBlock> {
+        self.lock().await.get_pinned_block().await
+    }
 }
\ No newline at end of file
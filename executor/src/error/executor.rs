@@ -5,6 +5,10 @@ use crate::error::date::DateError;
 use crate::error::dummy::DummyExecutorError;
 use crate::error::gateway::GatewayError;
 use crate::error::mock_deploy::MockDeployError;
+use crate::error::mock_query::MockQueryError;
+use crate::error::mock_snapshot::MockSnapshotError;
+use crate::error::mock_account::MockAccountError;
+use crate::error::mock_fork::MockForkError;
 use crate::error::mock_transaction::MockTransactionError;
 use crate::error::network_query_events::NetworkQueryEventsError;
 use crate::error::transaction::TransactionError;
@@ -45,6 +49,10 @@ pub enum ExecutorError {
     /// information about the nature of the mock deployment-related error that occurred.
     MockDeploy(MockDeployError),
     MockTransaction(MockTransactionError),
+    MockQuery(MockQueryError),
+    MockSnapshot(MockSnapshotError),
+    MockAccount(MockAccountError),
+    MockFork(MockForkError),
 
     Transaction(TransactionError),
     Wallet(WalletError),
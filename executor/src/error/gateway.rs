@@ -28,6 +28,34 @@ pub enum GatewayError {
     /// Occurs when there is a problem parsing the network configuration data fetched from the gateway.
     CannotParseNetworkConfig,
 
+    /// Error encountered when attempting to fetch the status of a shard from the `/network/status/{shard}` endpoint.
+    CannotFetchNetworkStatus {
+        /// The shard whose status couldn't be fetched.
+        shard: u32
+    },
+
+    /// Occurs when there is a problem parsing the status of a shard fetched from the gateway.
+    CannotParseNetworkStatus {
+        /// The shard whose status encountered a parsing error.
+        shard: u32
+    },
+
+    /// Error encountered when attempting to fetch a block from the `/block/{shard}/by-nonce/{nonce}` endpoint.
+    CannotFetchBlock {
+        /// The shard of the block.
+        shard: u32,
+        /// The nonce of the block.
+        nonce: u64
+    },
+
+    /// Occurs when there is a problem parsing a block fetched from the gateway.
+    CannotParseBlock {
+        /// The shard of the block.
+        shard: u32,
+        /// The nonce of the block.
+        nonce: u64
+    },
+
     /// Represents an error when simulating a transaction through the gateway.
     CannotSimulateTransaction,
 
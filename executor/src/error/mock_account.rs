@@ -0,0 +1,27 @@
+use crate::error::executor::ExecutorError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub enum MockAccountError {
+    /// This error variant is triggered when creating an account at an address already used by an account of the mocked world.
+    AccountAlreadyExists { address: String },
+}
+
+/// An implementation of the `From` trait to allow for easy conversions from `MockAccountError` to `ExecutorError`.
+///
+/// This implementation facilitates the propagation of `MockAccountError`s through the code,
+/// by allowing them to be converted into the more general `ExecutorError` type.
+impl From<MockAccountError> for ExecutorError {
+    /// Performs the conversion from a `MockAccountError` to an `ExecutorError`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The `MockAccountError` value to be converted.
+    ///
+    /// # Returns
+    ///
+    /// * `ExecutorError`: An `ExecutorError` instance containing the provided `MockAccountError` value.
+    fn from(value: MockAccountError) -> Self {
+        ExecutorError::MockAccount(value)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::error::executor::ExecutorError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub enum MockForkError {
+    /// This error variant is triggered when a fork executor can't fetch the state of an account it needs from the gateway,
+    /// the `reason` holding the underlying error.
+    UnableToLoadAccount { address: String, reason: String },
+    /// This error variant is triggered when a fork executor can't save the state it fetched into its infos file.
+    UnableToSaveInfosFile { reason: String },
+    /// This error variant is triggered when a request touches an account the fork executor didn't load, such as the receiver of a transfer
+    /// made by a contract. `ForkExecutor::load_accounts` can load it before sending the request.
+    AccountNotLoaded { address: String },
+}
+
+/// An implementation of the `From` trait to allow for easy conversions from `MockForkError` to `ExecutorError`.
+///
+/// This implementation facilitates the propagation of `MockForkError`s through the code,
+/// by allowing them to be converted into the more general `ExecutorError` type.
+impl From<MockForkError> for ExecutorError {
+    /// Performs the conversion from a `MockForkError` to an `ExecutorError`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The `MockForkError` value to be converted.
+    ///
+    /// # Returns
+    ///
+    /// * `ExecutorError`: An `ExecutorError` instance containing the provided `MockForkError` value.
+    fn from(value: MockForkError) -> Self {
+        ExecutorError::MockFork(value)
+    }
+}
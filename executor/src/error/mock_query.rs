@@ -0,0 +1,31 @@
+use crate::error::executor::ExecutorError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub enum MockQueryError {
+    /// This error variant is triggered when a query targets a specific block.
+    /// The mocked world only holds its current state, so a `MockExecutor` cannot execute historical queries.
+    HistoricalQueryNotSupported,
+    /// This error variant is triggered when the queried endpoint fails, for example because of a `require!`,
+    /// holding the status and the message of the error.
+    ExecutionFailed { status: u64, message: String },
+}
+
+/// An implementation of the `From` trait to allow for easy conversions from `MockQueryError` to `ExecutorError`.
+///
+/// This implementation facilitates the propagation of `MockQueryError`s through the code,
+/// by allowing them to be converted into the more general `ExecutorError` type.
+impl From<MockQueryError> for ExecutorError {
+    /// Performs the conversion from a `MockQueryError` to an `ExecutorError`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The `MockQueryError` value to be converted.
+    ///
+    /// # Returns
+    ///
+    /// * `ExecutorError`: An `ExecutorError` instance containing the provided `MockQueryError` value.
+    fn from(value: MockQueryError) -> Self {
+        ExecutorError::MockQuery(value)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::error::executor::ExecutorError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub enum MockSnapshotError {
+    /// This error variant is triggered when restoring a snapshot that hasn't been taken by the `MockExecutor`, or one of its clones.
+    UnknownSnapshot { id: u64 },
+}
+
+/// An implementation of the `From` trait to allow for easy conversions from `MockSnapshotError` to `ExecutorError`.
+///
+/// This implementation facilitates the propagation of `MockSnapshotError`s through the code,
+/// by allowing them to be converted into the more general `ExecutorError` type.
+impl From<MockSnapshotError> for ExecutorError {
+    /// Performs the conversion from a `MockSnapshotError` to an `ExecutorError`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The `MockSnapshotError` value to be converted.
+    ///
+    /// # Returns
+    ///
+    /// * `ExecutorError`: An `ExecutorError` instance containing the provided `MockSnapshotError` value.
+    fn from(value: MockSnapshotError) -> Self {
+        ExecutorError::MockSnapshot(value)
+    }
+}
@@ -7,5 +7,9 @@ pub mod transaction;
 pub mod dummy;
 pub mod wallet;
 pub mod mock_transaction;
+pub mod mock_query;
+pub mod mock_snapshot;
+pub mod mock_account;
+pub mod mock_fork;
 pub mod date;
 pub mod network_query_events;
\ No newline at end of file
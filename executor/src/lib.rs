@@ -51,10 +51,17 @@ mod mocking;
 mod dummy;
 mod utils;
 
+#[cfg(test)]
+pub(crate) mod mock;
+
 pub use error::executor::ExecutorError;
 pub use error::network::NetworkQueryError;
 pub use error::mock_deploy::MockDeployError;
 pub use error::mock_transaction::MockTransactionError;
+pub use error::mock_query::MockQueryError;
+pub use error::mock_snapshot::MockSnapshotError;
+pub use error::mock_account::MockAccountError;
+pub use error::mock_fork::MockForkError;
 pub use error::gateway::GatewayError;
 pub use error::transaction::TransactionError;
 pub use error::simulation::SimulationError;
@@ -66,6 +73,8 @@ pub use base::deploy::DeployExecutor;
 
 pub use network::query::executor::ProxyQueryExecutor;
 pub use network::query::executor::QueryNetworkExecutor;
+pub use network::query::session::QuerySession;
+pub use network::query::batch::QueryBatch;
 pub use network::utils::wallet::Wallet;
 pub use network::transaction::executor::NetworkExecutor;
 pub use network::transaction::executor::BaseTransactionNetworkExecutor;
@@ -93,6 +102,8 @@ pub use network::events::proxy::ElasticSearchClient;
 
 pub use mocking::executor::StandardMockExecutor;
 pub use mocking::executor::MockExecutor;
+pub use mocking::block::MockBlockInfos;
+pub use mocking::snapshot::MockSnapshotId;
 
 pub use dummy::transaction::DummyExecutor;
 pub use dummy::transaction::DummyTransactionExecutor;
@@ -104,6 +115,8 @@ pub use utils::transaction::token_transfer::TokenTransfer;
 pub use utils::transaction::call_result;
 pub use utils::transaction::results;
 pub use utils::transaction::normalization::NormalizationInOut;
+pub use utils::date::block_clock::BlockClock;
+pub use utils::query::block::QueryBlock;
 
 pub use utils::events::query_result::EventQueryResult;
 pub use utils::events::query_events_options::*;
@@ -0,0 +1 @@
+pub mod request;
\ No newline at end of file
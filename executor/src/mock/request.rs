@@ -0,0 +1,105 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use async_trait::async_trait;
+use http::StatusCode;
+use serde::Serialize;
+
+use novax_request::error::request::RequestError;
+use novax_request::gateway::client::GatewayClient;
+
+const MOCK_BASE_URL: &str = "https://test.test";
+
+pub struct MockClient {
+    url: String,
+    status_calls: Arc<AtomicU64>
+}
+
+impl MockClient {
+    pub fn new() -> MockClient {
+        MockClient {
+            url: MOCK_BASE_URL.to_string(),
+            status_calls: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn get_status_calls_count(&self) -> u64 {
+        self.status_calls.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait]
+impl GatewayClient for MockClient {
+    type Owned = Self;
+
+    fn get_gateway_url(&self) -> &str {
+        &self.url
+    }
+
+    fn with_appended_url(&self, url: &str) -> Self {
+        MockClient {
+            url: format!("{}{url}", self.url),
+            status_calls: self.status_calls.clone(),
+        }
+    }
+
+    async fn get(&self) -> Result<(StatusCode, Option<String>), RequestError> {
+        let Some(path) = self.url.strip_prefix(MOCK_BASE_URL) else {
+            panic!("Url should start with mocked base");
+        };
+
+        let response = if path == "/network/status/1" {
+            Some(network::get_network_status(self.status_calls.fetch_add(1, Ordering::Relaxed)))
+        } else {
+            network::get_network_response(path)
+        };
+
+        if let Some((status, data)) = response {
+            Ok((status, Some(data)))
+        } else {
+            panic!("Unknown url: {}", self.url)
+        }
+    }
+
+    async fn post<Body>(&self, _: &Body) -> Result<(StatusCode, Option<String>), RequestError>
+        where
+            Body: Serialize + Send + Sync
+    {
+        unreachable!()
+    }
+}
+
+mod network {
+    use http::StatusCode;
+
+    pub fn get_network_response(path: &str) -> Option<(StatusCode, String)> {
+        if path == "/network/config" {
+            Some(get_network_config())
+        } else if path == "/block/1/by-nonce/100" {
+            Some(get_block_100())
+        } else {
+            None
+        }
+    }
+
+    /// The nonce increases every two calls, the first round producing no block.
+    pub fn get_network_status(calls: u64) -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = format!(r#"{{"data":{{"status":{{"erd_nonce":{},"erd_current_round":{}}}}},"error":"","code":"successful"}}"#, 100 + calls / 2, 110 + calls);
+
+        (status, data)
+    }
+
+    fn get_network_config() -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = r#"{"data":{"config":{"erd_chain_id":"D","erd_min_gas_price":1000000000,"erd_min_transaction_version":1,"erd_round_duration":6000,"erd_start_time":1694000000}},"error":"","code":"successful"}"#.to_string();
+
+        (status, data)
+    }
+
+    fn get_block_100() -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = r#"{"data":{"block":{"nonce":100,"round":110,"timestamp":1700000000,"timestampMs":1700000000600}},"error":"","code":"successful"}"#.to_string();
+
+        (status, data)
+    }
+}
@@ -0,0 +1,63 @@
+use multiversx_sc_scenario::scenario_model::{Account, BigUintValue, BytesKey, BytesValue, U64Value};
+use num_bigint::BigUint;
+
+use crate::utils::transaction::token_transfer::TokenTransfer;
+
+/// Returns a new account of the scenario engine, holding the given EGLD and tokens.
+///
+/// A token with a nonce of 0 is added as a fungible ESDT, any other nonce adds an instance of a NFT, SFT or meta ESDT, without attributes.
+pub(crate) fn get_funded_account(egld_balance: &BigUint, tokens: &[TokenTransfer]) -> Account {
+    let mut account = Account::new()
+        .nonce(0)
+        .balance(BigUintValue::from(egld_balance));
+
+    for token in tokens {
+        let identifier = BytesKey::from(token.identifier.as_bytes().to_vec());
+        let amount = BigUintValue::from(&token.amount);
+
+        account = if token.nonce == 0 {
+            account.esdt_balance(identifier, amount)
+        } else {
+            account.esdt_nft_balance(identifier, U64Value::from(token.nonce), amount, None::<BytesValue>)
+        };
+    }
+
+    account
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc_scenario::scenario_model::{BytesKey, Esdt};
+    use num_bigint::BigUint;
+
+    use crate::mocking::account::get_funded_account;
+    use crate::utils::transaction::token_transfer::TokenTransfer;
+
+    #[test]
+    fn test_get_funded_account() {
+        let tokens = vec![
+            TokenTransfer {
+                identifier: "WEGLD-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(100u8),
+            },
+            TokenTransfer {
+                identifier: "NFT-abcdef".to_string(),
+                nonce: 3,
+                amount: BigUint::from(1u8),
+            },
+        ];
+
+        let account = get_funded_account(&BigUint::from(10u8), &tokens);
+
+        assert_eq!(account.balance.unwrap().value, BigUint::from(10u8));
+
+        let Esdt::Full(nft) = &account.esdt[&BytesKey::from(b"NFT-abcdef".to_vec())] else {
+            panic!("the NFT isn't fully described")
+        };
+        assert_eq!(nft.instances[0].nonce.as_ref().unwrap().value, 3);
+        assert_eq!(nft.instances[0].balance.as_ref().unwrap().value, BigUint::from(1u8));
+
+        assert!(account.esdt.contains_key(&BytesKey::from(b"WEGLD-abcdef".to_vec())));
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::date::block_clock::BlockClock;
+
+/// The infos of a block of a mocked world, as returned by the `get_block_*` and `get_prev_block_*` functions of the contracts.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct MockBlockInfos {
+    /// The timestamp of the block, in seconds.
+    pub timestamp: u64,
+    /// The nonce of the block.
+    pub nonce: u64,
+    /// The round of the block.
+    pub round: u64,
+    /// The epoch of the block.
+    pub epoch: u64,
+}
+
+impl MockBlockInfos {
+    /// The number of rounds in an epoch on the mainnet.
+    pub const ROUNDS_PER_EPOCH: u64 = 14_400;
+
+    /// Returns the infos of the block produced `count` rounds after this one, assuming every round produces a block.
+    ///
+    /// The timestamp moves forward by the round duration of [`BlockClock::DEFAULT`] for each round,
+    /// and the epoch changes every [`MockBlockInfos::ROUNDS_PER_EPOCH`] rounds, as on the mainnet.
+    pub fn advanced_by(&self, count: u64) -> Self {
+        self.advanced_by_with_round_duration(count, BlockClock::DEFAULT.round_duration)
+    }
+
+    /// Same as [`MockBlockInfos::advanced_by`], with a custom round duration.
+    ///
+    /// The timestamps of the mocked world are in seconds, a round duration shorter than a second may therefore leave the timestamp unchanged.
+    /// The values saturate at `u64::MAX` instead of overflowing.
+    pub fn advanced_by_with_round_duration(&self, count: u64, round_duration: Duration) -> Self {
+        let round = self.round.saturating_add(count);
+        let elapsed_epochs = round / Self::ROUNDS_PER_EPOCH - self.round / Self::ROUNDS_PER_EPOCH;
+        let elapsed_nanos = round_duration.as_nanos().saturating_mul(u128::from(count));
+        let elapsed_seconds = u64::try_from(elapsed_nanos / 1_000_000_000).unwrap_or(u64::MAX);
+
+        Self {
+            timestamp: self.timestamp.saturating_add(elapsed_seconds),
+            nonce: self.nonce.saturating_add(count),
+            round,
+            epoch: self.epoch.saturating_add(elapsed_epochs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::mocking::block::MockBlockInfos;
+
+    const BLOCK: MockBlockInfos = MockBlockInfos {
+        timestamp: 1_000,
+        nonce: 10,
+        round: 14_398,
+        epoch: 3,
+    };
+
+    #[test]
+    fn test_advanced_by_zero() {
+        assert_eq!(BLOCK.advanced_by(0), BLOCK);
+    }
+
+    #[test]
+    fn test_advanced_by_in_same_epoch() {
+        let expected = MockBlockInfos {
+            timestamp: 1_006,
+            nonce: 11,
+            round: 14_399,
+            epoch: 3,
+        };
+
+        assert_eq!(BLOCK.advanced_by(1), expected);
+    }
+
+    #[test]
+    fn test_advanced_by_to_next_epochs() {
+        let expected = MockBlockInfos {
+            timestamp: 1_000 + 6 * 14_402,
+            nonce: 10 + 14_402,
+            round: 14_398 + 14_402,
+            epoch: 5,
+        };
+
+        assert_eq!(BLOCK.advanced_by(14_402), expected);
+    }
+
+    #[test]
+    fn test_advanced_by_with_round_duration() {
+        let result = BLOCK.advanced_by_with_round_duration(10, Duration::from_millis(600));
+
+        assert_eq!(result.timestamp, 1_006);
+        assert_eq!(result.nonce, 20);
+    }
+
+    #[test]
+    fn test_advanced_by_saturates() {
+        let result = BLOCK.advanced_by(u64::MAX);
+
+        assert_eq!(result.timestamp, u64::MAX);
+        assert_eq!(result.nonce, u64::MAX);
+        assert_eq!(result.round, u64::MAX);
+        assert_eq!(result.epoch, 3 + u64::MAX / MockBlockInfos::ROUNDS_PER_EPOCH - BLOCK.round / MockBlockInfos::ROUNDS_PER_EPOCH);
+    }
+}
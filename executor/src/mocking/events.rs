@@ -0,0 +1,233 @@
+use std::sync::{Arc, Mutex};
+
+use multiversx_sc_scenario::scenario_model::Log;
+
+use novax_data::Address;
+
+use crate::error::executor::ExecutorError;
+use crate::error::network_query_events::NetworkQueryEventsError;
+use crate::utils::events::decodable_event::DecodableEvent;
+use crate::utils::events::query_events_options::{EventQueryOptions, SortOption, TimestampOption};
+use crate::utils::events::query_result::EventQueryResult;
+
+/// An event emitted by a call or a deployment executed by a `MockExecutor`.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct MockEvent {
+    /// The address of the contract which emitted the event.
+    pub address: Address,
+    /// The topics of the event, the first one being the event's identifier.
+    pub topics: Vec<Vec<u8>>,
+    /// The data of the event.
+    pub data: Vec<u8>,
+    /// The timestamp of the block the event was emitted in.
+    pub timestamp: u64,
+    /// The position of the event in the log, used to order events emitted in the same block.
+    pub position: u64,
+}
+
+/// The events emitted by the calls and the deployments executed by a `MockExecutor`, shared between its clones.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct MockEventLog {
+    events: Arc<Mutex<Vec<MockEvent>>>,
+}
+
+impl MockEventLog {
+    /// Appends the logs of a successful transaction, emitted in a block having the given `timestamp`.
+    pub fn record(&self, logs: &[Log], timestamp: u64) {
+        let mut events = self.events.lock().unwrap();
+
+        for log in logs {
+            let position = events.len() as u64;
+
+            events.push(MockEvent {
+                address: Address::from_bytes(*log.address.as_array()),
+                topics: log.topics.clone(),
+                data: log.data.first().cloned().unwrap_or_default(),
+                timestamp,
+                position,
+            });
+        }
+    }
+
+    /// Returns the number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    /// Forgets the events recorded after the first `len` ones.
+    pub fn truncate(&self, len: usize) {
+        self.events.lock().unwrap().truncate(len);
+    }
+
+    /// Returns the decoded events matching the query, mirroring the behavior of the Elasticsearch executor:
+    ///
+    /// - the events are filtered on the contract address, the identifier and the `filter_terms`, each term being matched against the topic at its position;
+    /// - the timestamp range of the options is applied, then the sort, then `from` and `size`.
+    pub fn query<EventReturn>(
+        &self,
+        contract_address: &Address,
+        event_identifier: &str,
+        options: Option<EventQueryOptions>,
+        filter_terms: Vec<(Vec<u8>, u32)>
+    ) -> Result<Vec<EventQueryResult<EventReturn>>, ExecutorError>
+        where
+            EventReturn: DecodableEvent + Send + Sync
+    {
+        let options = options.unwrap_or_default();
+
+        let mut events: Vec<MockEvent> = self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| {
+                &event.address == contract_address
+                    && event.topics.first().is_some_and(|identifier| identifier == event_identifier.as_bytes())
+                    && filter_terms.iter().all(|(term, position)| event.topics.get(*position as usize) == Some(term))
+                    && is_timestamp_in_range(event.timestamp, options.timestamp.as_ref())
+            })
+            .cloned()
+            .collect();
+
+        if let Some(sort_timestamp) = options.sort.and_then(|sort| sort.timestamp) {
+            events.sort_by(|first, second| {
+                let ordering = (first.timestamp, first.position).cmp(&(second.timestamp, second.position));
+
+                match sort_timestamp {
+                    SortOption::Ascending => ordering,
+                    SortOption::Descending => ordering.reverse()
+                }
+            });
+        }
+
+        events
+            .into_iter()
+            .skip(options.from.unwrap_or_default() as usize)
+            .take(options.size.map_or(usize::MAX, |size| size as usize))
+            .map(|event| {
+                let topics = event.topics.get(1..).map_or_else(Vec::new, |topics| topics.to_vec());
+
+                let Ok(decoded_event) = EventReturn::decode_event(topics.clone(), event.data.clone()) else {
+                    let mut topics_hex: Vec<String> = topics.iter().map(hex::encode).collect();
+                    topics_hex.push(hex::encode(&event.data));
+
+                    return Err(NetworkQueryEventsError::CannotDeserializeTopicToContractType { topics: topics_hex }.into())
+                };
+
+                Ok(EventQueryResult {
+                    timestamp: event.timestamp,
+                    event: decoded_event
+                })
+            })
+            .collect()
+    }
+}
+
+fn is_timestamp_in_range(timestamp: u64, range: Option<&TimestampOption>) -> bool {
+    match range {
+        None => true,
+        Some(TimestampOption::GreaterThanOrEqual(min)) => timestamp >= *min,
+        Some(TimestampOption::LowerThanOrEqual(max)) => timestamp <= *max,
+        Some(TimestampOption::Between(min, max)) => timestamp >= *min && timestamp <= *max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc::types::Address as ScAddress;
+    use multiversx_sc_scenario::scenario_model::Log;
+
+    use novax_data::Address;
+
+    use crate::mocking::events::MockEventLog;
+    use crate::utils::events::query_events_options::{EventQueryOptions, QueryEventsSortOptions, SortOption, TimestampOption};
+    use crate::utils::events::query_result::EventQueryResult;
+
+    const CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+    const OTHER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6";
+
+    fn get_log(address: &str, identifier: &str, topic: u8, data: u8) -> Log {
+        Log {
+            address: ScAddress::from(Address::from_bech32_string(address).unwrap().to_bytes()),
+            endpoint: "emit".to_string(),
+            topics: vec![identifier.as_bytes().to_vec(), vec![topic]],
+            data: vec![vec![data]],
+        }
+    }
+
+    /// Records the events of the datas 1 to 4, emitted at the timestamps 10, 20, 20 and 30, and with the topics 1, 2, 1 and 2.
+    fn get_event_log() -> MockEventLog {
+        let event_log = MockEventLog::default();
+
+        event_log.record(&[get_log(CONTRACT_ADDRESS, "swap", 1, 1)], 10);
+        event_log.record(&[get_log(CONTRACT_ADDRESS, "swap", 2, 2), get_log(OTHER_CONTRACT_ADDRESS, "swap", 1, 5)], 20);
+        event_log.record(&[get_log(CONTRACT_ADDRESS, "addLiquidity", 1, 6), get_log(CONTRACT_ADDRESS, "swap", 1, 3)], 20);
+        event_log.record(&[get_log(CONTRACT_ADDRESS, "swap", 2, 4)], 30);
+
+        event_log
+    }
+
+    fn query(options: Option<EventQueryOptions>, filter_terms: Vec<(Vec<u8>, u32)>) -> Vec<(u64, u8)> {
+        let result: Vec<EventQueryResult<u8>> = get_event_log()
+            .query(&Address::from_bech32_string(CONTRACT_ADDRESS).unwrap(), "swap", options, filter_terms)
+            .unwrap();
+
+        result.into_iter().map(|result| (result.timestamp, result.event)).collect()
+    }
+
+    #[test]
+    fn test_query_no_filter_no_options() {
+        assert_eq!(query(None, vec![]), vec![(10, 1), (20, 2), (20, 3), (30, 4)]);
+    }
+
+    #[test]
+    fn test_query_with_filter() {
+        assert_eq!(query(None, vec![(vec![2], 1)]), vec![(20, 2), (30, 4)]);
+    }
+
+    #[test]
+    fn test_query_with_filter_at_wrong_position() {
+        assert_eq!(query(None, vec![(vec![2], 2)]), vec![]);
+    }
+
+    #[test]
+    fn test_query_with_from_and_size() {
+        let options = EventQueryOptions {
+            from: Some(1),
+            size: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(query(Some(options), vec![]), vec![(20, 2), (20, 3)]);
+    }
+
+    #[test]
+    fn test_query_with_timestamp_options() {
+        let gte = EventQueryOptions {
+            timestamp: Some(TimestampOption::GreaterThanOrEqual(20)),
+            ..Default::default()
+        };
+        let lte = EventQueryOptions {
+            timestamp: Some(TimestampOption::LowerThanOrEqual(20)),
+            ..Default::default()
+        };
+        let between = EventQueryOptions {
+            timestamp: Some(TimestampOption::Between(15, 25)),
+            ..Default::default()
+        };
+
+        assert_eq!(query(Some(gte), vec![]), vec![(20, 2), (20, 3), (30, 4)]);
+        assert_eq!(query(Some(lte), vec![]), vec![(10, 1), (20, 2), (20, 3)]);
+        assert_eq!(query(Some(between), vec![]), vec![(20, 2), (20, 3)]);
+    }
+
+    #[test]
+    fn test_query_sorted_descending_then_paginated() {
+        let options = EventQueryOptions {
+            size: Some(3),
+            sort: Some(QueryEventsSortOptions { timestamp: Some(SortOption::Descending) }),
+            ..Default::default()
+        };
+
+        assert_eq!(query(Some(options), vec![]), vec![(30, 4), (20, 3), (20, 2)]);
+    }
+}
@@ -3,10 +3,12 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use multiversx_sc::codec::TopDecodeMulti;
-use multiversx_sc::imports::{CodeMetadata, ReturnsNewAddress};
-use multiversx_sc::types::ReturnsRawResult;
+use multiversx_sc::imports::CodeMetadata;
 use multiversx_sc_scenario::imports::{Bech32Address, BytesValue};
-use multiversx_sc_scenario::ScenarioTxRun;
+use multiversx_sc_scenario::multiversx_chain_vm::types::VMAddress;
+use multiversx_sc_scenario::scenario_model::SetStateStep;
+use multiversx_sc_scenario::multiversx_chain_vm::world_mock::BlockInfo;
+use multiversx_sc_scenario::{ReturnsHandledOrError, ScenarioTxRun, WithRawTxResponse};
 use num_bigint::BigUint;
 use tokio::sync::Mutex;
 
@@ -15,13 +17,26 @@ use novax_data::NativeConvertible;
 
 use crate::base::deploy::DeployExecutor;
 use crate::base::query::QueryExecutor;
+use crate::base::query_events::QueryEventsExecutor;
 use crate::base::transaction::TransactionExecutor;
 use crate::call_result::CallResult;
 use crate::error::executor::ExecutorError;
+use crate::error::mock_account::MockAccountError;
 use crate::error::mock_deploy::MockDeployError;
+use crate::error::mock_query::MockQueryError;
 use crate::error::mock_transaction::MockTransactionError;
 use crate::error::transaction::TransactionError;
-use crate::{ScenarioWorld, TransactionOnNetwork};
+use crate::mocking::account::get_funded_account;
+use crate::mocking::block::MockBlockInfos;
+use crate::error::mock_snapshot::MockSnapshotError;
+use crate::mocking::events::MockEventLog;
+use crate::mocking::vm::{execute_rolled_back_query, with_world_state, MockQuery};
+use crate::mocking::response::get_call_result_from_tx_response;
+use crate::mocking::snapshot::{get_restore_step, MockSnapshotId, MockWorldSnapshot};
+use crate::{IntoFilterTerms, QueryBlock, ScenarioWorld, TxResponse};
+use crate::utils::events::decodable_event::DecodableEvent;
+use crate::utils::events::query_events_options::EventQueryOptions;
+use crate::utils::events::query_result::EventQueryResult;
 use crate::utils::transaction::token_transfer::TokenTransfer;
 use crate::utils::transaction::transfers::get_egld_or_esdt_transfers;
 
@@ -41,6 +56,10 @@ pub struct MockExecutor<A>
     world: Arc<Mutex<ScenarioWorld>>,
     /// Optional caller address. If not provided, the executor uses the address from the smart contract call or deployment request.
     opt_caller: Option<A>,
+    /// The events emitted by the calls and the deployments executed by this executor and its clones.
+    events: MockEventLog,
+    /// The snapshots taken by this executor and its clones, indexed by their id.
+    snapshots: Arc<std::sync::Mutex<Vec<Arc<MockWorldSnapshot>>>>,
 }
 
 impl<A> MockExecutor<A>
@@ -54,13 +73,190 @@ impl<A> MockExecutor<A>
     /// - `world`: The mocked world where the smart contract interactions are executed.
     /// - `opt_caller`: Optional caller address.
     ///
+    /// The returned executor starts with its own empty event log, which isn't shared with the other executors created on the same world.
+    ///
     /// # Returns
     /// A new instance of `MockExecutor`.
     pub fn new(world: Arc<Mutex<ScenarioWorld>>, opt_caller: Option<A>) -> MockExecutor<A> {
         MockExecutor {
             world,
             opt_caller,
+            events: MockEventLog::default(),
+            snapshots: Arc::new(std::sync::Mutex::new(vec![])),
+        }
+    }
+
+    /// Returns an executor sending its calls and deployments from the given caller, over the same world as this one.
+    ///
+    /// Creating the view is cheap: the world, the recorded events and the snapshots are shared with this executor.
+    /// A test can therefore play a scenario involving several users from a single executor, by calling `as_caller` for each transaction.
+    pub fn as_caller<C>(&self, caller: C) -> MockExecutor<C>
+        where
+            C: Deref + Send + Sync,
+            Address: for<'a> From<&'a C::Target>
+    {
+        MockExecutor {
+            world: self.world.clone(),
+            opt_caller: Some(caller),
+            events: self.events.clone(),
+            snapshots: self.snapshots.clone(),
+        }
+    }
+
+    /// Creates a new account in the world, holding the given EGLD and tokens.
+    ///
+    /// A token with a nonce of 0 is added as a fungible ESDT, any other nonce adds an instance of a NFT, SFT or meta ESDT, without attributes.
+    /// The new account can then send transactions through [`MockExecutor::as_caller`].
+    ///
+    /// Returns a `MockAccountError::AccountAlreadyExists` error if the address is already used by an account of the world,
+    /// since creating the account would overwrite its balances, storage and code.
+    pub async fn create_account(&self, address: &Address, egld_balance: BigUint, tokens: Vec<TokenTransfer>) -> Result<(), ExecutorError> {
+        let mut world = self.world.lock().await;
+
+        let vm_address = VMAddress::new(address.to_bytes());
+        if with_world_state(&mut world, |state| state.account_exists(&vm_address)) {
+            return Err(MockAccountError::AccountAlreadyExists { address: address.to_bech32_string()? }.into())
+        }
+
+        world.set_state_step(
+            SetStateStep::new()
+                .put_account(&vm_address, get_funded_account(&egld_balance, &tokens))
+        );
+
+        Ok(())
+    }
+
+    /// Returns whether an account exists at the given address in the world, for example because it has been created, deployed or funded.
+    pub async fn account_exists(&self, address: &Address) -> bool {
+        let mut world = self.world.lock().await;
+        let vm_address = VMAddress::new(address.to_bytes());
+
+        with_world_state(&mut world, |state| state.account_exists(&vm_address))
+    }
+
+    /// Records the logs of a successful transaction, timestamped with the current block timestamp of the world.
+    fn record_events(&self, world: &mut ScenarioWorld, response: &TxResponse) {
+        if !response.tx_error.is_success() {
+            return;
+        }
+
+        let timestamp = with_world_state(world, |state| state.current_block_info.block_timestamp);
+
+        self.events.record(&response.logs, timestamp);
+    }
+
+    /// Returns the infos of the current block of the world, the one the next calls, deployments and queries are executed in.
+    pub async fn get_current_block(&self) -> MockBlockInfos {
+        let mut world = self.world.lock().await;
+
+        with_world_state(&mut world, |state| get_mock_block_infos(&state.current_block_info))
+    }
+
+    /// Returns the infos of the block preceding the current one, as returned by the `get_prev_block_*` functions of the contracts.
+    pub async fn get_previous_block(&self) -> MockBlockInfos {
+        let mut world = self.world.lock().await;
+
+        with_world_state(&mut world, |state| get_mock_block_infos(&state.previous_block_info))
+    }
+
+    /// Replaces the infos of the current block of the world.
+    pub async fn set_current_block(&self, block: MockBlockInfos) {
+        self.world.lock().await
+            .current_block()
+            .block_timestamp(block.timestamp)
+            .block_nonce(block.nonce)
+            .block_round(block.round)
+            .block_epoch(block.epoch);
+    }
+
+    /// Replaces the infos of the block preceding the current one.
+    pub async fn set_previous_block(&self, block: MockBlockInfos) {
+        self.world.lock().await
+            .previous_block()
+            .block_timestamp(block.timestamp)
+            .block_nonce(block.nonce)
+            .block_round(block.round)
+            .block_epoch(block.epoch);
+    }
+
+    /// Moves the world `count` blocks forward, as computed by [`MockBlockInfos::advanced_by`].
+    ///
+    /// The previous block becomes the block right before the new current one, so a contract comparing both sees a single round elapsed.
+    /// Use [`MockExecutor::set_current_block`] and [`MockExecutor::set_previous_block`] to move the time forward differently.
+    pub async fn advance_blocks(&self, count: u64) {
+        if count == 0 {
+            return;
         }
+
+        // A single lock, so concurrent calls can't read the same current block and advance the world once
+        let mut world = self.world.lock().await;
+
+        let current_block = with_world_state(&mut world, |state| get_mock_block_infos(&state.current_block_info));
+        let previous_block = current_block.advanced_by(count - 1);
+        let new_current_block = current_block.advanced_by(count);
+
+        world
+            .previous_block()
+            .block_timestamp(previous_block.timestamp)
+            .block_nonce(previous_block.nonce)
+            .block_round(previous_block.round)
+            .block_epoch(previous_block.epoch);
+
+        world
+            .current_block()
+            .block_timestamp(new_current_block.timestamp)
+            .block_nonce(new_current_block.nonce)
+            .block_round(new_current_block.round)
+            .block_epoch(new_current_block.epoch);
+    }
+
+    /// Takes a snapshot of the world, which can be restored later using [`MockExecutor::restore`].
+    ///
+    /// The snapshot holds the accounts of the world, with their balances, storage, code and nonce, the mocked new addresses and the block infos.
+    /// It also remembers the events recorded by the executor, so the events emitted after the snapshot are forgotten once it is restored.
+    pub async fn snapshot(&self) -> MockSnapshotId {
+        let mut world = self.world.lock().await;
+
+        let snapshot = MockWorldSnapshot {
+            state: with_world_state(&mut world, |state| state.clone()),
+            events_count: self.events.len(),
+        };
+
+        let mut snapshots = self.snapshots.lock().unwrap();
+        snapshots.push(Arc::new(snapshot));
+
+        MockSnapshotId(snapshots.len() as u64 - 1)
+    }
+
+    /// Rolls the world back to the given snapshot. A snapshot can be restored any number of times, so each test can start from the same state.
+    ///
+    /// The scenario engine cannot remove an account, the accounts created after the snapshot are therefore emptied instead of removed.
+    /// The contracts deployed after the snapshot keep their code, so deploying again to one of their addresses isn't supported.
+    /// The names of the NFTs aren't supported by the scenario engine and are lost, and the mocked new addresses registered after the snapshot are kept.
+    ///
+    /// Returns a `MockSnapshotError::UnknownSnapshot` error if the snapshot hasn't been taken by this executor or one of its clones.
+    pub async fn restore(&self, id: MockSnapshotId) -> Result<(), ExecutorError> {
+        let Some(snapshot) = self.snapshots.lock().unwrap().get(id.0 as usize).cloned() else {
+            return Err(MockSnapshotError::UnknownSnapshot { id: id.0 }.into())
+        };
+
+        let mut world = self.world.lock().await;
+
+        let step = with_world_state(&mut world, |state| get_restore_step(state, &snapshot.state));
+        world.set_state_step(step);
+
+        self.events.truncate(snapshot.events_count);
+
+        Ok(())
+    }
+}
+
+fn get_mock_block_infos(block_info: &BlockInfo) -> MockBlockInfos {
+    MockBlockInfos {
+        timestamp: block_info.block_timestamp,
+        nonce: block_info.block_nonce,
+        round: block_info.block_round,
+        epoch: block_info.block_epoch,
     }
 }
 
@@ -73,6 +269,12 @@ impl<A> TransactionExecutor for MockExecutor<A>
     /// Executes a smart contract call within a mocked environment.
     ///
     /// This method updates the world state accordingly, all within a controlled, mocked environment.
+    ///
+    /// The result is mapped into the same shapes as the network executor: the logs emitted by the call are returned as the events of the response,
+    /// and a failed call, for example because of a `require!`, returns a `TransactionError::SmartContractExecutionError` instead of panicking.
+    /// As on the network, a failed call doesn't modify the world, except for the nonce of the caller.
+    ///
+    /// The events emitted by a successful call are recorded, so they can be retrieved through the `QueryEventsExecutor` implementation.
     async fn sc_call<OutputManaged>(
         &mut self,
         to: &Address,
@@ -96,38 +298,30 @@ impl<A> TransactionExecutor for MockExecutor<A>
             return Err(MockTransactionError::CallerAddressNotPresent.into())
         };
 
+        let mut opt_response = None;
+
         let mut tx = world.tx()
             .from(Bech32Address::from_bech32_string(Address::from(caller).to_bech32_string()?))
             .to(Bech32Address::from_bech32_string(to.to_bech32_string()?))
             .raw_call(function)
             .with_gas_limit(gas_limit)
             .egld_or_multi_esdt(transfers)
-            .returns(ReturnsRawResult);
+            .returns(ReturnsHandledOrError::new())
+            .returns(WithRawTxResponse(|response| opt_response = Some(response.clone())));
 
         for argument in arguments {
             tx = tx.argument(&argument);
         }
 
-        let raw_result_managed = tx.run();
-        let mut raw_result: Vec<Vec<u8>> = raw_result_managed
-            .into_vec()
-            .iter()
-            .map(|buffer| buffer.to_boxed_bytes().into_vec())
-            .collect();
+        let _ = tx.run();
 
-        let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
-            return Err(TransactionError::CannotDecodeSmartContractResult { response: Default::default() }.into())
+        let Some(response) = opt_response else {
+            unreachable!("the raw response handler is always executed")
         };
 
-        let mut response = TransactionOnNetwork::default();
-        response.transaction.status = "successful".to_string();
-
-        let call_result = CallResult {
-            response,
-            result: Some(output_managed.to_native()),
-        };
+        self.record_events(&mut world, &response);
 
-        Ok(call_result)
+        get_call_result_from_tx_response::<OutputManaged>(to, response)
     }
 }
 
@@ -149,6 +343,9 @@ impl<A> DeployExecutor for MockExecutor<A>
         Address: for<'a> From<&'a A::Target>
 {
     /// Asynchronously deploys a smart contract to the mock blockchain environment.
+    ///
+    /// As with `sc_call`, a failed deployment returns a `TransactionError::SmartContractExecutionError` instead of panicking,
+    /// and the events emitted by a successful deployment are recorded.
     async fn sc_deploy<
         OutputManaged
     >(
@@ -168,6 +365,8 @@ impl<A> DeployExecutor for MockExecutor<A>
             return Err(MockDeployError::CallerAddressNotPresent.into())
         };
 
+        let mut opt_response = None;
+
         let mut tx = world.tx()
             .from(Bech32Address::from_bech32_string(Address::from(caller).to_bech32_string()?))
             .raw_deploy()
@@ -175,30 +374,28 @@ impl<A> DeployExecutor for MockExecutor<A>
             .code(BytesValue::from(bytes))
             .with_gas_limit(gas_limit)
             .egld(multiversx_sc::types::BigUint::from(egld_value))
-            .returns(ReturnsNewAddress)
-            .returns(ReturnsRawResult);
+            .returns(ReturnsHandledOrError::new())
+            .returns(WithRawTxResponse(|response| opt_response = Some(response.clone())));
 
         for argument in arguments {
             tx = tx.argument(&argument);
         }
 
-        let (new_address, raw_result_managed) = tx.run();
-        let mut raw_result: Vec<Vec<u8>> = raw_result_managed
-            .into_vec()
-            .iter()
-            .map(|buffer| buffer.to_boxed_bytes().into_vec())
-            .collect();
+        let _ = tx.run();
 
-        let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
-            return Err(TransactionError::CannotDecodeSmartContractResult { response: Default::default() }.into())
+        let Some(response) = opt_response else {
+            unreachable!("the raw response handler is always executed")
         };
 
-        let call_result = CallResult {
-            response: Default::default(),
-            result: Some(output_managed.to_native()),
+        let Some(new_address) = response.new_deployed_address.as_ref() else {
+            return Err(TransactionError::NoSCDeployLogInTheResponse.into())
         };
 
-        Ok((Address::from_bytes(*new_address.as_array()), call_result))
+        let new_address = Address::from_bytes(*new_address.as_array());
+        self.record_events(&mut world, &response);
+        let call_result = get_call_result_from_tx_response::<OutputManaged>(&new_address, response)?;
+
+        Ok((new_address, call_result))
     }
 }
 
@@ -215,38 +412,56 @@ impl<A> QueryExecutor for MockExecutor<A>
         Address: for<'a> From<&'a A::Target>
 {
     /// Executes a simulated smart contract query in the mock environment.
+    ///
+    /// The query is sent by `caller`, or by the caller of the executor if none is provided, or by the contract itself otherwise.
+    /// The given payments are transferred to the contract before executing the endpoint.
+    /// Every change made by the query, including the payments, is rolled back afterward.
+    /// A failed query, for example because of a `require!`, returns a `MockQueryError::ExecutionFailed` error.
+    ///
+    /// The mocked world only holds its current state, so providing a `block` results in a `MockQueryError::HistoricalQueryNotSupported` error.
     async fn execute<OutputManaged>(
         &self,
         to: &Address,
         function: String,
         arguments: Vec<Vec<u8>>,
-        _egld_value: BigUint,
-        _esdt_transfers: Vec<TokenTransfer>
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>
     ) -> Result<OutputManaged::Native, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
     {
-        let mut world = self.world.lock().await;
+        if block.is_some() {
+            return Err(MockQueryError::HistoricalQueryNotSupported.into())
+        }
 
-        // TODO: use from when run() becomes available with it in the MvX Rust SDK
-        // TODO: use payments when run() becomes available with them in the MvX Rust SDK
-        // let from = self.opt_caller.as_ref().map(|a| Address::from(a)).unwrap_or_else(|| to.clone());
+        let from = caller
+            .or_else(|| self.opt_caller.as_ref().map(|caller| Address::from(caller)))
+            .unwrap_or_else(|| to.clone());
 
-        let mut tx = world.query()
-            .to(Bech32Address::from_bech32_string(to.to_bech32_string()?))
-            .raw_call(function)
-            .returns(ReturnsRawResult);
+        let mut world = self.world.lock().await;
 
-        for argument in arguments {
-            tx = tx.argument(&argument);
+        let tx_result = execute_rolled_back_query(
+            &mut world,
+            MockQuery {
+                from,
+                to: to.clone(),
+                function,
+                arguments,
+                egld_value,
+                esdt_transfers,
+            }
+        )?;
+
+        if !tx_result.result_status.is_success() {
+            return Err(MockQueryError::ExecutionFailed {
+                status: tx_result.result_status.as_u64(),
+                message: tx_result.result_message
+            }.into())
         }
 
-        let raw_result_managed = tx.run();
-        let mut raw_result: Vec<Vec<u8>> = raw_result_managed
-            .into_vec()
-            .iter()
-            .map(|buffer| buffer.to_boxed_bytes().into_vec())
-            .collect();
+        let mut raw_result = tx_result.result_values;
 
         let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
             return Err(TransactionError::CannotDecodeSmartContractResult { response: Default::default() }.into())
@@ -254,4 +469,42 @@ impl<A> QueryExecutor for MockExecutor<A>
 
         Ok(output_managed.to_native())
     }
-}
\ No newline at end of file
+}
+
+/// The `MockExecutor` implementation for the `QueryEventsExecutor` trait, querying the events emitted by the calls and the deployments executed by the executor.
+///
+/// The events are shared between an executor and its clones. Each event is timestamped with the block timestamp of the world at the time it was emitted.
+///
+/// The events are recorded by the executor rather than by the world: executors created separately through `MockExecutor::new`
+/// don't see each other's events, even when they share the same world. Use `clone` or `as_caller` to get executors sharing their events.
+#[async_trait]
+impl<A> QueryEventsExecutor for MockExecutor<A>
+    where
+        A: Deref + Send + Sync,
+        Address: for<'a> From<&'a A::Target>
+{
+    /// Queries the recorded events, honoring the options and the filters the same way the Elasticsearch executor does.
+    ///
+    /// Without a sort option, the events are returned in the order they were emitted.
+    /// Unlike Elasticsearch, which returns 10 events by default, all the matching events are returned when no `size` is provided.
+    async fn execute<EventReturn, FilterOptions>(
+        &self,
+        contract_address: &Address,
+        event_identifier: &str,
+        options: Option<EventQueryOptions>,
+        filters: Option<FilterOptions>
+    ) -> Result<Vec<EventQueryResult<EventReturn>>, ExecutorError>
+        where
+            EventReturn: DecodableEvent + Send + Sync,
+            FilterOptions: IntoFilterTerms + Send + Sync
+    {
+        let filter_terms = filters.map_or_else(Vec::new, |filters| filters.into_filter_terms());
+
+        self.events.query(
+            contract_address,
+            event_identifier,
+            options,
+            filter_terms
+        )
+    }
+}
@@ -1 +1,7 @@
-pub mod executor;
\ No newline at end of file
+pub mod account;
+pub mod block;
+pub mod events;
+pub mod executor;
+pub mod response;
+pub mod snapshot;
+pub(crate) mod vm;
\ No newline at end of file
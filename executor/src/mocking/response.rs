@@ -0,0 +1,135 @@
+use base64::Engine;
+use multiversx_sc::codec::TopDecodeMulti;
+use multiversx_sc_scenario::scenario_model::Log;
+
+use novax_data::{Address, NativeConvertible};
+
+use crate::call_result::CallResult;
+use crate::error::executor::ExecutorError;
+use crate::error::transaction::TransactionError;
+use crate::{TransactionOnNetwork, TransactionOnNetworkTransaction, TransactionOnNetworkTransactionLogs, TransactionOnNetworkTransactionLogsEvents, TxResponse};
+
+/// Converts the response of a transaction executed on the mocked world into the `CallResult` the network executor would have returned.
+///
+/// A failed transaction results in a `TransactionError::SmartContractExecutionError`, holding the status and the message of the error,
+/// such as the message of a `require!` or a `sc_panic!`.
+///
+/// The logs of the transaction are converted into the events of the response, their topics and data being base64 encoded as on the gateway,
+/// which returns no data for an event with an empty one.
+/// Note that the scenario engine doesn't meter the gas, the `gas_used` of the response is therefore always 0.
+pub(crate) fn get_call_result_from_tx_response<OutputManaged>(
+    to: &Address,
+    response: TxResponse
+) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+    where
+        OutputManaged: TopDecodeMulti + NativeConvertible
+{
+    if !response.tx_error.is_success() {
+        return Err(TransactionError::SmartContractExecutionError {
+            status: response.tx_error.status.as_u64(),
+            message: response.tx_error.message
+        }.into())
+    }
+
+    let events = response.logs
+        .iter()
+        .map(get_event_from_log)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let tx_on_network = TransactionOnNetwork {
+        transaction: TransactionOnNetworkTransaction {
+            gas_used: response.gas_used,
+            smart_contract_results: None,
+            status: "successful".to_string(),
+            logs: Some(TransactionOnNetworkTransactionLogs {
+                address: to.to_bech32_string()?,
+                events,
+            }),
+        },
+    };
+
+    let mut raw_result = response.out;
+
+    let Ok(output_managed) = OutputManaged::multi_decode(&mut raw_result) else {
+        return Err(TransactionError::CannotDecodeSmartContractResult { response: tx_on_network }.into())
+    };
+
+    Ok(CallResult {
+        response: tx_on_network,
+        result: Some(output_managed.to_native()),
+    })
+}
+
+fn get_event_from_log(log: &Log) -> Result<TransactionOnNetworkTransactionLogsEvents, ExecutorError> {
+    let engine = base64::engine::general_purpose::STANDARD;
+
+    Ok(TransactionOnNetworkTransactionLogsEvents {
+        address: Address::from_bytes(*log.address.as_array()).to_bech32_string()?,
+        identifier: log.endpoint.clone(),
+        topics: log.topics.iter().map(|topic| engine.encode(topic)).collect(),
+        data: log.data.first().filter(|data| !data.is_empty()).map(|data| engine.encode(data)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc::types::Address as ScAddress;
+    use multiversx_sc_scenario::multiversx_chain_vm::tx_mock::TxResult;
+    use multiversx_sc_scenario::scenario_model::Log;
+
+    use novax_data::Address;
+
+    use crate::mocking::response::get_call_result_from_tx_response;
+    use crate::{ExecutorError, TransactionError, TransactionOnNetworkTransactionLogsEvents, TxResponse};
+
+    const CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+
+    #[test]
+    fn test_get_call_result_success() {
+        let contract_address = Address::from_bech32_string(CONTRACT_ADDRESS).unwrap();
+
+        let response = TxResponse {
+            out: vec![vec![5]],
+            logs: vec![
+                Log {
+                    address: ScAddress::from(contract_address.to_bytes()),
+                    endpoint: "emptyEvent".to_string(),
+                    topics: vec![b"emptyEvent".to_vec()],
+                    data: vec![vec![1, 2]],
+                }
+            ],
+            ..Default::default()
+        };
+
+        let result = get_call_result_from_tx_response::<u64>(&contract_address, response).unwrap();
+
+        let expected_events = vec![
+            TransactionOnNetworkTransactionLogsEvents {
+                address: CONTRACT_ADDRESS.to_string(),
+                identifier: "emptyEvent".to_string(),
+                topics: vec!["ZW1wdHlFdmVudA==".to_string()],
+                data: Some("AQI=".to_string()),
+            }
+        ];
+
+        assert!(result.response.is_success());
+        assert_eq!(result.response.transaction.logs.unwrap().events, expected_events);
+        assert_eq!(result.result, Some(5));
+    }
+
+    #[test]
+    fn test_get_call_result_error() {
+        let contract_address = Address::from_bech32_string(CONTRACT_ADDRESS).unwrap();
+
+        let response = TxResponse::from_tx_result(TxResult::from_panic_string("amount too low"));
+
+        let result = get_call_result_from_tx_response::<u64>(&contract_address, response).err().unwrap();
+
+        let expected = ExecutorError::Transaction(TransactionError::SmartContractExecutionError {
+            status: 4,
+            message: "amount too low".to_string(),
+        });
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use multiversx_sc_scenario::multiversx_chain_vm::world_mock::{AccountData, BlockInfo as VMBlockInfo, BlockchainState, EsdtData};
+use multiversx_sc_scenario::scenario_model::{Account, AddressValue, BigUintValue, BlockInfo, BytesKey, BytesValue, Esdt, EsdtInstance, EsdtObject, NewAddress, SetStateStep, U64Value};
+use serde::{Deserialize, Serialize};
+
+/// The identifier of a snapshot taken by `MockExecutor::snapshot`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct MockSnapshotId(pub u64);
+
+/// A copy of the state of a mocked world, along with the number of events recorded by the executor when it was taken.
+pub(crate) struct MockWorldSnapshot {
+    pub state: BlockchainState,
+    pub events_count: usize,
+}
+
+/// Returns the step turning the `current` state of a world back into the `snapshot` one.
+///
+/// Every account of the snapshot is set back entirely, replacing its balances, storage, code and nonce.
+/// The scenario engine cannot remove an account, the accounts created after the snapshot are therefore replaced by empty ones.
+/// A smart contract account cannot exist without code: the contracts deployed after the snapshot keep their code and owner, but lose their balances and storage.
+pub(crate) fn get_restore_step(current: &BlockchainState, snapshot: &BlockchainState) -> SetStateStep {
+    let mut step = SetStateStep::new();
+
+    for (address, account) in snapshot.accounts.iter() {
+        step.accounts.insert(address.into(), get_scenario_account(account));
+    }
+
+    for address in current.accounts.keys() {
+        if !snapshot.accounts.contains_key(address) {
+            step.accounts.insert(address.into(), get_empty_scenario_account(&current.accounts[address]));
+        }
+    }
+
+    for ((creator_address, creator_nonce), new_address) in snapshot.new_addresses.iter() {
+        step.new_addresses.push(NewAddress {
+            creator_address: AddressValue::from(creator_address),
+            creator_nonce: U64Value::from(*creator_nonce),
+            new_address: AddressValue::from(new_address),
+        });
+    }
+
+    step.previous_block_info = Box::new(Some(get_scenario_block_info(&snapshot.previous_block_info)));
+    step.current_block_info = Box::new(Some(get_scenario_block_info(&snapshot.current_block_info)));
+
+    step
+}
+
+fn get_scenario_account(account: &AccountData) -> Account {
+    Account {
+        comment: None,
+        nonce: Some(U64Value::from(account.nonce)),
+        balance: Some(BigUintValue::from(&account.egld_balance)),
+        esdt: account.esdt
+            .iter()
+            .map(|(identifier, esdt)| (BytesKey::from(identifier.clone()), get_scenario_esdt(esdt)))
+            .collect(),
+        username: Some(BytesValue::from(account.username.clone())),
+        storage: account.storage
+            .iter()
+            .map(|(key, value)| (BytesKey::from(key.clone()), BytesValue::from(value.clone())))
+            .collect::<BTreeMap<_, _>>(),
+        code: account.contract_path.clone().map(BytesValue::from),
+        code_metadata: Some(BytesValue::from(account.code_metadata.to_byte_array().to_vec())),
+        owner: account.contract_owner.as_ref().map(AddressValue::from),
+        developer_rewards: Some(BigUintValue::from(&account.developer_rewards)),
+    }
+}
+
+fn get_empty_scenario_account(account: &AccountData) -> Account {
+    Account {
+        code: account.contract_path.clone().map(BytesValue::from),
+        owner: account.contract_owner.as_ref().map(AddressValue::from),
+        ..Account::new()
+    }
+}
+
+fn get_scenario_esdt(esdt: &EsdtData) -> Esdt {
+    Esdt::Full(EsdtObject {
+        token_identifier: None,
+        instances: esdt.instances
+            .get_instances()
+            .values()
+            .map(|instance| EsdtInstance {
+                nonce: Some(U64Value::from(instance.nonce)),
+                balance: Some(BigUintValue::from(&instance.balance)),
+                creator: instance.metadata.creator.as_ref().map(AddressValue::from),
+                royalties: Some(U64Value::from(instance.metadata.royalties)),
+                hash: instance.metadata.hash.clone().map(BytesValue::from),
+                uri: instance.metadata.uri.iter().cloned().map(BytesValue::from).collect(),
+                attributes: Some(BytesValue::from(instance.metadata.attributes.clone())),
+            })
+            .collect(),
+        last_nonce: Some(U64Value::from(esdt.last_nonce)),
+        roles: esdt.get_roles()
+            .into_iter()
+            .map(|role| String::from_utf8_lossy(&role).into_owned())
+            .collect(),
+        frozen: Some(U64Value::from(esdt.frozen as u64)),
+    })
+}
+
+fn get_scenario_block_info(block_info: &VMBlockInfo) -> BlockInfo {
+    BlockInfo {
+        block_timestamp: Some(U64Value::from(block_info.block_timestamp)),
+        block_nonce: Some(U64Value::from(block_info.block_nonce)),
+        block_round: Some(U64Value::from(block_info.block_round)),
+        block_epoch: Some(U64Value::from(block_info.block_epoch)),
+        block_random_seed: Some(BytesValue::from(block_info.block_random_seed.to_vec())),
+    }
+}
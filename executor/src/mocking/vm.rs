@@ -0,0 +1,116 @@
+//! The internals of the scenario engine used to execute queries with a caller and payments, and to read the state of a world:
+//! the query context opened by `whitebox` on a dummy contract, `TxContextStack::static_peek` and `execute_builtin_function_or_default`.
+//!
+//! They aren't part of the stable API of `multiversx-sc-scenario`, the multiversx dependencies are therefore pinned to `=0.57.1`.
+//! Upgrading them requires checking this module first, nothing else in the crate relies on these internals.
+
+use multiversx_sc::contract_base::ContractBase;
+use multiversx_sc::types::Address as ScAddress;
+use multiversx_sc_scenario::imports::Bech32Address;
+use multiversx_sc_scenario::multiversx_chain_vm::tx_execution::execute_current_tx_context_input;
+use multiversx_sc_scenario::multiversx_chain_vm::tx_mock::{TxCache, TxContextStack, TxInput, TxResult, TxTokenTransfer};
+use multiversx_sc_scenario::multiversx_chain_vm::types::VMAddress;
+use multiversx_sc_scenario::multiversx_chain_vm::world_mock::{AccountData, BlockchainState};
+use multiversx_sc_scenario::{DebugApi, ScenarioTxWhitebox};
+use num_bigint::BigUint;
+
+use novax_data::Address;
+
+use crate::error::executor::ExecutorError;
+use crate::ScenarioWorld;
+use crate::utils::transaction::token_transfer::TokenTransfer;
+
+/// An empty contract object, only used to open a query context on the mocked world.
+struct QueryContextContract;
+
+impl ContractBase for QueryContextContract {
+    type Api = DebugApi;
+}
+
+/// The parameters of a query executed by [`execute_rolled_back_query`].
+pub(crate) struct MockQuery {
+    pub from: Address,
+    pub to: Address,
+    pub function: String,
+    pub arguments: Vec<Vec<u8>>,
+    pub egld_value: BigUint,
+    pub esdt_transfers: Vec<TokenTransfer>,
+}
+
+/// Executes a query as a transaction sent by `query.from` with the query's payments, then rolls back every change it made.
+///
+/// The scenario engine runs its own queries with the contract as the caller and without payments.
+/// Instead, the transaction is executed inside a query context of the world: the payments are transferred and the endpoint is called
+/// on a cache of the world's state, which is dropped once the query context ends, exactly like the updates of a regular query.
+///
+/// The caller doesn't have to exist in the world, as on the network. It must however hold the payments.
+pub(crate) fn execute_rolled_back_query(world: &mut ScenarioWorld, query: MockQuery) -> Result<TxResult, ExecutorError> {
+    let tx_input = TxInput {
+        from: VMAddress::new(query.from.to_bytes()),
+        to: VMAddress::new(query.to.to_bytes()),
+        egld_value: query.egld_value,
+        esdt_values: query.esdt_transfers
+            .into_iter()
+            .map(|transfer| TxTokenTransfer {
+                token_identifier: transfer.identifier.into_bytes(),
+                nonce: transfer.nonce,
+                value: transfer.amount,
+            })
+            .collect(),
+        func_name: query.function.into(),
+        args: query.arguments,
+        gas_limit: u64::MAX,
+        ..Default::default()
+    };
+
+    let mut opt_tx_result = None;
+
+    world.query()
+        .to(Bech32Address::from_bech32_string(query.to.to_bech32_string()?))
+        .whitebox(|| QueryContextContract, |_| {
+            opt_tx_result = Some(execute_in_current_context(tx_input));
+        });
+
+    let Some(tx_result) = opt_tx_result else {
+        unreachable!("the whitebox closure is always executed")
+    };
+
+    Ok(tx_result)
+}
+
+/// Calls `f` with the current state of the world, such as its accounts and its block infos.
+///
+/// The state of a `ScenarioWorld` isn't publicly readable, it is therefore read from inside a query context, which exposes it.
+pub(crate) fn with_world_state<R>(world: &mut ScenarioWorld, f: impl FnOnce(&BlockchainState) -> R) -> R {
+    let mut opt_result = None;
+
+    world.query()
+        .to(Bech32Address::from(ScAddress::zero()))
+        .whitebox(|| QueryContextContract, |_| {
+            opt_result = Some(f(TxContextStack::static_peek().blockchain_ref()));
+        });
+
+    let Some(result) = opt_result else {
+        unreachable!("the whitebox closure is always executed")
+    };
+
+    result
+}
+
+fn execute_in_current_context(tx_input: TxInput) -> TxResult {
+    let tx_context = TxContextStack::static_peek();
+    let tx_cache = TxCache::new(tx_context.blockchain_cache_arc());
+
+    let caller_exists = tx_cache.with_account_or_else(&tx_input.from, |_| true, || false);
+    if !caller_exists {
+        tx_cache.insert_account(AccountData::new_empty(tx_input.from.clone()));
+    }
+
+    let (tx_result, _) = tx_context.vm_ref.execute_builtin_function_or_default(
+        tx_input,
+        tx_cache,
+        execute_current_tx_context_input
+    );
+
+    tx_result
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use crate::network::models::generic::response::GatewayResponse;
+
+pub type BlockGatewayResponse = GatewayResponse<BlockGatewayData>;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockGatewayData {
+    pub block: BlockGatewayInfo
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockGatewayInfo {
+    pub nonce: u64,
+    pub round: u64,
+    /// The timestamp of the block, in seconds.
+    pub timestamp: u64,
+    /// The timestamp of the block in milliseconds, only returned by the recent gateways.
+    #[serde(default)]
+    pub timestamp_ms: Option<u64>,
+}
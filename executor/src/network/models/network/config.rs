@@ -13,4 +13,8 @@ pub struct NetworkGatewayConfigData {
     pub erd_chain_id: String,
     pub erd_min_gas_price: u64,
     pub erd_min_transaction_version: u8,
-}
\ No newline at end of file
+    /// The duration of a round, in milliseconds. `None` if the gateway doesn't provide it.
+    pub erd_round_duration: Option<u64>,
+    /// The timestamp of the genesis, in seconds. `None` if the gateway doesn't provide it.
+    pub erd_start_time: Option<u64>,
+}
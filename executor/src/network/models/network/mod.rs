@@ -1 +1,3 @@
-pub mod config;
\ No newline at end of file
+pub mod config;
+pub mod status;
+pub mod block;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use crate::network::models::generic::response::GatewayResponse;
+
+pub type NetworkGatewayStatusResponse = GatewayResponse<NetworkGatewayStatus>;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NetworkGatewayStatus {
+    pub status: NetworkGatewayStatusData
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NetworkGatewayStatusData {
+    pub erd_nonce: u64,
+    pub erd_current_round: u64,
+}
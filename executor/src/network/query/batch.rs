@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use multiversx_sc::codec::{DecodeErrorHandler, TopDecodeMulti, TopDecodeMultiInput};
+use num_bigint::BigUint;
+use tokio::sync::{OnceCell, Semaphore};
+
+use novax_data::{Address, NativeConvertible, parse_query_return_bytes_data};
+
+use crate::{ExecutorError, QueryBlock, QueryExecutor, TokenTransfer};
+
+type QueryBatchOutput = Vec<Vec<u8>>;
+type QueryBatchResult = Result<QueryBatchOutput, ExecutorError>;
+
+/// A query executor running the queries made through it with a bounded parallelism, and executing identical queries only once.
+///
+/// Create one batch per group of queries, clone it into each generated query and await them all with [`QueryBatch::join`]:
+///
+/// ```ignore
+/// let batch = QueryBatch::new(ProxyQueryExecutor::new(gateway_url), 10);
+///
+/// let reserves = batch.join(
+///     pair_addresses.iter().map(|address| {
+///         PairContract::new(address).query(batch.clone()).get_reserves_and_total_supply()
+///     })
+/// ).await;
+/// ```
+///
+/// Queries returning different types can be awaited together by mapping them into a common type, like an enum.
+///
+/// The successful result of a query is kept for the lifetime of the batch, so a query made twice through the same batch always returns the same result.
+/// A failed query isn't kept, making it again through the batch executes it again.
+pub struct QueryBatch<Executor: QueryExecutor> {
+    executor: Arc<Executor>,
+    semaphore: Arc<Semaphore>,
+    queries: Arc<std::sync::Mutex<HashMap<QueryBatchKey, Arc<OnceCell<QueryBatchOutput>>>>>,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct QueryBatchKey {
+    to: [u8; 32],
+    function: String,
+    arguments: Vec<Vec<u8>>,
+    egld_value: BigUint,
+    esdt_transfers: Vec<(String, u64, BigUint)>,
+    caller: Option<[u8; 32]>,
+    block: Option<QueryBlock>,
+}
+
+impl<Executor: QueryExecutor> QueryBatch<Executor> {
+    /// Creates a batch executing at most `max_concurrent_queries` queries at the same time.
+    pub fn new(executor: Executor, max_concurrent_queries: usize) -> Self {
+        Self {
+            executor: Arc::new(executor),
+            semaphore: Arc::new(Semaphore::new(max_concurrent_queries.max(1))),
+            queries: Arc::new(std::sync::Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Awaits all the given queries, returning their results in the same order.
+    ///
+    /// A failing query doesn't prevent the others from completing, its error is returned at its position.
+    pub async fn join<T, E, Query>(&self, queries: impl IntoIterator<Item = Query>) -> Vec<Result<T, E>>
+    where
+        Query: Future<Output = Result<T, E>>
+    {
+        futures::future::join_all(queries).await
+    }
+
+    async fn execute_raw(&self, key: QueryBatchKey, esdt_transfers: Vec<TokenTransfer>) -> QueryBatchResult {
+        let cell = self.queries.lock().unwrap().entry(key.clone()).or_default().clone();
+
+        // The identical queries waiting for a failing one execute it in turn, instead of sharing its error
+        cell.get_or_try_init(|| async move {
+            let Ok(_permit) = self.semaphore.acquire().await else {
+                unreachable!("the semaphore is never closed")
+            };
+
+            self.executor
+                .execute::<RawQueryOutput>(
+                    &Address::from_bytes(key.to),
+                    key.function,
+                    key.arguments,
+                    key.egld_value,
+                    esdt_transfers,
+                    key.caller.map(Address::from_bytes),
+                    key.block
+                )
+                .await
+        })
+            .await
+            .cloned()
+    }
+}
+
+impl<Executor: QueryExecutor> Clone for QueryBatch<Executor> {
+    fn clone(&self) -> Self {
+        Self {
+            executor: self.executor.clone(),
+            semaphore: self.semaphore.clone(),
+            queries: self.queries.clone(),
+        }
+    }
+}
+
+impl<Executor: QueryExecutor> Debug for QueryBatch<Executor> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryBatch")
+            .field("available_permits", &self.semaphore.available_permits())
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<Executor: QueryExecutor> QueryExecutor for QueryBatch<Executor> {
+    /// Executes the query with the wrapped executor once a slot is available,
+    /// or waits for the result of an identical query made through this batch.
+    async fn execute<OutputManaged>(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>
+    ) -> Result<OutputManaged::Native, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let key = QueryBatchKey {
+            to: to.to_bytes(),
+            function,
+            arguments,
+            egld_value,
+            esdt_transfers: esdt_transfers.iter()
+                .map(|transfer| (transfer.identifier.clone(), transfer.nonce, transfer.amount.clone()))
+                .collect(),
+            caller: caller.map(|caller| caller.to_bytes()),
+            block,
+        };
+
+        let mut raw_result = self.execute_raw(key, esdt_transfers).await?;
+
+        Ok(parse_query_return_bytes_data::<OutputManaged>(&mut raw_result)?.to_native())
+    }
+
+    async fn get_pinned_block(&self) -> Option<QueryBlock> {
+        self.executor.get_pinned_block().await
+    }
+}
+
+/// The undecoded values returned by a query, so the result of a query can be shared between queries decoding it into different types.
+struct RawQueryOutput(Vec<Vec<u8>>);
+
+impl TopDecodeMulti for RawQueryOutput {
+    fn multi_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeMultiInput,
+        H: DecodeErrorHandler
+    {
+        let mut values = vec![];
+        while input.has_next() {
+            values.push(input.next_value::<Vec<u8>, H>(h)?);
+        }
+
+        Ok(RawQueryOutput(values))
+    }
+}
+
+impl NativeConvertible for RawQueryOutput {
+    type Native = Vec<Vec<u8>>;
+
+    fn to_native(&self) -> Self::Native {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use multiversx_sc::codec::TopDecodeMulti;
+    use num_bigint::BigUint;
+
+    use novax_data::{Address, NativeConvertible};
+
+    use crate::{ExecutorError, NetworkQueryError, QueryBlock, QueryExecutor, TokenTransfer};
+    use crate::network::query::batch::QueryBatch;
+
+    /// Returns the first argument of the query, or an error if the function is "fail".
+    #[derive(Default)]
+    struct CountingQueryExecutor {
+        calls: AtomicUsize,
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl QueryExecutor for CountingQueryExecutor {
+        async fn execute<OutputManaged>(
+            &self,
+            _to: &Address,
+            function: String,
+            mut arguments: Vec<Vec<u8>>,
+            _egld_value: BigUint,
+            _esdt_transfers: Vec<TokenTransfer>,
+            _caller: Option<Address>,
+            _block: Option<QueryBlock>
+        ) -> Result<OutputManaged::Native, ExecutorError>
+            where
+                OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+        {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            let running = self.running.fetch_add(1, Ordering::Relaxed) + 1;
+            self.max_running.fetch_max(running, Ordering::Relaxed);
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+
+            self.running.fetch_sub(1, Ordering::Relaxed);
+
+            if function == "fail" {
+                return Err(NetworkQueryError::ErrorInResponse { message: "failed".to_string() }.into())
+            }
+
+            Ok(OutputManaged::multi_decode(&mut arguments).unwrap().to_native())
+        }
+    }
+
+    async fn query(batch: &QueryBatch<Arc<CountingQueryExecutor>>, function: &str, value: u64) -> Result<u64, ExecutorError> {
+        batch.execute::<u64>(
+            &Address::default(),
+            function.to_string(),
+            vec![value.to_be_bytes().to_vec()],
+            BigUint::from(0u8),
+            vec![],
+            None,
+            None
+        ).await
+    }
+
+    #[tokio::test]
+    async fn test_join_returns_results_in_order() {
+        let executor = Arc::new(CountingQueryExecutor::default());
+        let batch = QueryBatch::new(executor.clone(), 2);
+
+        let result = batch.join((0..5u64).rev().map(|value| query(&batch, "getValue", value))).await;
+        let expected: Vec<Result<u64, ExecutorError>> = vec![Ok(4), Ok(3), Ok(2), Ok(1), Ok(0)];
+
+        assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn test_join_bounds_parallelism() {
+        let executor = Arc::new(CountingQueryExecutor::default());
+        let batch = QueryBatch::new(executor.clone(), 3);
+
+        batch.join((0..10u64).map(|value| query(&batch, "getValue", value))).await;
+
+        assert_eq!(executor.calls.load(Ordering::Relaxed), 10);
+        assert_eq!(executor.max_running.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn test_join_deduplicates_identical_queries() {
+        let executor = Arc::new(CountingQueryExecutor::default());
+        let batch = QueryBatch::new(executor.clone(), 10);
+
+        let result = batch.join([
+            query(&batch, "getValue", 1),
+            query(&batch, "getValue", 1),
+            query(&batch, "getOtherValue", 1),
+            query(&batch, "getValue", 1),
+        ]).await;
+
+        assert_eq!(result, vec![Ok(1), Ok(1), Ok(1), Ok(1)]);
+        assert_eq!(executor.calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_join_keeps_per_query_errors() {
+        let executor = Arc::new(CountingQueryExecutor::default());
+        let batch = QueryBatch::new(executor.clone(), 2);
+
+        let result = batch.join([
+            query(&batch, "getValue", 1),
+            query(&batch, "fail", 2),
+            query(&batch, "getValue", 3),
+        ]).await;
+
+        let expected = vec![
+            Ok(1),
+            Err(NetworkQueryError::ErrorInResponse { message: "failed".to_string() }.into()),
+            Ok(3),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn test_failed_query_is_executed_again() {
+        let executor = Arc::new(CountingQueryExecutor::default());
+        let batch = QueryBatch::new(executor.clone(), 2);
+
+        let first_result = query(&batch, "fail", 1).await;
+        let second_result = query(&batch, "fail", 1).await;
+
+        assert!(first_result.is_err());
+        assert!(second_result.is_err());
+        assert_eq!(executor.calls.load(Ordering::Relaxed), 2);
+    }
+}
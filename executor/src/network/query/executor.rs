@@ -6,7 +6,7 @@ use num_bigint::BigUint;
 
 use novax_data::{NativeConvertible, parse_query_return_string_data};
 
-use crate::{BlockchainProxy, ExecutorError, NetworkQueryError, QueryExecutor, TokenTransfer, VmValuesQueryRequest};
+use crate::{BlockchainProxy, ExecutorError, NetworkQueryError, QueryBlock, QueryExecutor, TokenTransfer, VmValuesQueryRequest};
 use crate::network::query::proxy::NetworkBlockchainProxy;
 use crate::utils::transaction::normalization::NormalizationInOut;
 
@@ -45,22 +45,31 @@ impl<Proxy: BlockchainProxy> QueryNetworkExecutor<Proxy> {
 #[async_trait]
 impl<Proxy: BlockchainProxy> QueryExecutor for QueryNetworkExecutor<Proxy> {
     /// Executes a smart contract query on the real blockchain environment.
+    ///
+    /// The query is made by `caller`, or by the contract itself if no caller is provided.
     async fn execute<OutputManaged>(
         &self,
         to: &novax_data::Address,
         function: String,
         arguments: Vec<Vec<u8>>,
         egld_value: BigUint,
-        esdt_transfers: Vec<TokenTransfer>
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<novax_data::Address>,
+        block: Option<QueryBlock>
     ) -> Result<OutputManaged::Native, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
     {
         let sc_address = to.to_bech32_string()?;
+        let sender = match caller {
+            Some(caller) => caller.to_bech32_string()?,
+            None => sc_address.clone()
+        };
 
+        // The normalization sets the receiver to the sender for the NFT and multi transfers, the query still targets the contract
         let normalized = NormalizationInOut {
-            sender: sc_address.clone(),
-            receiver: sc_address,
+            sender,
+            receiver: sc_address.clone(),
             function_name: Some(function),
             arguments,
             egld_value,
@@ -68,11 +77,12 @@ impl<Proxy: BlockchainProxy> QueryExecutor for QueryNetworkExecutor<Proxy> {
         }.normalize()?;
 
         let vm_request = VmValuesQueryRequest {
-            sc_address: normalized.receiver,
+            sc_address,
             func_name: normalized.function_name.unwrap_or_default(),
             caller: Some(normalized.sender),
             value: Some(normalized.egld_value.to_string()),
             args: encode_arguments(&normalized.arguments),
+            block,
         };
 
         let blockchain = Proxy::new(self.gateway_url.clone());
@@ -99,7 +109,9 @@ impl QueryExecutor for &str {
         function: String,
         arguments: Vec<Vec<u8>>,
         egld_value: BigUint,
-        esdt_transfers: Vec<TokenTransfer>
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<novax_data::Address>,
+        block: Option<QueryBlock>
     ) -> Result<OutputManaged::Native, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
@@ -110,7 +122,9 @@ impl QueryExecutor for &str {
                 function,
                 arguments,
                 egld_value,
-                esdt_transfers
+                esdt_transfers,
+                caller,
+                block
             )
             .await
     }
@@ -128,7 +142,9 @@ impl QueryExecutor for String {
         function: String,
         arguments: Vec<Vec<u8>>,
         egld_value: BigUint,
-        esdt_transfers: Vec<TokenTransfer>
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<novax_data::Address>,
+        block: Option<QueryBlock>
     ) -> Result<OutputManaged::Native, ExecutorError>
         where
             OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
@@ -139,7 +155,9 @@ impl QueryExecutor for String {
                 function,
                 arguments,
                 egld_value,
-                esdt_transfers
+                esdt_transfers,
+                caller,
+                block
             )
             .await
     }
@@ -1,3 +1,5 @@
+pub mod batch;
 pub mod executor;
 pub mod models;
-pub mod proxy;
\ No newline at end of file
+pub mod proxy;
+pub mod session;
\ No newline at end of file
@@ -1,5 +1,7 @@
 use serde::{Serialize};
 
+use crate::QueryBlock;
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VmValuesQueryRequest {
@@ -7,5 +9,8 @@ pub struct VmValuesQueryRequest {
     pub func_name: String,
     pub args: Vec<String>,
     pub caller: Option<String>,
-    pub value: Option<String>
-}
\ No newline at end of file
+    pub value: Option<String>,
+    /// The block at which the query is executed, sent as a URL parameter rather than in the body.
+    #[serde(skip)]
+    pub block: Option<QueryBlock>
+}
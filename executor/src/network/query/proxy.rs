@@ -4,7 +4,7 @@ use reqwest::Client;
 use crate::error::executor::ExecutorError;
 use crate::network::query::models::request::VmValuesQueryRequest;
 use crate::network::query::models::response::{VmValuesQueryResponse, VmValuesQueryResponseData};
-use crate::NetworkQueryError;
+use crate::{NetworkQueryError, QueryBlock};
 
 #[async_trait]
 pub trait BlockchainProxy: Send + Sync {
@@ -29,7 +29,7 @@ impl BlockchainProxy for NetworkBlockchainProxy {
     }
 
     async fn execute_vmquery(&self, vm_request: &VmValuesQueryRequest) -> Result<VmValuesQueryResponseData, ExecutorError> {
-        let url = format!("{}/vm-values/query", self.gateway_url);
+        let url = get_vm_query_url(&self.gateway_url, vm_request.block.as_ref());
 
         let json = serde_json::to_string(vm_request)
             .map_err(|_| NetworkQueryError::CannotSerializeVmValuesRequestBody)?;
@@ -61,4 +61,44 @@ impl BlockchainProxy for NetworkBlockchainProxy {
 
         Ok(response_data)
     }
-}
\ No newline at end of file
+}
+
+fn get_vm_query_url(gateway_url: &str, block: Option<&QueryBlock>) -> String {
+    let url = format!("{gateway_url}/vm-values/query");
+
+    match block {
+        None => url,
+        Some(QueryBlock::Nonce(nonce)) => format!("{url}?blockNonce={nonce}"),
+        Some(QueryBlock::Hash(hash)) => format!("{url}?blockHash={hash}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::network::query::proxy::get_vm_query_url;
+    use crate::QueryBlock;
+
+    #[test]
+    fn test_get_vm_query_url_latest_block() {
+        let result = get_vm_query_url("https://gateway.multiversx.com", None);
+        let expected = "https://gateway.multiversx.com/vm-values/query";
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_vm_query_url_block_nonce() {
+        let result = get_vm_query_url("https://gateway.multiversx.com", Some(&QueryBlock::Nonce(1000)));
+        let expected = "https://gateway.multiversx.com/vm-values/query?blockNonce=1000";
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_vm_query_url_block_hash() {
+        let result = get_vm_query_url("https://gateway.multiversx.com", Some(&QueryBlock::Hash("abcd".to_string())));
+        let expected = "https://gateway.multiversx.com/vm-values/query?blockHash=abcd";
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use multiversx_sc::codec::TopDecodeMulti;
+use num_bigint::BigUint;
+
+use novax_data::{Address, NativeConvertible};
+use novax_request::gateway::client::GatewayClient;
+
+use crate::{ExecutorError, QueryBlock, QueryExecutor, TokenTransfer};
+use crate::network::utils::network::get_network_status;
+
+/// A query executor pinning every query made through it to the same block, so they all read a coherent snapshot of the blockchain.
+///
+/// Reading several views one after the other may otherwise mix the states of different blocks,
+/// for example when computing prices from the reserves and the supplies of several pairs.
+///
+/// Block nonces are specific to a shard, a session should therefore only target contracts of the shard it has been resolved on.
+/// Queries explicitly targeting a block through `with_block_nonce` or `with_block_hash` keep their own block.
+///
+/// The pinned block is part of the caching keys of the generated queries, so their results are cached separately from the latest state's.
+#[derive(Clone, Debug)]
+pub struct QuerySession<Executor: QueryExecutor> {
+    /// The executor running the pinned queries.
+    pub executor: Executor,
+    /// The block every query made through this session is executed at.
+    pub block: QueryBlock,
+}
+
+impl<Executor: QueryExecutor> QuerySession<Executor> {
+    /// Creates a session pinned to `block`.
+    pub fn new(executor: Executor, block: QueryBlock) -> Self {
+        Self {
+            executor,
+            block
+        }
+    }
+
+    /// Creates a session pinned to the latest block of `shard`, resolved once from the gateway.
+    pub async fn at_latest_block<Client: GatewayClient>(executor: Executor, client: &Client, shard: u32) -> Result<Self, ExecutorError> {
+        let status = get_network_status(client, shard).await?;
+
+        Ok(Self::new(executor, QueryBlock::Nonce(status.erd_nonce)))
+    }
+}
+
+#[async_trait]
+impl<Executor: QueryExecutor> QueryExecutor for QuerySession<Executor> {
+    /// Executes the query with the wrapped executor, at the block of the session.
+    async fn execute<OutputManaged>(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>
+    ) -> Result<OutputManaged::Native, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        self.executor
+            .execute::<OutputManaged>(
+                to,
+                function,
+                arguments,
+                egld_value,
+                esdt_transfers,
+                caller,
+                Some(block.unwrap_or_else(|| self.block.clone()))
+            )
+            .await
+    }
+
+    async fn get_pinned_block(&self) -> Option<QueryBlock> {
+        Some(self.block.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use multiversx_sc::codec::TopDecodeMulti;
+    use num_bigint::BigUint;
+
+    use novax_data::{Address, NativeConvertible};
+
+    use crate::{ExecutorError, NetworkQueryError, QueryBlock, QueryExecutor, TokenTransfer};
+    use crate::mock::request::MockClient;
+    use crate::network::query::session::QuerySession;
+
+    /// Returns the nonce of the block targeted by the query.
+    struct BlockNonceQueryExecutor;
+
+    #[async_trait]
+    impl QueryExecutor for BlockNonceQueryExecutor {
+        async fn execute<OutputManaged>(
+            &self,
+            _to: &Address,
+            _function: String,
+            _arguments: Vec<Vec<u8>>,
+            _egld_value: BigUint,
+            _esdt_transfers: Vec<TokenTransfer>,
+            _caller: Option<Address>,
+            block: Option<QueryBlock>
+        ) -> Result<OutputManaged::Native, ExecutorError>
+            where
+                OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+        {
+            let Some(QueryBlock::Nonce(nonce)) = block else {
+                return Err(NetworkQueryError::Other { id: "test".to_string(), reason: format!("{block:?}") }.into())
+            };
+
+            let mut raw_result = vec![nonce.to_be_bytes().to_vec()];
+
+            Ok(OutputManaged::multi_decode(&mut raw_result).unwrap().to_native())
+        }
+    }
+
+    async fn execute_query(session: &QuerySession<BlockNonceQueryExecutor>, block: Option<QueryBlock>) -> Result<u64, ExecutorError> {
+        session.execute::<u64>(
+            &Address::default(),
+            "getReserves".to_string(),
+            vec![],
+            BigUint::from(0u8),
+            vec![],
+            None,
+            block
+        ).await
+    }
+
+    #[tokio::test]
+    async fn test_execute_pins_block() {
+        let session = QuerySession::new(BlockNonceQueryExecutor, QueryBlock::Nonce(10));
+
+        assert_eq!(execute_query(&session, None).await.unwrap(), 10);
+        assert_eq!(execute_query(&session, None).await.unwrap(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_execute_keeps_explicit_block() {
+        let session = QuerySession::new(BlockNonceQueryExecutor, QueryBlock::Nonce(10));
+
+        let result = execute_query(&session, Some(QueryBlock::Nonce(5))).await.unwrap();
+
+        assert_eq!(result, 5);
+    }
+
+    #[tokio::test]
+    async fn test_at_latest_block() {
+        let client = MockClient::new();
+        let session = QuerySession::at_latest_block(BlockNonceQueryExecutor, &client, 1).await.unwrap();
+
+        assert_eq!(session.block, QueryBlock::Nonce(100));
+        assert_eq!(execute_query(&session, None).await.unwrap(), 100);
+    }
+}
@@ -13,10 +13,13 @@ use crate::network::models::network::config::NetworkGatewayConfig;
 use crate::network::transaction::models::send_request::TransactionSendRequest;
 use crate::network::transaction::models::transaction_on_network::{FINAL_TRANSACTION_STATUS, TransactionOnNetwork};
 use crate::network::utils::address::get_address_info;
-use crate::network::utils::network::get_network_config;
+use crate::network::utils::network::{get_network_config, get_network_status};
 use crate::network::utils::transaction::{get_transaction_on_network, send_transaction};
 use crate::network::utils::wallet::{SignableTransaction, Wallet};
-use crate::utils::date::get_current_timestamp::{get_current_timestamp, get_timestamp_of_next_block};
+use crate::utils::date::block_clock::BlockClock;
+use crate::utils::date::get_current_timestamp::get_current_timestamp;
+
+const METACHAIN_SHARD: u32 = u32::MAX;
 
 #[async_trait]
 pub trait BlockchainInteractor: Sized + Send + Sync {
@@ -38,6 +41,7 @@ pub struct Interactor {
     pub gateway_url: String,
     pub wallet: Wallet,
     pub network_config: NetworkGatewayConfig,
+    pub block_clock: BlockClock,
     pub refresh_strategy: TransactionRefreshStrategy,
     pub timeout: Duration
 }
@@ -57,6 +61,7 @@ impl Interactor {
 
     async fn wait_for_execution(&self, tx_hash: &str) -> Result<TransactionOnNetwork, ExecutorError> {
         let end_timestamp = get_current_timestamp()? + self.timeout;
+        let mut opt_current_nonce: Option<u64> = None;
 
         loop {
             let transaction_on_network = get_transaction_on_network(
@@ -76,8 +81,17 @@ impl Interactor {
 
             match self.refresh_strategy {
                 TransactionRefreshStrategy::EachBlock => {
-                    let timestamp_of_next_block = get_timestamp_of_next_block(current_timestamp)?;
-                    tokio::time::sleep(timestamp_of_next_block - current_timestamp).await;
+                    let current_nonce = match opt_current_nonce {
+                        Some(current_nonce) => current_nonce,
+                        None => get_network_status(&self.gateway_url, METACHAIN_SHARD).await?.erd_nonce
+                    };
+
+                    // A round may not produce a block, the transaction is only fetched again once the nonce increased
+                    let next_block = self.block_clock.wait_for_next_block(&self.gateway_url, METACHAIN_SHARD, current_nonce);
+
+                    if let Ok(next_nonce) = tokio::time::timeout(end_timestamp - current_timestamp, next_block).await {
+                        opt_current_nonce = Some(next_nonce?);
+                    }
                 }
                 TransactionRefreshStrategy::EachDuration(duration) => {
                     tokio::time::sleep(duration).await;
@@ -126,12 +140,20 @@ impl BlockchainInteractor for Interactor {
         wallet: Wallet
     ) -> Result<Self, ExecutorError> {
         let network_config = get_network_config(&gateway_url).await?;
+        let genesis_block_clock = BlockClock::from_network_config(&network_config);
+
+        // Syncing only improves the accuracy of the clock, failing to do so shouldn't prevent sending transactions
+        let block_clock = genesis_block_clock
+            .synced_with_latest_block(&gateway_url, METACHAIN_SHARD)
+            .await
+            .unwrap_or(genesis_block_clock);
 
         Ok(
             Self {
                 gateway_url,
                 wallet,
                 network_config,
+                block_clock,
                 refresh_strategy: TransactionRefreshStrategy::EachBlock,
                 timeout: Duration::from_secs(10)
             }
@@ -1,7 +1,9 @@
 use novax_request::gateway::client::GatewayClient;
 use crate::error::gateway::GatewayError;
 use crate::ExecutorError;
+use crate::network::models::network::block::{BlockGatewayInfo, BlockGatewayResponse};
 use crate::network::models::network::config::{NetworkGatewayConfig, NetworkGatewayConfigResponse};
+use crate::network::models::network::status::{NetworkGatewayStatusData, NetworkGatewayStatusResponse};
 
 pub async fn get_network_config<Client: GatewayClient>(client: &Client) -> Result<NetworkGatewayConfig, ExecutorError> {
     let Ok((_, Some(text)))= client.with_appended_url("/network/config").get().await else {
@@ -17,4 +19,36 @@ pub async fn get_network_config<Client: GatewayClient>(client: &Client) -> Resul
     };
 
     Ok(data)
-}
\ No newline at end of file
+}
+
+pub async fn get_network_status<Client: GatewayClient>(client: &Client, shard: u32) -> Result<NetworkGatewayStatusData, ExecutorError> {
+    let Ok((_, Some(text))) = client.with_appended_url(&format!("/network/status/{shard}")).get().await else {
+        return Err(GatewayError::CannotFetchNetworkStatus { shard }.into())
+    };
+
+    let Ok(info) = serde_json::from_str::<NetworkGatewayStatusResponse>(&text) else {
+        return Err(GatewayError::CannotParseNetworkStatus { shard }.into())
+    };
+
+    let Some(data) = info.data else {
+        return Err(GatewayError::CannotParseNetworkStatus { shard }.into())
+    };
+
+    Ok(data.status)
+}
+
+pub async fn get_block_by_nonce<Client: GatewayClient>(client: &Client, shard: u32, nonce: u64) -> Result<BlockGatewayInfo, ExecutorError> {
+    let Ok((_, Some(text))) = client.with_appended_url(&format!("/block/{shard}/by-nonce/{nonce}")).get().await else {
+        return Err(GatewayError::CannotFetchBlock { shard, nonce }.into())
+    };
+
+    let Ok(info) = serde_json::from_str::<BlockGatewayResponse>(&text) else {
+        return Err(GatewayError::CannotParseBlock { shard, nonce }.into())
+    };
+
+    let Some(data) = info.data else {
+        return Err(GatewayError::CannotParseBlock { shard, nonce }.into())
+    };
+
+    Ok(data.block)
+}
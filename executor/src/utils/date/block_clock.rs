@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use novax_request::gateway::client::GatewayClient;
+use serde::{Deserialize, Serialize};
+
+use crate::ExecutorError;
+use crate::network::models::network::config::NetworkGatewayConfig;
+use crate::network::utils::network::{get_block_by_nonce, get_network_config, get_network_status};
+use crate::utils::date::get_current_timestamp::get_current_timestamp;
+
+/// Computes when the rounds of a shard start, so blocks can be awaited without polling the gateway blindly.
+///
+/// A round starts every `round_duration` from a reference point, which is either the genesis of the network
+/// (see [`BlockClock::from_network_config`]) or a recent block (see [`BlockClock::from_gateway`]).
+/// Anchoring on a recent block keeps the clock accurate when the round duration changed since the genesis.
+///
+/// Note that a round may not produce a block. Use [`BlockClock::wait_for_next_block`] to follow the actual block nonce.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct BlockClock {
+    /// The duration of a round.
+    pub round_duration: Duration,
+    /// The timestamp at which the reference round started.
+    pub reference_timestamp: Duration,
+    /// The round starting at `reference_timestamp`.
+    pub reference_round: u64,
+}
+
+impl BlockClock {
+    /// A 6 seconds round duration aligned on the Unix epoch, the behavior of NovaX before the block clock was introduced.
+    pub const DEFAULT: BlockClock = BlockClock::new(Duration::from_secs(6), Duration::ZERO, 0);
+
+    pub const fn new(round_duration: Duration, reference_timestamp: Duration, reference_round: u64) -> Self {
+        Self {
+            round_duration,
+            reference_timestamp,
+            reference_round
+        }
+    }
+
+    /// Creates a clock anchored on the genesis of the network.
+    ///
+    /// The round duration and the genesis timestamp missing from the config are taken from [`BlockClock::DEFAULT`].
+    pub fn from_network_config(network_config: &NetworkGatewayConfig) -> Self {
+        let round_duration = network_config.config.erd_round_duration
+            .map(Duration::from_millis)
+            .unwrap_or(Self::DEFAULT.round_duration);
+
+        let reference_timestamp = network_config.config.erd_start_time
+            .map(Duration::from_secs)
+            .unwrap_or(Self::DEFAULT.reference_timestamp);
+
+        Self::new(round_duration, reference_timestamp, 0)
+    }
+
+    /// Creates a clock anchored on the latest block of `shard`, using the round duration from the network config.
+    pub async fn from_gateway<Client: GatewayClient>(client: &Client, shard: u32) -> Result<Self, ExecutorError> {
+        let network_config = get_network_config(client).await?;
+
+        Self::from_network_config(&network_config)
+            .synced_with_latest_block(client, shard)
+            .await
+    }
+
+    /// Returns a clock with the same round duration, anchored on the latest block of `shard`.
+    ///
+    /// All the shards share the same rounds, so any shard can be used.
+    pub async fn synced_with_latest_block<Client: GatewayClient>(&self, client: &Client, shard: u32) -> Result<Self, ExecutorError> {
+        let status = get_network_status(client, shard).await?;
+        let block = get_block_by_nonce(client, shard, status.erd_nonce).await?;
+
+        let reference_timestamp = match block.timestamp_ms {
+            Some(timestamp_ms) => Duration::from_millis(timestamp_ms),
+            None => Duration::from_secs(block.timestamp)
+        };
+
+        Ok(Self::new(self.round_duration, reference_timestamp, block.round))
+    }
+
+    /// Returns the round in progress at `timestamp`.
+    pub fn get_round_at(&self, timestamp: Duration) -> u64 {
+        if self.round_duration.is_zero() || timestamp <= self.reference_timestamp {
+            return self.reference_round
+        }
+
+        let elapsed_rounds = (timestamp - self.reference_timestamp).as_nanos() / self.round_duration.as_nanos();
+
+        self.reference_round.saturating_add(elapsed_rounds as u64)
+    }
+
+    /// Returns the timestamp at which `round` starts.
+    pub fn get_timestamp_of_round(&self, round: u64) -> Duration {
+        let elapsed_rounds = round.saturating_sub(self.reference_round) as u128;
+        let nanos = self.reference_timestamp.as_nanos() + elapsed_rounds * self.round_duration.as_nanos();
+
+        Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32)
+    }
+
+    /// Returns the timestamp at which the round following the one in progress at `current_timestamp` starts.
+    pub fn get_timestamp_of_next_block(&self, current_timestamp: Duration) -> Duration {
+        if current_timestamp < self.reference_timestamp {
+            return self.reference_timestamp
+        }
+
+        self.get_timestamp_of_round(self.get_round_at(current_timestamp) + 1)
+    }
+
+    /// Waits until a block with a nonce greater than `current_nonce` is produced on `shard`, and returns its nonce.
+    ///
+    /// The gateway is only queried at the start of each round.
+    pub async fn wait_for_next_block<Client: GatewayClient>(&self, client: &Client, shard: u32, current_nonce: u64) -> Result<u64, ExecutorError> {
+        loop {
+            let current_timestamp = get_current_timestamp()?;
+            tokio::time::sleep(self.get_timestamp_of_next_block(current_timestamp) - current_timestamp).await;
+
+            let status = get_network_status(client, shard).await?;
+
+            if status.erd_nonce > current_nonce {
+                return Ok(status.erd_nonce)
+            }
+        }
+    }
+}
+
+impl Default for BlockClock {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::mock::request::MockClient;
+    use crate::network::models::network::config::NetworkGatewayConfig;
+    use crate::utils::date::block_clock::BlockClock;
+
+    #[test]
+    fn test_get_timestamp_of_next_block_start_of_block() {
+        let result = BlockClock::DEFAULT.get_timestamp_of_next_block(Duration::from_secs(6));
+        let expected = Duration::from_secs(12);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_timestamp_of_next_block_mid_of_block() {
+        let result = BlockClock::DEFAULT.get_timestamp_of_next_block(Duration::from_secs(8));
+        let expected = Duration::from_secs(12);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_timestamp_of_next_block_end_of_block() {
+        let result = BlockClock::DEFAULT.get_timestamp_of_next_block(Duration::from_secs(11));
+        let expected = Duration::from_secs(12);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_timestamp_of_next_block_not_aligned_on_epoch() {
+        let clock = BlockClock::new(Duration::from_millis(600), Duration::from_millis(1_000_100), 50);
+
+        let result = clock.get_timestamp_of_next_block(Duration::from_millis(1_001_000));
+        let expected = Duration::from_millis(1_001_300);
+
+        assert_eq!(result, expected);
+        assert_eq!(clock.get_round_at(Duration::from_millis(1_001_000)), 51);
+    }
+
+    #[test]
+    fn test_get_timestamp_of_next_block_before_reference() {
+        let clock = BlockClock::new(Duration::from_secs(6), Duration::from_secs(100), 0);
+
+        let result = clock.get_timestamp_of_next_block(Duration::from_secs(10));
+        let expected = Duration::from_secs(100);
+
+        assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn test_from_gateway() {
+        let result = BlockClock::from_gateway(&MockClient::new(), 1).await.unwrap();
+        let expected = BlockClock::new(Duration::from_secs(6), Duration::from_millis(1700000000600), 110);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_network_config_without_round_infos() {
+        let network_config: NetworkGatewayConfig = serde_json::from_str(
+            r#"{"config":{"erd_chain_id":"D","erd_min_gas_price":1000000000,"erd_min_transaction_version":1}}"#
+        ).unwrap();
+
+        let result = BlockClock::from_network_config(&network_config);
+
+        assert_eq!(result, BlockClock::DEFAULT);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_next_block_skips_empty_rounds() {
+        let client = MockClient::new();
+        let clock = BlockClock::new(Duration::from_millis(10), Duration::ZERO, 0);
+
+        let result = clock.wait_for_next_block(&client, 1, 100).await.unwrap();
+
+        assert_eq!(result, 101);
+        assert_eq!(client.get_status_calls_count(), 3);
+    }
+}
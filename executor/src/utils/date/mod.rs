@@ -1 +1,2 @@
-pub mod get_current_timestamp;
\ No newline at end of file
+pub mod get_current_timestamp;
+pub mod block_clock;
@@ -2,4 +2,5 @@
 pub mod transaction;
 pub mod date;
 pub mod events;
+pub mod query;
 // TODO: this mod is a duplicate with the caching crate
\ No newline at end of file
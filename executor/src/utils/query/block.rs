@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// The block at which the state of the blockchain is read by a historical query.
+///
+/// Queries are executed against the latest state by default. Providing a `QueryBlock` makes the gateway
+/// execute them against the state right after the given block, which requires the observers to keep the history.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Debug)]
+pub enum QueryBlock {
+    /// The block with the given nonce.
+    Nonce(u64),
+    /// The block with the given hash, hex-encoded.
+    Hash(String),
+}
@@ -6,7 +6,16 @@ use novax_token::error::token::TokenError;
 pub enum NovaXMockingError {
     UnableToFetchAddressKeys,
     UnableToParseAddressKeys,
+    UnableToFetchAddressRoles,
+    UnableToParseAddressRoles,
+    UnableToFetchAccountInfos,
+    UnableToParseAccountInfos,
+    UnableToOpenInfosFile,
+    UnableToWriteInfosFile,
     UnableToReadInfosFromFile,
+    UnableToReadScenarioFile,
+    UnableToWriteScenarioFile,
+    UnableToReadScenarioCodeFile(String),
     NovaXError(NovaXError),
     NovaXTokenError(TokenError)
 }
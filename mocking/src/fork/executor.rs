@@ -0,0 +1,591 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use async_trait::async_trait;
+use futures::FutureExt;
+use multiversx_sc::codec::TopDecodeMulti;
+use multiversx_sc::imports::CodeMetadata;
+use multiversx_sc_snippets::hex;
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::BytesValue;
+use num_bigint::BigUint;
+use tokio::sync::Mutex;
+use novax::Address;
+use novax::caching::CachingStrategy;
+use novax::executor::call_result::CallResult;
+use novax::executor::{DecodableEvent, DeployExecutor, EventQueryOptions, EventQueryResult, ExecutorError, IntoFilterTerms, MockForkError, MockSnapshotId, QueryBlock, QueryEventsExecutor, QueryExecutor, StandardMockExecutor, TokenTransfer, TransactionExecutor};
+use novax::data::NativeConvertible;
+use novax_request::gateway::client::GatewayClient;
+use crate::errors::mocking::NovaXMockingError;
+use crate::world::dependencies::{find_contract_addresses, find_contract_addresses_in_bytes, is_contract_address, is_deployed_contract, ScenarioWorldDependenciesOptions};
+use crate::world::fetch::ScenarioWorldFetchOptions;
+use crate::world::infos::{fetch_address, ScenarioWorldInfos};
+use crate::world::scenario::get_set_state_step;
+use crate::world::serde::ScenarioWorldInfosJson;
+use crate::ScenarioWorld;
+
+/// Registers the code of a contract in the world, as the function given to `ScenarioWorldInfos::into_world` does.
+type RegisterFunction = dyn Fn([u8; 32], &str, &mut ScenarioWorld) + Send + Sync;
+
+#[derive(Default)]
+struct ForkState {
+    /// The accounts fetched so far, and the ones read from the infos file.
+    infos: ScenarioWorldInfos,
+    /// The addresses whose account has been put in the world, or was already there.
+    loaded_addresses: HashSet<[u8; 32]>,
+    /// The hex encoded codes already registered in the world.
+    registered_codes: HashSet<String>,
+    /// The addresses loaded when each snapshot was taken.
+    snapshots_loaded_addresses: HashMap<MockSnapshotId, HashSet<[u8; 32]>>
+}
+
+/// An executor running calls, deployments and queries in a local scenario world forking a network.
+///
+/// Nothing is cloned up front: the first time a request touches an account, such as the caller or the called contract,
+/// the account is fetched from the gateway with its nonce, EGLD and ESDT balances, storage and code, then put in the world.
+/// The smart contract addresses passed as arguments are loaded too.
+/// The contracts referenced in the storage of a loaded contract are loaded too, within the limits of the `ScenarioWorldDependenciesOptions`,
+/// so the contracts it calls are available. The scenario engine doesn't report the storage keys it reads, the accounts are therefore
+/// loaded whole rather than key by key.
+///
+/// An account is never fetched twice: once loaded, its state only changes through the executed transactions.
+/// The fetched accounts are cached in the given `CachingStrategy`, and saved into the infos file if one is set through `with_infos_file`.
+/// A later run using the same file reads the accounts from it instead of the gateway, being fully offline and deterministic as long as
+/// it touches the same accounts.
+///
+/// A request touching an account which hasn't been loaded, for example the receiver of a transfer made by a contract,
+/// fails with a `MockForkError::AccountNotLoaded` error, `load_accounts` allowing to load it beforehand.
+///
+/// The ESDT roles, the token properties and the block infos of the network aren't loaded.
+#[derive(Clone)]
+pub struct ForkExecutor<Client, Caching>
+where
+    Client: GatewayClient + Clone,
+    Caching: CachingStrategy
+{
+    executor: StandardMockExecutor,
+    world: Arc<Mutex<ScenarioWorld>>,
+    gateway_client: Client,
+    caching: Caching,
+    register: Arc<RegisterFunction>,
+    opt_caller: Option<Address>,
+    fetch_options: ScenarioWorldFetchOptions,
+    dependencies_options: ScenarioWorldDependenciesOptions,
+    opt_infos_file: Option<PathBuf>,
+    state: Arc<std::sync::Mutex<ForkState>>,
+    /// Held while loading accounts, so an account needed by concurrent requests is fetched once.
+    load_lock: Arc<Mutex<()>>
+}
+
+impl<Client, Caching> ForkExecutor<Client, Caching>
+where
+    Client: GatewayClient + Clone,
+    Caching: CachingStrategy
+{
+    /// Creates an executor forking the network of the gateway, starting from an empty world.
+    ///
+    /// `register` is called once for each distinct code of the loaded contracts, and registers it in the world,
+    /// the same way as the function given to `ScenarioWorldInfos::into_world`.
+    pub fn new<Register>(gateway_client: Client, caching: Caching, register: Register) -> Self
+    where
+        Register: Fn([u8; 32], &str, &mut ScenarioWorld) + Send + Sync + 'static
+    {
+        let world = Arc::new(Mutex::new(ScenarioWorld::new()));
+
+        ForkExecutor {
+            executor: StandardMockExecutor::new(world.clone(), None),
+            world,
+            gateway_client,
+            caching,
+            register: Arc::new(register),
+            opt_caller: None,
+            fetch_options: ScenarioWorldFetchOptions::default(),
+            dependencies_options: ScenarioWorldDependenciesOptions::default(),
+            opt_infos_file: None,
+            state: Arc::new(std::sync::Mutex::new(ForkState::default())),
+            load_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Sends the calls and the deployments from the given bech32 address.
+    pub fn with_caller(mut self, caller: String) -> Self {
+        self.opt_caller = Some(Address::from(caller.as_str()));
+        self.executor = self.executor.as_caller(caller);
+
+        self
+    }
+
+    pub fn with_fetch_options(mut self, options: ScenarioWorldFetchOptions) -> Self {
+        self.fetch_options = options;
+
+        self
+    }
+
+    pub fn with_dependencies_options(mut self, options: ScenarioWorldDependenciesOptions) -> Self {
+        self.dependencies_options = options;
+
+        self
+    }
+
+    /// Reads the accounts from the infos file instead of the gateway, and saves the accounts fetched from the gateway into it.
+    ///
+    /// The file doesn't have to exist, it is created when the first account is fetched.
+    pub fn with_infos_file<P: AsRef<Path>>(mut self, file_path: P) -> Result<Self, NovaXMockingError> {
+        let file_path = file_path.as_ref();
+
+        if file_path.exists() {
+            self.state.lock().unwrap().infos = ScenarioWorldInfos::from_file(file_path)?;
+        }
+
+        self.opt_infos_file = Some(file_path.to_path_buf());
+
+        Ok(self)
+    }
+
+    /// Returns the accounts fetched so far, as they were on the network. The changes made by the executed transactions aren't included.
+    pub fn get_infos(&self) -> ScenarioWorldInfos {
+        self.state.lock().unwrap().infos.clone()
+    }
+
+    /// Returns the executor running the requests in the local world, sharing this executor's world, events and snapshots.
+    ///
+    /// It can be used to manipulate the world, for example through blocks. The requests sent through it don't load any account.
+    /// The snapshots should be taken and restored through `snapshot` and `restore` rather than through this executor,
+    /// otherwise the accounts loaded after a snapshot are left empty once it is restored.
+    pub fn get_mock_executor(&self) -> StandardMockExecutor {
+        self.executor.clone()
+    }
+
+    /// Takes a snapshot of the world, as `MockExecutor::snapshot` does, remembering the accounts loaded so far.
+    pub async fn snapshot(&self) -> MockSnapshotId {
+        let _load_guard = self.load_lock.lock().await;
+
+        let id = self.executor.snapshot().await;
+
+        let mut state = self.state.lock().unwrap();
+        let loaded_addresses = state.loaded_addresses.clone();
+        state.snapshots_loaded_addresses.insert(id, loaded_addresses);
+
+        id
+    }
+
+    /// Rolls the world back to the given snapshot, as `MockExecutor::restore` does.
+    ///
+    /// The accounts loaded after the snapshot are put again in the world as they were fetched, instead of being left empty.
+    pub async fn restore(&self, id: MockSnapshotId) -> Result<(), ExecutorError> {
+        let _load_guard = self.load_lock.lock().await;
+
+        self.executor.restore(id).await?;
+
+        let accounts_to_reload: Vec<(Address, ScenarioWorldInfos)> = {
+            let state = self.state.lock().unwrap();
+            let Some(snapshot_loaded_addresses) = state.snapshots_loaded_addresses.get(&id) else { return Ok(()) };
+
+            state.loaded_addresses
+                .difference(snapshot_loaded_addresses)
+                .map(|address_bytes| Address::from_bytes(*address_bytes))
+                .filter(|address| state.infos.contains_fetched_address(address))
+                .map(|address| {
+                    let infos = select_account(&state.infos, &address);
+                    (address, infos)
+                })
+                .collect()
+        };
+
+        for (address, infos) in accounts_to_reload {
+            self.put_account(&address, &infos).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads the accounts of the addresses and their dependencies if they aren't in the world yet.
+    ///
+    /// The requests load the accounts they obviously touch, this function allows loading the other ones, such as the receivers of transfers.
+    pub async fn load_accounts(&self, addresses: &[Address]) -> Result<(), ExecutorError> {
+        let _load_guard = self.load_lock.lock().await;
+
+        let mut pending: Vec<(Address, usize)> = addresses.iter().map(|address| (address.clone(), 0)).collect();
+        let mut dependencies_count = 0;
+
+        while let Some((address, depth)) = pending.pop() {
+            let Some(infos) = self.load_account(&address).await? else { continue };
+
+            if depth >= self.dependencies_options.max_depth {
+                continue
+            }
+
+            let Some(keys) = infos.address_keys.get(&address.to_bytes()) else { continue };
+
+            for dependency in find_contract_addresses(keys) {
+                if dependencies_count >= self.dependencies_options.max_addresses {
+                    break
+                }
+
+                if self.state.lock().unwrap().loaded_addresses.contains(&dependency) {
+                    continue
+                }
+
+                dependencies_count += 1;
+                pending.push((Address::from_bytes(dependency), depth + 1));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Loads the account of the address, returning its infos, or `None` if it was already in the world.
+    async fn load_account(&self, address: &Address) -> Result<Option<ScenarioWorldInfos>, ExecutorError> {
+        let address_bytes = address.to_bytes();
+
+        let is_loaded = self.state.lock().unwrap().loaded_addresses.contains(&address_bytes);
+        if is_loaded {
+            return Ok(None)
+        }
+
+        if self.executor.account_exists(address).await {
+            self.state.lock().unwrap().loaded_addresses.insert(address_bytes);
+            return Ok(None)
+        }
+
+        let opt_saved_infos = {
+            let state = self.state.lock().unwrap();
+            state.infos.contains_fetched_address(address).then(|| select_account(&state.infos, address))
+        };
+
+        let infos = match opt_saved_infos {
+            Some(infos) => infos,
+            None => {
+                let fetched_infos = self.fetch_account(address).await?;
+
+                let all_infos = {
+                    let mut state = self.state.lock().unwrap();
+                    state.infos.overwrite(fetched_infos.clone());
+                    state.infos.clone()
+                };
+
+                self.save_infos(all_infos)?;
+
+                fetched_infos
+            }
+        };
+
+        self.put_account(address, &infos).await?;
+
+        Ok(Some(infos))
+    }
+
+    async fn fetch_account(&self, address: &Address) -> Result<ScenarioWorldInfos, ExecutorError> {
+        let bech32 = address.to_bech32_string()?;
+
+        let key = format!("novax_mocking_fork_account_{}_{bech32}", self.gateway_client.get_gateway_url());
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        let result = self.caching.get_or_set_cache(
+            hasher.finish(),
+            async {
+                let (bytes, account_infos, keys, balances) = fetch_address(&self.gateway_client, address, &self.fetch_options).await?;
+
+                let mut infos = ScenarioWorldInfos::default();
+                infos.address_infos.push(account_infos);
+                infos.address_keys.insert(bytes, keys);
+                infos.address_balances.insert(bytes, balances);
+
+                Ok::<_, NovaXMockingError>(ScenarioWorldInfosJson::from(infos))
+            }
+        ).await;
+
+        match result {
+            Ok(infos) => Ok(infos.into()),
+            Err(error) => Err(MockForkError::UnableToLoadAccount { address: bech32, reason: format!("{error:?}") }.into())
+        }
+    }
+
+    async fn put_account(&self, address: &Address, infos: &ScenarioWorldInfos) -> Result<(), ExecutorError> {
+        let address_bytes = address.to_bytes();
+        let mut world = self.world.lock().await;
+        let mut state = self.state.lock().unwrap();
+
+        state.loaded_addresses.insert(address_bytes);
+
+        // The scenario engine refuses smart contract addresses without code, and nothing is deployed there anyway
+        if is_contract_address(&address_bytes) && !infos.address_infos.iter().any(is_deployed_contract) {
+            return Ok(())
+        }
+
+        let registered_codes = &mut state.registered_codes;
+        let step = get_set_state_step(infos, |address_bytes, code| {
+            let code_expr = format!("0x{code}");
+
+            if registered_codes.insert(code.to_string()) {
+                (self.register)(address_bytes, &code_expr, &mut world);
+            }
+
+            BytesValue::from(&*code_expr)
+        });
+
+        let step = step.map_err(|error| MockForkError::UnableToLoadAccount {
+            address: address.to_bech32_string().unwrap_or_default(),
+            reason: format!("{error:?}")
+        })?;
+
+        world.set_state_step(step);
+
+        Ok(())
+    }
+
+    /// Returns the addresses which aren't in the world, such as the undeployed smart contract ones.
+    async fn get_missing_addresses(&self, addresses: &[Address]) -> Vec<Address> {
+        let mut missing_addresses = vec![];
+
+        for address in addresses {
+            if !self.executor.account_exists(address).await {
+                missing_addresses.push(address.clone());
+            }
+        }
+
+        missing_addresses
+    }
+
+    fn save_infos(&self, infos: ScenarioWorldInfos) -> Result<(), ExecutorError> {
+        let Some(file_path) = self.opt_infos_file.as_ref() else { return Ok(()) };
+
+        infos.save_into_file(file_path)
+            .map_err(|error| MockForkError::UnableToSaveInfosFile { reason: format!("{error:?}") }.into())
+    }
+}
+
+#[async_trait]
+impl<Client, Caching> TransactionExecutor for ForkExecutor<Client, Caching>
+where
+    Client: GatewayClient + Clone,
+    Caching: CachingStrategy
+{
+    /// Loads the caller, the called contract, the contracts passed as arguments and their dependencies, then executes the call as `MockExecutor` does.
+    async fn sc_call<OutputManaged>(
+        &mut self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>
+    ) -> Result<CallResult<OutputManaged::Native>, ExecutorError>
+    where
+        OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let addresses = get_request_addresses(Some(to), self.opt_caller.as_ref(), &arguments);
+
+        self.load_accounts(&addresses).await?;
+        let missing_addresses = self.get_missing_addresses(&addresses).await;
+        check_receiver_loaded(to, &missing_addresses)?;
+
+        catch_account_not_loaded(
+            missing_addresses,
+            self.executor.sc_call::<OutputManaged>(
+                to,
+                function,
+                arguments,
+                gas_limit,
+                egld_value,
+                esdt_transfers
+            )
+        ).await
+    }
+}
+
+#[async_trait]
+impl<Client, Caching> DeployExecutor for ForkExecutor<Client, Caching>
+where
+    Client: GatewayClient + Clone,
+    Caching: CachingStrategy
+{
+    /// Loads the caller and the contracts passed as arguments, then executes the deployment as `MockExecutor` does.
+    async fn sc_deploy<
+        OutputManaged
+    >(
+        &mut self,
+        bytes: Vec<u8>,
+        code_metadata: CodeMetadata,
+        egld_value: BigUint,
+        arguments: Vec<Vec<u8>>,
+        gas_limit: u64
+    ) -> Result<(Address, CallResult<OutputManaged::Native>), ExecutorError>
+    where
+        OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let addresses = get_request_addresses(None, self.opt_caller.as_ref(), &arguments);
+
+        self.load_accounts(&addresses).await?;
+        let missing_addresses = self.get_missing_addresses(&addresses).await;
+
+        catch_account_not_loaded(
+            missing_addresses,
+            self.executor.sc_deploy::<OutputManaged>(
+                bytes,
+                code_metadata,
+                egld_value,
+                arguments,
+                gas_limit
+            )
+        ).await
+    }
+}
+
+#[async_trait]
+impl<Client, Caching> QueryExecutor for ForkExecutor<Client, Caching>
+where
+    Client: GatewayClient + Clone,
+    Caching: CachingStrategy
+{
+    /// Loads the sender, the queried contract, the contracts passed as arguments and their dependencies, then executes the query as `MockExecutor` does.
+    ///
+    /// As with `MockExecutor`, the world only holds its current state, so providing a `block` results in a `MockQueryError::HistoricalQueryNotSupported` error.
+    async fn execute<OutputManaged>(
+        &self,
+        to: &Address,
+        function: String,
+        arguments: Vec<Vec<u8>>,
+        egld_value: BigUint,
+        esdt_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>
+    ) -> Result<OutputManaged::Native, ExecutorError>
+    where
+        OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let addresses = get_request_addresses(Some(to), caller.as_ref().or(self.opt_caller.as_ref()), &arguments);
+
+        let missing_addresses = if block.is_none() {
+            self.load_accounts(&addresses).await?;
+            self.get_missing_addresses(&addresses).await
+        } else {
+            vec![]
+        };
+        check_receiver_loaded(to, &missing_addresses)?;
+
+        catch_account_not_loaded(
+            missing_addresses,
+            QueryExecutor::execute::<OutputManaged>(
+                &self.executor,
+                to,
+                function,
+                arguments,
+                egld_value,
+                esdt_transfers,
+                caller,
+                block
+            )
+        ).await
+    }
+}
+
+#[async_trait]
+impl<Client, Caching> QueryEventsExecutor for ForkExecutor<Client, Caching>
+where
+    Client: GatewayClient + Clone,
+    Caching: CachingStrategy
+{
+    /// Queries the events emitted by the calls and the deployments executed by this executor, as `MockExecutor` does.
+    ///
+    /// The events emitted on the network before the fork aren't loaded.
+    async fn execute<EventReturn, FilterOptions>(
+        &self,
+        contract_address: &Address,
+        event_identifier: &str,
+        options: Option<EventQueryOptions>,
+        filters: Option<FilterOptions>
+    ) -> Result<Vec<EventQueryResult<EventReturn>>, ExecutorError>
+    where
+        EventReturn: DecodableEvent + Send + Sync,
+        FilterOptions: IntoFilterTerms + Send + Sync
+    {
+        QueryEventsExecutor::execute::<EventReturn, FilterOptions>(
+            &self.executor,
+            contract_address,
+            event_identifier,
+            options,
+            filters
+        ).await
+    }
+}
+
+/// Returns the addresses touched by a request: the receiver, the sender and the smart contract addresses found in the arguments.
+fn get_request_addresses(opt_to: Option<&Address>, opt_caller: Option<&Address>, arguments: &[Vec<u8>]) -> Vec<Address> {
+    let mut addresses: Vec<Address> = opt_to.into_iter().chain(opt_caller).cloned().collect();
+
+    for argument in arguments {
+        addresses.extend(find_contract_addresses_in_bytes(argument).into_iter().map(Address::from_bytes));
+    }
+
+    addresses
+}
+
+/// Returns a `MockForkError::AccountNotLoaded` error without running the request if its receiver isn't in the world.
+fn check_receiver_loaded(to: &Address, missing_addresses: &[Address]) -> Result<(), ExecutorError> {
+    if missing_addresses.contains(to) {
+        return Err(MockForkError::AccountNotLoaded { address: to.to_bech32_string()? }.into())
+    }
+
+    Ok(())
+}
+
+/// Executes the request, turning the panic of the scenario engine on an account missing from the world into a `MockForkError::AccountNotLoaded` error.
+///
+/// The receiver is checked beforehand, this catches the other accounts touched during the execution, such as the ones called by a contract.
+/// The executor can still be used afterwards.
+///
+/// The scenario engine may panic again while handling the first panic, hiding its message. The first of the `missing_addresses` touched by the request
+/// is then reported, and the panic is propagated if there is none.
+async fn catch_account_not_loaded<T, F>(missing_addresses: Vec<Address>, request: F) -> Result<T, ExecutorError>
+where
+    F: Future<Output = Result<T, ExecutorError>>
+{
+    match AssertUnwindSafe(request).catch_unwind().await {
+        Ok(result) => result,
+        Err(panic) => {
+            let opt_message = panic.downcast_ref::<String>().map(String::as_str)
+                .or_else(|| panic.downcast_ref::<&str>().copied());
+
+            let opt_address = opt_message
+                .and_then(|message| message.strip_prefix("Account 0x"))
+                .and_then(|message| message.strip_suffix(" not found"))
+                .and_then(|address_hex| hex::decode(address_hex).ok())
+                .and_then(|address_bytes| <[u8; 32]>::try_from(address_bytes).ok())
+                .map(Address::from_bytes)
+                .or_else(|| missing_addresses.into_iter().next());
+
+            match opt_address {
+                Some(address) => Err(MockForkError::AccountNotLoaded { address: address.to_bech32_string()? }.into()),
+                None => std::panic::resume_unwind(panic)
+            }
+        }
+    }
+}
+
+/// Returns the infos of a single account.
+fn select_account(infos: &ScenarioWorldInfos, address: &Address) -> ScenarioWorldInfos {
+    let address_bytes = address.to_bytes();
+
+    fn select<T: Clone>(map: &HashMap<[u8; 32], T>, address_bytes: [u8; 32]) -> HashMap<[u8; 32], T> {
+        map.get(&address_bytes)
+            .map(|value| HashMap::from([(address_bytes, value.clone())]))
+            .unwrap_or_default()
+    }
+
+    ScenarioWorldInfos {
+        address_keys: select(&infos.address_keys, address_bytes),
+        address_balances: select(&infos.address_balances, address_bytes),
+        address_infos: infos.address_infos
+            .iter()
+            .filter(|e| Address::from_bech32_string(&e.data.account.address).is_ok_and(|e| &e == address))
+            .cloned()
+            .collect(),
+        address_esdt_roles: select(&infos.address_esdt_roles, address_bytes),
+        token_properties: HashMap::new(),
+    }
+}
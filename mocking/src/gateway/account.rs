@@ -0,0 +1,24 @@
+use novax::account::AccountInfos;
+use novax::Address;
+use novax::errors::NovaXError;
+use novax_request::gateway::client::GatewayClient;
+use crate::errors::mocking::NovaXMockingError;
+
+/// Fetches the infos of an account, including its code, its owner and its storage root hash.
+pub(crate) async fn fetch_account_infos<Client>(gateway_client: &Client, address: &Address) -> Result<AccountInfos, NovaXMockingError>
+where
+    Client: GatewayClient + ?Sized
+{
+    let bech32 = address.to_bech32_string().map_err(NovaXError::from)?;
+    let client = gateway_client.with_appended_url(&format!("/address/{bech32}"));
+
+    let Ok((status, Some(response))) = client.get().await else { return Err(NovaXMockingError::UnableToFetchAccountInfos) };
+
+    if !status.is_success() {
+        return Err(NovaXMockingError::UnableToFetchAccountInfos)
+    }
+
+    let Ok(result) = serde_json::from_str::<AccountInfos>(&response) else { return Err(NovaXMockingError::UnableToParseAccountInfos) };
+
+    Ok(result)
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use novax::Address;
 use novax::errors::NovaXError;
+use novax_request::gateway::client::GatewayClient;
 use crate::errors::mocking::NovaXMockingError;
 
 type Pairs = HashMap<String, String>;
@@ -19,12 +20,18 @@ pub struct AddressKeys {
 }
 
 impl AddressKeys {
-    pub async fn from_gateway(gateway_url: &str, address: &Address) -> Result<AddressKeys, NovaXMockingError> {
+    pub async fn from_gateway<Client>(gateway_client: &Client, address: &Address) -> Result<AddressKeys, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
         let bech32 = address.to_bech32_string().map_err(NovaXError::from)?;
-        let url = format!("{gateway_url}/address/{bech32}/keys");
+        let client = gateway_client.with_appended_url(&format!("/address/{bech32}/keys"));
 
-        let Ok(response) = reqwest::get(url).await else { return Err(NovaXMockingError::UnableToFetchAddressKeys) };
-        let Ok(response) = response.text().await else { return Err(NovaXMockingError::UnableToFetchAddressKeys) };
+        let Ok((status, Some(response))) = client.get().await else { return Err(NovaXMockingError::UnableToFetchAddressKeys) };
+
+        if !status.is_success() {
+            return Err(NovaXMockingError::UnableToFetchAddressKeys)
+        }
 
         let Ok(result) = serde_json::from_str::<AddressKeys>(&response) else { return Err(NovaXMockingError::UnableToParseAddressKeys) };
 
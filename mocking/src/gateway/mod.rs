@@ -1 +1,3 @@
 pub mod keys;
+pub mod roles;
+pub(crate) mod account;
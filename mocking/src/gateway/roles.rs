@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use novax::Address;
+use novax::errors::NovaXError;
+use novax_request::gateway::client::GatewayClient;
+use crate::errors::mocking::NovaXMockingError;
+
+type Roles = HashMap<String, Vec<String>>;
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct AddressRolesData {
+    pub roles: Roles,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct AddressRoles {
+    pub data: AddressRolesData,
+    pub error: String,
+    pub code: String,
+}
+
+impl AddressRoles {
+    pub async fn from_gateway<Client>(gateway_client: &Client, address: &Address) -> Result<AddressRoles, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        let bech32 = address.to_bech32_string().map_err(NovaXError::from)?;
+        let client = gateway_client.with_appended_url(&format!("/address/{bech32}/esdts/roles"));
+
+        let Ok((status, Some(response))) = client.get().await else { return Err(NovaXMockingError::UnableToFetchAddressRoles) };
+
+        if !status.is_success() {
+            return Err(NovaXMockingError::UnableToFetchAddressRoles)
+        }
+
+        let Ok(result) = serde_json::from_str::<AddressRoles>(&response) else { return Err(NovaXMockingError::UnableToParseAddressRoles) };
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gateway::roles::AddressRoles;
+
+    #[test]
+    fn test_deserialize_address_roles() {
+        let data = r#"{"data":{"roles":{"WEGLD-abcdef":["ESDTRoleLocalMint","ESDTRoleLocalBurn"]}},"error":"","code":"successful"}"#;
+
+        let result: AddressRoles = serde_json::from_str(data).unwrap();
+
+        assert_eq!(result.data.roles["WEGLD-abcdef"], vec!["ESDTRoleLocalMint".to_string(), "ESDTRoleLocalBurn".to_string()]);
+    }
+}
@@ -1,6 +1,10 @@
 pub mod world;
 pub mod gateway;
 pub mod errors;
+pub mod fork;
+
+#[cfg(test)]
+pub(crate) mod mock;
 
 pub use multiversx_sc::codec::TopEncodeMulti;
 pub use novax_executor::TypedResponse;
@@ -0,0 +1,224 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use async_trait::async_trait;
+use http::StatusCode;
+use serde::Serialize;
+
+use novax_request::error::request::RequestError;
+use novax_request::gateway::client::GatewayClient;
+
+const MOCK_BASE_URL: &str = "https://test.test";
+
+pub struct MockClient {
+    url: String,
+    failing_address: Option<String>,
+    address_pairs: HashMap<String, String>,
+    undeployed_addresses: Vec<String>,
+    rate_limited_paths: Arc<Mutex<HashSet<String>>>,
+    requests_count: Arc<AtomicUsize>
+}
+
+impl MockClient {
+    pub fn new() -> MockClient {
+        MockClient {
+            url: MOCK_BASE_URL.to_string(),
+            failing_address: None,
+            address_pairs: HashMap::new(),
+            undeployed_addresses: vec![],
+            rate_limited_paths: Arc::new(Mutex::new(HashSet::new())),
+            requests_count: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns a client which can't reach the gateway for the requests about the given address.
+    pub fn with_failing_address(failing_address: &str) -> MockClient {
+        MockClient {
+            failing_address: Some(failing_address.to_string()),
+            ..MockClient::new()
+        }
+    }
+
+    /// Serves the given storage pairs, as a JSON object of hex encoded keys and values, for the address instead of the default ones.
+    pub fn with_pairs(mut self, address: &str, pairs: &str) -> MockClient {
+        self.address_pairs.insert(address.to_string(), pairs.to_string());
+        self
+    }
+
+    /// Serves the address as an account without code.
+    pub fn with_undeployed_address(mut self, address: &str) -> MockClient {
+        self.undeployed_addresses.push(address.to_string());
+        self
+    }
+
+    /// Answers the first request to the path, e.g. `/address/erd1.../keys`, with a 429 Too Many Requests status.
+    pub fn with_rate_limited_path(self, path: &str) -> MockClient {
+        self.rate_limited_paths.lock().unwrap().insert(path.to_string());
+        self
+    }
+
+    /// Returns the number of requests sent by this client and the clients derived from it.
+    pub fn get_requests_count(&self) -> usize {
+        self.requests_count.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl GatewayClient for MockClient {
+    type Owned = Self;
+
+    fn get_gateway_url(&self) -> &str {
+        &self.url
+    }
+
+    fn with_appended_url(&self, url: &str) -> Self {
+        MockClient {
+            url: format!("{}{url}", self.url),
+            failing_address: self.failing_address.clone(),
+            address_pairs: self.address_pairs.clone(),
+            undeployed_addresses: self.undeployed_addresses.clone(),
+            rate_limited_paths: self.rate_limited_paths.clone(),
+            requests_count: self.requests_count.clone(),
+        }
+    }
+
+    async fn get(&self) -> Result<(StatusCode, Option<String>), RequestError> {
+        self.requests_count.fetch_add(1, Ordering::SeqCst);
+
+        let Some(path) = self.url.strip_prefix(MOCK_BASE_URL) else {
+            panic!("Url should start with mocked base");
+        };
+
+        if let Some(failing_address) = self.failing_address.as_deref() {
+            if address::get_address(path) == Some(failing_address) {
+                return Ok((StatusCode::INTERNAL_SERVER_ERROR, None))
+            }
+        }
+
+        if self.rate_limited_paths.lock().unwrap().remove(path) {
+            return Ok((StatusCode::TOO_MANY_REQUESTS, Some(r#"{"data":null,"error":"too many requests","code":"bad_request"}"#.to_string())))
+        }
+
+        let response = match address::get_address(path) {
+            Some(address) if path.ends_with("/keys") && self.address_pairs.contains_key(address) => {
+                Some(address::get_address_keys(&self.address_pairs[address]))
+            },
+            Some(address) if path == format!("/address/{address}") && self.undeployed_addresses.iter().any(|e| e == address) => {
+                Some(address::get_wallet_account(address))
+            },
+            _ => address::get_address_response(path)
+        };
+
+        if let Some((status, data)) = response {
+            Ok((status, Some(data)))
+        } else {
+            panic!("Unknown url: {}", self.url)
+        }
+    }
+
+    async fn post<Body>(&self, body: &Body) -> Result<(StatusCode, Option<String>), RequestError>
+        where
+            Body: Serialize + Send + Sync
+    {
+        self.requests_count.fetch_add(1, Ordering::SeqCst);
+
+        if self.url.strip_prefix(MOCK_BASE_URL) != Some("/vm-values/query") {
+            panic!("Unknown url: {}", self.url)
+        }
+
+        let body = serde_json::to_value(body).unwrap();
+
+        if let Some((status, data)) = token::get_token_properties_vm_query_response(&body) {
+            Ok((status, Some(data)))
+        } else {
+            panic!("Unknown query: {body}")
+        }
+    }
+}
+
+mod address {
+    use http::StatusCode;
+
+    pub fn get_address(path: &str) -> Option<&str> {
+        path.strip_prefix("/address/")?.split('/').next()
+    }
+
+    pub fn get_address_response(path: &str) -> Option<(StatusCode, String)> {
+        let address = get_address(path)?;
+
+        if path.ends_with("/keys") {
+            Some(get_address_keys(r#"{"73756d":"05"}"#))
+        } else if path.ends_with("/esdt") {
+            Some(get_address_esdt())
+        } else if path.ends_with("/esdts/roles") {
+            Some(get_address_esdt_roles())
+        } else if path == format!("/address/{address}") {
+            Some(get_contract_account(address))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_address_keys(pairs: &str) -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = format!(r#"{{"data":{{"pairs":{pairs}}},"error":"","code":"successful"}}"#);
+
+        (status, data)
+    }
+
+    fn get_address_esdt() -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = r#"{"data":{"esdts":{"WEGLD-abcdef":{"balance":"10","tokenIdentifier":"WEGLD-abcdef"}}},"error":"","code":"successful"}"#.to_string();
+
+        (status, data)
+    }
+
+    fn get_address_esdt_roles() -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = r#"{"data":{"roles":{"WEGLD-abcdef":["ESDTRoleLocalMint","ESDTRoleLocalBurn"]}},"error":"","code":"successful"}"#.to_string();
+
+        (status, data)
+    }
+
+    fn get_contract_account(address: &str) -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = format!(r#"{{"data":{{"account":{{"address":"{address}","nonce":0,"balance":"0","code":"0061736d","ownerAddress":"{address}","rootHash":"aGFzaA=="}}}},"error":"","code":"successful"}}"#);
+
+        (status, data)
+    }
+
+    pub fn get_wallet_account(address: &str) -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = format!(r#"{{"data":{{"account":{{"address":"{address}","nonce":0,"balance":"0","code":"","ownerAddress":"","rootHash":null}}}},"error":"","code":"successful"}}"#);
+
+        (status, data)
+    }
+}
+
+mod token {
+    use http::StatusCode;
+    use serde_json::Value;
+
+    pub fn get_token_properties_vm_query_response(json: &Value) -> Option<(StatusCode, String)> {
+        let sc_address = json.get("scAddress")?.as_str()?;
+        let func_name = json.get("funcName")?.as_str()?;
+        let args = json.get("args")?.as_array()?;
+
+        if sc_address != "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u" || func_name != "getTokenProperties" {
+            return None
+        }
+
+        if args.len() == 1 && args[0] == "5745474c442d616263646566" { // WEGLD-abcdef
+            Some(get_wegld_properties())
+        } else {
+            None
+        }
+    }
+
+    fn get_wegld_properties() -> (StatusCode, String) {
+        let status = StatusCode::OK;
+        let data = r#"{"data":{"data":{"returnData":["V3JhcHBlZEVHTEQ=","RnVuZ2libGVFU0RU","NEq8RBGc/KziU94F4zwBeWwS+W87zFK1BLm8K5aSfOs=","MA==","MA==","TnVtRGVjaW1hbHMtMTg=","SXNQYXVzZWQtZmFsc2U=","Q2FuVXBncmFkZS10cnVl","Q2FuTWludC10cnVl","Q2FuQnVybi10cnVl","Q2FuQ2hhbmdlT3duZXItdHJ1ZQ==","Q2FuUGF1c2UtdHJ1ZQ==","Q2FuRnJlZXplLXRydWU=","Q2FuV2lwZS10cnVl","Q2FuQWRkU3BlY2lhbFJvbGVzLXRydWU=","Q2FuVHJhbnNmZXJORlRDcmVhdGVSb2xlLWZhbHNl","TkZUQ3JlYXRlU3RvcHBlZC1mYWxzZQ==","TnVtV2lwZWQtMA=="],"returnCode":"ok","returnMessage":"","gasRemaining":18446744073659551615,"gasRefund":0,"outputAccounts":{},"deletedAccounts":null,"touchedAccounts":null,"logs":[]}},"error":"","code":"successful"}"#.to_string();
+
+        (status, data)
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+use multiversx_sc::codec::TopEncode;
+use multiversx_sc::types::EsdtLocalRole;
+use multiversx_sc_snippets::hex;
+use num_bigint::BigUint;
+use novax::account::{AccountInfos, AccountInfosAccountData, AccountInfosData};
+use novax::Address;
+use novax::data::ManagedConvertible;
+use novax::errors::NovaXError;
+use crate::errors::mocking::NovaXMockingError;
+use crate::gateway::keys::{AddressKeys, AddressKeysData};
+use crate::ScenarioWorld;
+use crate::world::infos::{ScenarioWorldInfos, ScenarioWorldInfosEsdtRoles, ScenarioWorldInfosEsdtTokenAmount};
+
+/// A builder describing the accounts of a mocked world with native values, instead of scenario expressions.
+///
+/// The result is either a `ScenarioWorld`, or a `ScenarioWorldInfos` which can be saved to a file and loaded later,
+/// the same way as a world cloned from a gateway.
+///
+/// # Example
+///
+/// ```no_run
+/// # use num_bigint::BigUint;
+/// # use novax::Address;
+/// # use novax_mocking::world::builder::{ScenarioWorldAccountBuilder, ScenarioWorldBuilder};
+/// let user = Address::from("address:user");
+///
+/// let infos = ScenarioWorldBuilder::new()
+///     .account(
+///         ScenarioWorldAccountBuilder::new(&user)
+///             .egld_balance(BigUint::from(10u8).pow(18))
+///             .esdt_balance("WEGLD-abcdef", BigUint::from(100u8))
+///     )
+///     .into_infos()
+///     .unwrap();
+///
+/// infos.save_into_file("world.json").unwrap();
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct ScenarioWorldBuilder {
+    accounts: Vec<ScenarioWorldAccountBuilder>
+}
+
+/// The description of a single account of a `ScenarioWorldBuilder`.
+#[derive(Clone, Debug)]
+pub struct ScenarioWorldAccountBuilder {
+    address: Address,
+    egld_balance: BigUint,
+    balances: Vec<ScenarioWorldInfosEsdtTokenAmount>,
+    storage: HashMap<String, String>,
+    esdt_roles: Vec<ScenarioWorldInfosEsdtRoles>,
+    opt_code: Option<(Vec<u8>, Address)>
+}
+
+impl ScenarioWorldBuilder {
+    pub fn new() -> ScenarioWorldBuilder {
+        ScenarioWorldBuilder::default()
+    }
+
+    /// Adds an account to the world. An account added twice replaces the previous one.
+    pub fn account(mut self, account: ScenarioWorldAccountBuilder) -> ScenarioWorldBuilder {
+        self.accounts.retain(|e| e.address != account.address);
+        self.accounts.push(account);
+
+        self
+    }
+
+    /// Returns the infos of the world, which can be saved to a file or turned into a `ScenarioWorld`.
+    ///
+    /// The EGLD balances are held by `address_infos`, as in fetched infos.
+    pub fn into_infos(self) -> Result<ScenarioWorldInfos, NovaXMockingError> {
+        let mut infos = ScenarioWorldInfos {
+            address_keys: HashMap::new(),
+            address_balances: HashMap::new(),
+            address_infos: vec![],
+            address_esdt_roles: HashMap::new(),
+            token_properties: HashMap::new(),
+        };
+
+        for account in self.accounts {
+            let address_bytes = account.address.to_bytes();
+
+            let (opt_code, opt_owner) = match account.opt_code {
+                Some((code, owner)) => (Some(hex::encode(code)), Some(owner.to_bech32_string().map_err(NovaXError::from)?)),
+                None => (None, None)
+            };
+
+            infos.address_infos.push(AccountInfos {
+                data: AccountInfosData {
+                    account: AccountInfosAccountData {
+                        address: account.address.to_bech32_string().map_err(NovaXError::from)?,
+                        nonce: 0,
+                        balance: account.egld_balance.to_string(),
+                        code: opt_code,
+                        owner_address: opt_owner,
+                        root_hash: None,
+                    },
+                },
+            });
+
+            if !account.balances.is_empty() {
+                infos.address_balances.insert(address_bytes, account.balances);
+            }
+
+            if !account.storage.is_empty() {
+                infos.address_keys.insert(
+                    address_bytes,
+                    AddressKeys {
+                        data: AddressKeysData { pairs: account.storage },
+                        error: "".to_string(),
+                        code: "successful".to_string(),
+                    }
+                );
+            }
+
+            if !account.esdt_roles.is_empty() {
+                infos.address_esdt_roles.insert(address_bytes, account.esdt_roles);
+            }
+        }
+
+        Ok(infos)
+    }
+
+    /// Returns the world, see `ScenarioWorldInfos::into_world` for the `register` function.
+    pub fn into_world<RegisterFunction>(self, register: RegisterFunction) -> Result<ScenarioWorld, NovaXMockingError>
+    where
+        RegisterFunction: Fn([u8; 32], &str, &mut ScenarioWorld)
+    {
+        self.into_infos()?.into_world(register)
+    }
+}
+
+impl ScenarioWorldAccountBuilder {
+    pub fn new(address: &Address) -> ScenarioWorldAccountBuilder {
+        ScenarioWorldAccountBuilder {
+            address: address.clone(),
+            egld_balance: BigUint::from(0u8),
+            balances: vec![],
+            storage: HashMap::new(),
+            esdt_roles: vec![],
+            opt_code: None,
+        }
+    }
+
+    pub fn egld_balance(self, amount: BigUint) -> ScenarioWorldAccountBuilder {
+        ScenarioWorldAccountBuilder {
+            egld_balance: amount,
+            ..self
+        }
+    }
+
+    /// Sets the balance of a fungible ESDT.
+    pub fn esdt_balance(self, token_identifier: &str, amount: BigUint) -> ScenarioWorldAccountBuilder {
+        self.put_balance(token_identifier, 0, amount, None)
+    }
+
+    /// Sets the balance of a NFT, SFT or meta ESDT instance, without attributes.
+    pub fn nft_balance(self, token_identifier: &str, nonce: u64, amount: BigUint) -> ScenarioWorldAccountBuilder {
+        self.put_balance(token_identifier, nonce, amount, None)
+    }
+
+    /// Sets the balance of a NFT, SFT or meta ESDT instance, with attributes encoded from their native type.
+    ///
+    /// A native type convertible into several managed types, such as `String` or `BigUint`, needs the managed type to be specified:
+    /// `nft_balance_with_attributes::<ManagedBuffer<StaticApi>, _>(...)`.
+    pub fn nft_balance_with_attributes<ManagedAttributes, Attributes>(
+        self,
+        token_identifier: &str,
+        nonce: u64,
+        amount: BigUint,
+        attributes: &Attributes
+    ) -> ScenarioWorldAccountBuilder
+    where
+        Attributes: ManagedConvertible<ManagedAttributes>,
+        ManagedAttributes: TopEncode
+    {
+        self.put_balance(token_identifier, nonce, amount, Some(top_encode(&attributes.to_managed())))
+    }
+
+    /// Sets a storage entry, the value being encoded from its native type.
+    ///
+    /// The key is the raw storage key, which is the name of the storage mapper for a mapper without arguments.
+    /// As for `nft_balance_with_attributes`, the managed type might need to be specified: `storage::<BigUint<StaticApi>, _>("sum", &value)`.
+    pub fn storage<ManagedValue, Value>(mut self, key: &str, value: &Value) -> ScenarioWorldAccountBuilder
+    where
+        Value: ManagedConvertible<ManagedValue>,
+        ManagedValue: TopEncode
+    {
+        self.storage.insert(
+            hex::encode(key),
+            hex::encode(top_encode(&value.to_managed()))
+        );
+
+        self
+    }
+
+    /// Sets the ESDT roles of the account for the given token, replacing the previous ones.
+    pub fn esdt_roles(mut self, token_identifier: &str, roles: &[EsdtLocalRole]) -> ScenarioWorldAccountBuilder {
+        self.esdt_roles.retain(|e| e.token_identifier != token_identifier);
+        self.esdt_roles.push(ScenarioWorldInfosEsdtRoles {
+            token_identifier: token_identifier.to_string(),
+            roles: roles.iter()
+                .map(|role| String::from_utf8_lossy(role.as_role_name()).into_owned())
+                .collect(),
+        });
+
+        self
+    }
+
+    /// Sets the code of the account, making it a smart contract owned by `owner`.
+    ///
+    /// The address of the account should be a smart contract one, and the code is passed to the `register` function of `into_world`.
+    pub fn code(self, code: Vec<u8>, owner: &Address) -> ScenarioWorldAccountBuilder {
+        ScenarioWorldAccountBuilder {
+            opt_code: Some((code, owner.clone())),
+            ..self
+        }
+    }
+
+    fn put_balance(mut self, token_identifier: &str, nonce: u64, amount: BigUint, opt_attributes: Option<Vec<u8>>) -> ScenarioWorldAccountBuilder {
+        self.balances.retain(|e| e.token_identifier != token_identifier || e.nonce != nonce);
+        self.balances.push(ScenarioWorldInfosEsdtTokenAmount {
+            token_identifier: token_identifier.to_string(),
+            nonce,
+            amount,
+            opt_attributes,
+        });
+
+        self
+    }
+}
+
+fn top_encode<T: TopEncode>(value: &T) -> Vec<u8> {
+    let mut encoded = vec![];
+    value.top_encode(&mut encoded).unwrap();
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc::types::{BigUint as ManagedBigUint, EsdtLocalRole, ManagedBuffer};
+    use multiversx_sc_snippets::imports::StaticApi;
+    use num_bigint::BigUint;
+    use novax::Address;
+    use crate::world::builder::{ScenarioWorldAccountBuilder, ScenarioWorldBuilder};
+    use crate::world::infos::{ScenarioWorldInfosEsdtRoles, ScenarioWorldInfosEsdtTokenAmount};
+
+    const USER: &str = "erd1devnet6uy8xjusvusfy3q83qadfhwrtty5fwa8ceh9cl60q2p6ysra7aaa";
+
+    #[test]
+    fn test_into_infos_balances() {
+        let address = Address::from(USER);
+
+        let infos = ScenarioWorldBuilder::new()
+            .account(
+                ScenarioWorldAccountBuilder::new(&address)
+                    .egld_balance(BigUint::from(10u8))
+                    .esdt_balance("WEGLD-abcdef", BigUint::from(5u8))
+                    .esdt_balance("WEGLD-abcdef", BigUint::from(20u8))
+                    .nft_balance_with_attributes::<ManagedBigUint<StaticApi>, _>("NFT-abcdef", 1, BigUint::from(1u8), &BigUint::from(2u8))
+            )
+            .into_infos()
+            .unwrap();
+
+        let expected_balances = vec![
+            ScenarioWorldInfosEsdtTokenAmount {
+                token_identifier: "WEGLD-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(20u8),
+                opt_attributes: None,
+            },
+            ScenarioWorldInfosEsdtTokenAmount {
+                token_identifier: "NFT-abcdef".to_string(),
+                nonce: 1,
+                amount: BigUint::from(1u8),
+                opt_attributes: Some(vec![2]),
+            },
+        ];
+
+        assert_eq!(infos.address_infos[0].data.account.balance, "10");
+        assert_eq!(infos.address_balances[&address.to_bytes()], expected_balances);
+        assert_eq!(infos.address_infos[0].data.account.address, USER);
+        assert!(infos.address_keys.is_empty());
+    }
+
+    #[test]
+    fn test_into_infos_storage_and_roles() {
+        let address = Address::from(USER);
+
+        let infos = ScenarioWorldBuilder::new()
+            .account(
+                ScenarioWorldAccountBuilder::new(&address)
+                    .storage::<ManagedBigUint<StaticApi>, _>("sum", &BigUint::from(256u16))
+                    .storage::<ManagedBuffer<StaticApi>, _>("name", &"Alice".to_string())
+                    .esdt_roles("WEGLD-abcdef", &[EsdtLocalRole::Mint, EsdtLocalRole::Burn])
+            )
+            .into_infos()
+            .unwrap();
+
+        let pairs = &infos.address_keys[&address.to_bytes()].data.pairs;
+
+        assert_eq!(pairs[&hex::encode("sum")], "0100");
+        assert_eq!(pairs[&hex::encode("name")], hex::encode("Alice"));
+
+        let expected_roles = vec![
+            ScenarioWorldInfosEsdtRoles {
+                token_identifier: "WEGLD-abcdef".to_string(),
+                roles: vec!["ESDTRoleLocalMint".to_string(), "ESDTRoleLocalBurn".to_string()],
+            }
+        ];
+
+        assert_eq!(infos.address_esdt_roles[&address.to_bytes()], expected_roles);
+    }
+
+    #[test]
+    fn test_into_infos_code() {
+        let address = Address::from("erd1qqqqqqqqqqqqqpgqp699jngundfqw07d8jzkepucvpzush6k3wvqyc44rx");
+        let owner = Address::from(USER);
+
+        let infos = ScenarioWorldBuilder::new()
+            .account(
+                ScenarioWorldAccountBuilder::new(&address)
+                    .code(vec![0, 97, 115, 109], &owner)
+            )
+            .into_infos()
+            .unwrap();
+
+        let account = &infos.address_infos[0].data.account;
+
+        assert_eq!(account.code, Some("0061736d".to_string()));
+        assert_eq!(account.owner_address, Some(USER.to_string()));
+    }
+
+    #[test]
+    fn test_account_added_twice() {
+        let address = Address::from(USER);
+
+        let infos = ScenarioWorldBuilder::new()
+            .account(ScenarioWorldAccountBuilder::new(&address).egld_balance(BigUint::from(1u8)))
+            .account(ScenarioWorldAccountBuilder::new(&address).egld_balance(BigUint::from(2u8)))
+            .into_infos()
+            .unwrap();
+
+        assert_eq!(infos.address_infos.len(), 1);
+        assert_eq!(infos.address_infos[0].data.account.balance, "2");
+    }
+}
@@ -0,0 +1,174 @@
+use multiversx_sc_snippets::hex;
+use serde::{Deserialize, Serialize};
+use novax::account::AccountInfos;
+use novax::Address;
+use crate::gateway::keys::AddressKeys;
+
+/// The length of the prefix made of zeros, shared by every smart contract address.
+const CONTRACT_ADDRESS_ZEROS_PREFIX_LENGTH: usize = 8;
+
+/// The VM type following the zeros prefix of a WASM smart contract address.
+const CONTRACT_ADDRESS_VM_TYPE: [u8; 2] = [5, 0];
+
+/// The limits of the dependency crawl of `ScenarioWorldInfos::fetch_with_dependencies`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ScenarioWorldDependenciesOptions {
+    /// The maximum number of hops from the fetched addresses. A depth of 1 only fetches the contracts referenced by the fetched addresses.
+    pub max_depth: usize,
+    /// The maximum number of dependencies fetched, in addition to the fetched addresses.
+    pub max_addresses: usize
+}
+
+impl Default for ScenarioWorldDependenciesOptions {
+    fn default() -> Self {
+        ScenarioWorldDependenciesOptions {
+            max_depth: 3,
+            max_addresses: 100,
+        }
+    }
+}
+
+/// A smart contract discovered in the storage of a fetched address.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ScenarioWorldDependency {
+    pub address: Address,
+    /// The address whose storage references the dependency.
+    pub found_in: Address,
+    /// The number of hops from the fetched addresses, starting at 1.
+    pub depth: usize
+}
+
+/// The dependencies discovered by `ScenarioWorldInfos::fetch_with_dependencies`, in the order they have been found.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
+pub struct ScenarioWorldDependencies {
+    pub dependencies: Vec<ScenarioWorldDependency>,
+    /// Whether the crawl stopped because of the `max_depth` or the `max_addresses` option, some dependencies might then be missing.
+    pub limit_reached: bool
+}
+
+impl ScenarioWorldDependencies {
+    pub fn addresses(&self) -> Vec<Address> {
+        self.dependencies
+            .iter()
+            .map(|dependency| dependency.address.clone())
+            .collect()
+    }
+}
+
+/// Returns the smart contract addresses found in the storage keys and values, sorted and without duplicates.
+///
+/// An address is looked for at any position, so the addresses inside encoded structs or mapper keys are found too.
+/// The returned addresses are only shaped as smart contract ones, they might not be deployed.
+pub(crate) fn find_contract_addresses(keys: &AddressKeys) -> Vec<[u8; 32]> {
+    let mut result = vec![];
+
+    for (key, value) in keys.data.pairs.iter() {
+        for encoded in [key, value] {
+            let Ok(bytes) = hex::decode(encoded) else { continue };
+            result.extend(find_contract_addresses_in_bytes(&bytes));
+        }
+    }
+
+    result.sort();
+    result.dedup();
+
+    result
+}
+
+pub(crate) fn is_deployed_contract(account: &AccountInfos) -> bool {
+    account.data.account.code.as_ref().is_some_and(|code| !code.is_empty())
+}
+
+/// Returns the smart contract addresses found at any position in the bytes, in the order they appear.
+pub(crate) fn find_contract_addresses_in_bytes(bytes: &[u8]) -> Vec<[u8; 32]> {
+    bytes.windows(32)
+        .filter(|window| is_contract_address(window))
+        .map(|window| window.try_into().unwrap())
+        .collect()
+}
+
+pub(crate) fn is_contract_address(bytes: &[u8]) -> bool {
+    let (prefix, rest) = bytes.split_at(CONTRACT_ADDRESS_ZEROS_PREFIX_LENGTH);
+
+    prefix.iter().all(|byte| *byte == 0)
+        && rest.starts_with(&CONTRACT_ADDRESS_VM_TYPE)
+        && rest[CONTRACT_ADDRESS_VM_TYPE.len()..].iter().any(|byte| *byte != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use multiversx_sc_snippets::hex;
+    use novax::Address;
+    use crate::gateway::keys::{AddressKeys, AddressKeysData};
+    use crate::world::dependencies::find_contract_addresses;
+
+    const CONTRACT: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+    const OTHER_CONTRACT: &str = "erd1qqqqqqqqqqqqqpgqp699jngundfqw07d8jzkepucvpzush6k3wvqyc44rx";
+    const USER: &str = "erd1devnet6uy8xjusvusfy3q83qadfhwrtty5fwa8ceh9cl60q2p6ysra7aaa";
+
+    fn get_keys(pairs: Vec<(Vec<u8>, Vec<u8>)>) -> AddressKeys {
+        AddressKeys {
+            data: AddressKeysData {
+                pairs: pairs.into_iter()
+                    .map(|(key, value)| (hex::encode(key), hex::encode(value)))
+                    .collect::<HashMap<_, _>>(),
+            },
+            error: "".to_string(),
+            code: "successful".to_string(),
+        }
+    }
+
+    fn get_address_bytes(bech32: &str) -> [u8; 32] {
+        Address::from_bech32_string(bech32).unwrap().to_bytes()
+    }
+
+    #[test]
+    fn test_find_contract_addresses_no_address() {
+        let keys = get_keys(vec![(b"sum".to_vec(), vec![5])]);
+
+        assert!(find_contract_addresses(&keys).is_empty());
+    }
+
+    #[test]
+    fn test_find_contract_addresses_single_value() {
+        let keys = get_keys(vec![(b"router".to_vec(), get_address_bytes(CONTRACT).to_vec())]);
+
+        assert_eq!(find_contract_addresses(&keys), vec![get_address_bytes(CONTRACT)]);
+    }
+
+    #[test]
+    fn test_find_contract_addresses_ignores_user_addresses() {
+        let keys = get_keys(vec![(b"owner".to_vec(), get_address_bytes(USER).to_vec())]);
+
+        assert!(find_contract_addresses(&keys).is_empty());
+    }
+
+    #[test]
+    fn test_find_contract_addresses_ignores_zero_address() {
+        let keys = get_keys(vec![(b"owner".to_vec(), [0u8; 32].to_vec())]);
+
+        assert!(find_contract_addresses(&keys).is_empty());
+    }
+
+    #[test]
+    fn test_find_contract_addresses_in_encoded_struct_and_key() {
+        let mut value = vec![0, 0, 0, 4, 1, 2, 3, 4];
+        value.extend(get_address_bytes(CONTRACT));
+        value.extend([0, 0, 0, 1, 9]);
+
+        let mut key = b"pairs".to_vec();
+        key.extend(get_address_bytes(OTHER_CONTRACT));
+
+        let keys = get_keys(vec![
+            (key, vec![1]),
+            (b"config".to_vec(), value),
+            (b"router".to_vec(), get_address_bytes(CONTRACT).to_vec())
+        ]);
+
+        let mut expected = vec![get_address_bytes(CONTRACT), get_address_bytes(OTHER_CONTRACT)];
+        expected.sort();
+
+        assert_eq!(find_contract_addresses(&keys), expected);
+    }
+}
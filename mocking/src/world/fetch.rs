@@ -0,0 +1,195 @@
+use std::future::Future;
+use std::time::Duration;
+use futures::{stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use novax_token::error::token::TokenError;
+use crate::errors::mocking::NovaXMockingError;
+
+/// How `ScenarioWorldInfos` requests the gateway while cloning a world.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ScenarioWorldFetchOptions {
+    /// The maximum number of requests sent to the gateway at the same time.
+    pub max_concurrent_requests: usize,
+    /// The number of times a request which couldn't reach the gateway is sent again before giving up.
+    pub max_retries: u32,
+    /// The delay before the first retry of a request, doubled before each of the next ones.
+    pub retry_delay: Duration,
+    /// The number of addresses fetched between two saves of `ScenarioWorldInfos::fetch_into_file`.
+    pub batch_size: usize
+}
+
+impl Default for ScenarioWorldFetchOptions {
+    fn default() -> Self {
+        ScenarioWorldFetchOptions {
+            max_concurrent_requests: 8,
+            max_retries: 3,
+            retry_delay: Duration::from_millis(500),
+            batch_size: 50,
+        }
+    }
+}
+
+/// Runs `fetch` for every item, with at most `max_concurrent_requests` of them running at the same time.
+///
+/// The results are in the same order as the items. The first error stops the remaining fetches and is returned.
+pub(crate) async fn fetch_concurrently<'a, Item, Output, FetchFunction, FetchFuture>(
+    items: &'a [Item],
+    options: &ScenarioWorldFetchOptions,
+    fetch: FetchFunction
+) -> Result<Vec<Output>, NovaXMockingError>
+where
+    FetchFunction: Fn(&'a Item) -> FetchFuture,
+    FetchFuture: Future<Output = Result<Output, NovaXMockingError>>
+{
+    stream::iter(items)
+        .map(fetch)
+        .buffered(options.max_concurrent_requests.max(1))
+        .try_collect()
+        .await
+}
+
+/// Runs `fetch` until it succeeds, at most `max_retries` times after the first failure, waiting longer and longer between two attempts.
+///
+/// Only the failures to reach the gateway and the error statuses, e.g. 429 Too Many Requests, are retried,
+/// a response which can't be parsed would be the same the next time and is returned right away.
+/// Returns the error of the last attempt if none succeeded.
+pub(crate) async fn fetch_with_retries<Output, FetchFunction, FetchFuture>(
+    options: &ScenarioWorldFetchOptions,
+    fetch: FetchFunction
+) -> Result<Output, NovaXMockingError>
+where
+    FetchFunction: Fn() -> FetchFuture,
+    FetchFuture: Future<Output = Result<Output, NovaXMockingError>>
+{
+    let mut delay = options.retry_delay;
+    let mut retries = 0;
+
+    loop {
+        match fetch().await {
+            Ok(result) => return Ok(result),
+            Err(error) if retries >= options.max_retries || !is_fetch_failure(&error) => return Err(error),
+            Err(_) => {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                retries += 1;
+            }
+        }
+    }
+}
+
+/// Whether the error tells that the gateway couldn't be reached or answered with an error status, which might not happen again,
+/// unlike a response which can't be parsed.
+///
+/// The token errors don't tell these cases apart when fetching a token's properties, they are considered as fetch failures.
+fn is_fetch_failure(error: &NovaXMockingError) -> bool {
+    matches!(
+        error,
+        NovaXMockingError::UnableToFetchAddressKeys
+            | NovaXMockingError::UnableToFetchAddressRoles
+            | NovaXMockingError::UnableToFetchAccountInfos
+            | NovaXMockingError::NovaXTokenError(TokenError::UnknownErrorWhileGettingEsdtInfosOfAddress { .. })
+            | NovaXMockingError::NovaXTokenError(TokenError::UnknownErrorForToken { .. })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use crate::errors::mocking::NovaXMockingError;
+    use crate::world::fetch::{fetch_concurrently, fetch_with_retries, ScenarioWorldFetchOptions};
+
+    fn get_options(max_concurrent_requests: usize, max_retries: u32) -> ScenarioWorldFetchOptions {
+        ScenarioWorldFetchOptions {
+            max_concurrent_requests,
+            max_retries,
+            retry_delay: Duration::from_millis(1),
+            batch_size: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_retries_succeeds_after_failures() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = fetch_with_retries(&get_options(1, 3), || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(NovaXMockingError::UnableToFetchAddressKeys)
+            } else {
+                Ok(10)
+            }
+        }).await;
+
+        assert_eq!(result, Ok(10));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_retries_gives_up() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = fetch_with_retries(&get_options(1, 2), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+
+            Err::<u8, _>(NovaXMockingError::UnableToFetchAddressKeys)
+        }).await;
+
+        assert_eq!(result, Err(NovaXMockingError::UnableToFetchAddressKeys));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_retries_doesnt_retry_parse_errors() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = fetch_with_retries(&get_options(1, 3), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+
+            Err::<u8, _>(NovaXMockingError::UnableToParseAccountInfos)
+        }).await;
+
+        assert_eq!(result, Err(NovaXMockingError::UnableToParseAccountInfos));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_concurrently_keeps_order_and_bounds_concurrency() {
+        let running = AtomicUsize::new(0);
+        let max_running = AtomicUsize::new(0);
+        let items: Vec<u64> = (0..20).collect();
+
+        let result = fetch_concurrently(&items, &get_options(3, 0), |item| {
+            let running = &running;
+            let max_running = &max_running;
+
+            async move {
+                let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(current, Ordering::SeqCst);
+
+                tokio::time::sleep(Duration::from_millis(20 - item)).await;
+
+                running.fetch_sub(1, Ordering::SeqCst);
+
+                Ok(*item * 2)
+            }
+        }).await;
+
+        assert_eq!(result, Ok(items.iter().map(|item| item * 2).collect()));
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_concurrently_returns_first_error() {
+        let items: Vec<u8> = (0..5).collect();
+
+        let result = fetch_concurrently(&items, &get_options(2, 0), |item| async move {
+            if *item == 3 {
+                Err(NovaXMockingError::UnableToFetchAddressRoles)
+            } else {
+                Ok(*item)
+            }
+        }).await;
+
+        assert_eq!(result, Err(NovaXMockingError::UnableToFetchAddressRoles));
+    }
+}
@@ -1,26 +1,30 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{HashMap, HashSet};
 use std::fs::OpenOptions;
-use tokio::join;
 use std::hash::Hash;
 use std::path::Path;
 use num_bigint::BigUint;
-use futures::future::join_all;
-use multiversx_sc_snippets::hex;
-use multiversx_sc_snippets::multiversx_sc_scenario::scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext};
-use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::{BytesKey, BytesValue};
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_format::interpret_trait::IntoRaw;
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_format::serde_raw::{ScenarioRaw, ValueSubTree};
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::{BytesValue, Scenario, Step};
 use novax::errors::NovaXError;
 use serde::{Deserialize, Serialize};
 use novax::account::AccountInfos;
 use novax::Address;
 use novax::caching::CachingNone;
-use novax_token::account::balance::{FetchAllTokens, TokenInfos};
-use novax_token::error::token::TokenError;
+use novax_request::gateway::client::GatewayClient;
+use novax_token::account::balance::FetchAllTokens;
+use novax_token::properties::fetch::FetchTokenProperties;
+use novax_token::properties::model::TokenProperties;
 use crate::errors::mocking::NovaXMockingError;
+use crate::gateway::account::fetch_account_infos;
 use crate::gateway::keys::AddressKeys;
-use crate::{Account, ScenarioWorld, SetStateStep};
-use crate::world::serde::ScenarioWorldInfosJson;
-
-type BalanceAsyncResults = Vec<Result<([u8;32], Vec<TokenInfos>), TokenError>>;
+use crate::gateway::roles::AddressRoles;
+use crate::ScenarioWorld;
+use crate::world::dependencies::{find_contract_addresses, is_deployed_contract, ScenarioWorldDependencies, ScenarioWorldDependenciesOptions, ScenarioWorldDependency};
+use crate::world::fetch::{fetch_concurrently, fetch_with_retries, ScenarioWorldFetchOptions};
+use crate::world::refresh::{get_account_changes, is_account_outdated, ScenarioWorldInfosRefreshSummary};
+use crate::world::scenario::{get_infos_from_scenario, get_set_state_step};
+use crate::world::serde::{sort_json_value, ScenarioWorldInfosJson};
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub struct ScenarioWorldInfosEsdtTokenAmount {
@@ -30,11 +34,21 @@ pub struct ScenarioWorldInfosEsdtTokenAmount {
     pub opt_attributes: Option<Vec<u8>>
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ScenarioWorldInfosEsdtRoles {
+    pub token_identifier: String,
+    pub roles: Vec<String>
+}
+
+#[derive(PartialEq, Clone, Default, Debug)]
 pub struct ScenarioWorldInfos {
     pub address_keys: HashMap<[u8; 32], AddressKeys>,
     pub address_balances: HashMap<[u8; 32], Vec<ScenarioWorldInfosEsdtTokenAmount>>,
-    pub address_infos: Vec<AccountInfos>
+    pub address_infos: Vec<AccountInfos>,
+    pub address_esdt_roles: HashMap<[u8; 32], Vec<ScenarioWorldInfosEsdtRoles>>,
+    /// The properties of the tokens, indexed by their identifier, filled by `fetch_tokens_properties` only.
+    /// They are informational: the scenario engine doesn't store token properties such as the decimals, they are therefore not applied by `into_world`.
+    pub token_properties: HashMap<String, TokenProperties>
 }
 
 impl From<ScenarioWorldInfosJson> for ScenarioWorldInfos {
@@ -43,16 +57,15 @@ impl From<ScenarioWorldInfosJson> for ScenarioWorldInfos {
             address_keys: convert_hashmap_address_keys_to_bytes(value.address_keys),
             address_balances: convert_hashmap_address_keys_to_bytes(value.address_balances),
             address_infos: value.address_infos,
+            address_esdt_roles: convert_hashmap_address_keys_to_bytes(value.address_esdt_roles),
+            token_properties: value.token_properties,
         }
     }
 }
 
 impl ScenarioWorldInfos {
     pub fn from_file<P: AsRef<Path>>(file_path: P) -> Result<ScenarioWorldInfos, NovaXMockingError> {
-        let reader = OpenOptions::new()
-            .read(true)
-            .open(file_path)
-            .unwrap();
+        let Ok(reader) = OpenOptions::new().read(true).open(file_path) else { return Err(NovaXMockingError::UnableToOpenInfosFile) };
 
         let Ok(result) = serde_json::from_reader::<_, ScenarioWorldInfosJson>(reader) else { return Err(NovaXMockingError::UnableToReadInfosFromFile) };
 
@@ -78,194 +91,501 @@ impl ScenarioWorldInfos {
 
         overwrite_hashmap(&mut self.address_keys, other.address_keys);
         overwrite_hashmap(&mut self.address_balances, other.address_balances);
+        overwrite_hashmap(&mut self.address_esdt_roles, other.address_esdt_roles);
+        overwrite_hashmap(&mut self.token_properties, other.token_properties);
     }
 
-    pub async fn fetch(
-        gateway_url: &str,
+    /// Fetches the accounts, the storages and the ESDT balances of the addresses, using the default `ScenarioWorldFetchOptions`.
+    pub async fn fetch<Client>(
+        gateway_client: &Client,
         addresses: &[Address]
-    ) -> Result<ScenarioWorldInfos, NovaXMockingError> {
-        let (
-            accounts,
-            keys,
-            balances
-        ) = join!(
-            get_addresses_infos(gateway_url, addresses),
-            get_addresses_keys(gateway_url, addresses),
-            get_addresses_balances(gateway_url, addresses)
-        );
-
-        let infos = ScenarioWorldInfos {
-            address_keys: keys?,
-            address_balances: balances?,
-            address_infos: accounts?,
+    ) -> Result<ScenarioWorldInfos, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        ScenarioWorldInfos::fetch_with_options(gateway_client, addresses, &ScenarioWorldFetchOptions::default()).await
+    }
+
+    /// Same as `fetch`, sending at most `options.max_concurrent_requests` requests at the same time and retrying the failed ones.
+    pub async fn fetch_with_options<Client>(
+        gateway_client: &Client,
+        addresses: &[Address],
+        options: &ScenarioWorldFetchOptions
+    ) -> Result<ScenarioWorldInfos, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        let fetched_addresses = fetch_concurrently(addresses, options, |address| {
+            fetch_address(gateway_client, address, options)
+        }).await?;
+
+        let mut infos = ScenarioWorldInfos::default();
+
+        for (bytes, account_infos, keys, balances) in fetched_addresses {
+            infos.address_infos.push(account_infos);
+            infos.address_keys.insert(bytes, keys);
+            infos.address_balances.insert(bytes, balances);
+        }
+
+        Ok(infos)
+    }
+
+    /// Fetches the addresses as `fetch_with_options` does, saving the infos into the file every `options.batch_size` addresses.
+    ///
+    /// If the file already exists, its infos are loaded and the addresses it fully contains aren't fetched again.
+    /// A clone interrupted by an error or by the user is therefore resumed by calling this function again with the same file.
+    ///
+    /// Returns all the infos of the file, including the ones saved by previous calls.
+    pub async fn fetch_into_file<Client, P>(
+        gateway_client: &Client,
+        addresses: &[Address],
+        file_path: P,
+        options: &ScenarioWorldFetchOptions
+    ) -> Result<ScenarioWorldInfos, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized,
+        P: AsRef<Path>
+    {
+        let file_path = file_path.as_ref();
+
+        let mut infos = if file_path.exists() {
+            ScenarioWorldInfos::from_file(file_path)?
+        } else {
+            ScenarioWorldInfos::default()
         };
 
+        let remaining_addresses: Vec<Address> = addresses
+            .iter()
+            .filter(|address| !infos.contains_fetched_address(address))
+            .cloned()
+            .collect();
+
+        for batch in remaining_addresses.chunks(options.batch_size.max(1)) {
+            infos.overwrite(ScenarioWorldInfos::fetch_with_options(gateway_client, batch, options).await?);
+            infos.clone().save_into_file(file_path)?;
+        }
+
         Ok(infos)
     }
 
-    pub fn into_world<RegisterFunction>(self, register: RegisterFunction) -> ScenarioWorld
+    /// Fetches the ESDT roles of the addresses of these infos.
+    ///
+    /// The roles are applied by `into_world`, so contracts checking them, for example before minting, behave as on the network.
+    pub async fn fetch_esdt_system_state<Client>(&mut self, gateway_client: &Client) -> Result<(), NovaXMockingError>
     where
-        RegisterFunction: Fn([u8; 32], &str, &mut ScenarioWorld)
+        Client: GatewayClient + ?Sized
     {
-        let mut world = ScenarioWorld::new();
+        self.fetch_esdt_system_state_with_options(gateway_client, &ScenarioWorldFetchOptions::default()).await
+    }
 
-        let all_addresses: Vec<[u8; 32]> = self.address_keys.keys().chain(self.address_balances.keys()).copied().collect();
+    /// Same as `fetch_esdt_system_state`, requesting the gateway as `fetch_with_options` does.
+    pub async fn fetch_esdt_system_state_with_options<Client>(&mut self, gateway_client: &Client, options: &ScenarioWorldFetchOptions) -> Result<(), NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        let mut addresses: Vec<Address> = self.address_infos
+            .iter()
+            .map(|e| Address::from_bech32_string(&e.data.account.address))
+            .collect::<Result<_, _>>()
+            .map_err(NovaXError::from)?;
 
-        let mut set_state_accounts_step = SetStateStep::new();
+        addresses.extend(self.address_balances.keys().map(|e| Address::from_bytes(*e)));
+        addresses.sort_by_key(|e| e.to_bytes());
+        addresses.dedup();
 
-        let mut registered_codes = vec![];
+        let roles = get_addresses_roles(gateway_client, &addresses, options).await?;
+        overwrite_hashmap(&mut self.address_esdt_roles, roles);
 
-        for address_bytes in all_addresses {
-            let encoded_contract_address_expr = format!("0x{}", hex::encode(address_bytes));
+        Ok(())
+    }
 
-            let mut account = Account::new();
+    /// Fetches the properties of the tokens held by the addresses of these infos or on which they have roles, such as their decimals.
+    ///
+    /// The properties are informational: the scenario engine doesn't support them, so `into_world` doesn't apply them.
+    /// They are therefore only fetched when calling this function, for tools needing them alongside the cloned world.
+    pub async fn fetch_tokens_properties<Client>(&mut self, gateway_client: &Client) -> Result<(), NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        self.fetch_tokens_properties_with_options(gateway_client, &ScenarioWorldFetchOptions::default()).await
+    }
 
-            let balances = self.address_balances
-                .iter()
-                .find(|e| e.0 == &address_bytes)
-                .map(|e| e.1);
-
-            if let Some(balances) = balances {
-                for balance in balances {
-                    let token_identifier = balance.token_identifier.as_bytes().to_vec();
-                    if balance.nonce == 0 {
-                        account = account.esdt_balance(token_identifier, &balance.amount)
-                    } else {
-                        account = account.esdt_nft_balance(token_identifier, balance.nonce, &balance.amount, balance.opt_attributes.clone());
+    /// Same as `fetch_tokens_properties`, requesting the gateway as `fetch_with_options` does.
+    pub async fn fetch_tokens_properties_with_options<Client>(&mut self, gateway_client: &Client, options: &ScenarioWorldFetchOptions) -> Result<(), NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        let mut token_identifiers: Vec<String> = self.address_balances
+            .values()
+            .flatten()
+            .map(|e| e.token_identifier.clone())
+            .chain(self.address_esdt_roles.values().flatten().map(|e| e.token_identifier.clone()))
+            .collect();
+
+        token_identifiers.sort();
+        token_identifiers.dedup();
+
+        let properties = get_tokens_properties(gateway_client, &token_identifiers, options).await?;
+        overwrite_hashmap(&mut self.token_properties, properties);
+
+        Ok(())
+    }
+
+    /// Same as `fetch`, also fetching the smart contracts referenced in the storage of the fetched addresses, and their own dependencies.
+    ///
+    /// The storage keys and values are scanned for smart contract addresses, an address being kept only if a contract is deployed there.
+    /// The crawl stops at the limits given in `options`, `ScenarioWorldDependencies::limit_reached` then tells that some dependencies might be missing.
+    pub async fn fetch_with_dependencies<Client>(
+        gateway_client: &Client,
+        addresses: &[Address],
+        options: ScenarioWorldDependenciesOptions
+    ) -> Result<(ScenarioWorldInfos, ScenarioWorldDependencies), NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        ScenarioWorldInfos::fetch_with_dependencies_with_options(gateway_client, addresses, options, &ScenarioWorldFetchOptions::default()).await
+    }
+
+    /// Same as `fetch_with_dependencies`, requesting the gateway as `fetch_with_options` does.
+    pub async fn fetch_with_dependencies_with_options<Client>(
+        gateway_client: &Client,
+        addresses: &[Address],
+        options: ScenarioWorldDependenciesOptions,
+        fetch_options: &ScenarioWorldFetchOptions
+    ) -> Result<(ScenarioWorldInfos, ScenarioWorldDependencies), NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        let mut infos = ScenarioWorldInfos::fetch_with_options(gateway_client, addresses, fetch_options).await?;
+        let mut dependencies = ScenarioWorldDependencies::default();
+        let mut known_addresses: HashSet<[u8; 32]> = addresses.iter().map(|address| address.to_bytes()).collect();
+        let mut crawled_addresses = addresses.to_vec();
+
+        for depth in 1.. {
+            let mut candidates: Vec<(Address, Address)> = vec![];
+            for crawled_address in &crawled_addresses {
+                let Some(keys) = infos.address_keys.get(&crawled_address.to_bytes()) else { continue };
+
+                for candidate in find_contract_addresses(keys) {
+                    if known_addresses.insert(candidate) {
+                        candidates.push((Address::from_bytes(candidate), crawled_address.clone()));
                     }
                 }
             }
 
-            let keys = self.address_keys
-                .iter()
-                .find(|e| e.0 == &address_bytes)
-                .map(|e| e.1);
-
-            if let Some(keys) = keys {
-                let mut keys_map = BTreeMap::new();
-                for key in &keys.data.pairs {
-                    let key_expr = format!("0x{}", key.0);
-                    let value_expr = format!("0x{}", key.1);
-                    let context = InterpreterContext::default();
-                    keys_map.insert(BytesKey::interpret_from(key_expr, &context), BytesValue::interpret_from(value_expr, &context));
-                }
+            if candidates.is_empty() {
+                break
+            }
 
-                account.storage = keys_map;
+            if depth > options.max_depth {
+                dependencies.limit_reached = true;
+                break
             }
 
-            let account_infos = self.address_infos
-                .iter()
-                .find(|e| Address::from_bech32_string(&e.data.account.address).unwrap().to_bytes() == address_bytes);
-
-            if let Some(account_infos) = account_infos {
-                if let Some(code) = &account_infos.data.account.code {
-                    if let Some(owner) = &account_infos.data.account.owner_address {
-                        if !owner.is_empty() {
-                            let code_expr = format!("0x{code}");
-
-                            if !registered_codes.contains(code) {
-                                register(address_bytes, &code_expr, &mut world);
-                                registered_codes.push(code.clone());
-                            }
-
-                            let owner_address_expr = format!("bech32:{owner}");
-                            account = account.code(&*code_expr.clone());
-                            account = account.owner(&*owner_address_expr);
-                        }
+            // The candidates are fetched by chunks of the remaining capacity, so a storage referencing many contracts doesn't fetch more accounts than needed
+            let mut new_dependencies = vec![];
+            let mut remaining_candidates = candidates.as_slice();
+            while !remaining_candidates.is_empty() {
+                let remaining_capacity = options.max_addresses.saturating_sub(dependencies.dependencies.len() + new_dependencies.len());
+                if remaining_capacity == 0 {
+                    dependencies.limit_reached = true;
+                    break
+                }
+
+                let (chunk, rest) = remaining_candidates.split_at(remaining_capacity.min(remaining_candidates.len()));
+                remaining_candidates = rest;
+
+                let chunk_addresses: Vec<Address> = chunk.iter().map(|e| e.0.clone()).collect();
+                let chunk_infos = get_addresses_infos(gateway_client, &chunk_addresses, fetch_options).await?;
+
+                for ((address, found_in), account_infos) in chunk.iter().zip(chunk_infos) {
+                    if is_deployed_contract(&account_infos) {
+                        new_dependencies.push(ScenarioWorldDependency { address: address.clone(), found_in: found_in.clone(), depth });
                     }
                 }
             }
 
-            set_state_accounts_step = set_state_accounts_step.put_account(&*encoded_contract_address_expr, account);
+            crawled_addresses = new_dependencies.iter().map(|e| e.address.clone()).collect();
+
+            if !crawled_addresses.is_empty() {
+                infos.overwrite(ScenarioWorldInfos::fetch_with_options(gateway_client, &crawled_addresses, fetch_options).await?);
+            }
+
+            dependencies.dependencies.extend(new_dependencies);
+
+            if crawled_addresses.is_empty() || dependencies.limit_reached {
+                break
+            }
         }
 
+        Ok((infos, dependencies))
+    }
+
+    /// Returns a world holding the accounts of the infos, `register` being called once for each distinct contract code.
+    ///
+    /// Fails if an address of `address_infos` isn't a valid bech32 address.
+    pub fn into_world<RegisterFunction>(self, register: RegisterFunction) -> Result<ScenarioWorld, NovaXMockingError>
+    where
+        RegisterFunction: Fn([u8; 32], &str, &mut ScenarioWorld)
+    {
+        let mut world = ScenarioWorld::new();
+
+        let mut registered_codes = vec![];
+
+        let set_state_accounts_step = get_set_state_step(&self, |address_bytes, code| {
+            let code_expr = format!("0x{code}");
+
+            if !registered_codes.iter().any(|registered_code| registered_code == code) {
+                register(address_bytes, &code_expr, &mut world);
+                registered_codes.push(code.to_string());
+            }
+
+            BytesValue::from(&*code_expr)
+        })?;
+
         world.set_state_step(set_state_accounts_step);
 
-        world
+        Ok(world)
+    }
+
+    /// Fetches again the accounts that changed since these infos have been fetched, leaving the other ones untouched.
+    ///
+    /// An account is fetched again when its storage root hash, its nonce or its EGLD balance changed.
+    /// The accounts saved without root hash, for example by an older version, are always fetched again.
+    /// The ESDT roles and the token properties aren't refreshed, `fetch_esdt_system_state` and `fetch_tokens_properties` can be called again for this purpose.
+    ///
+    /// Returns a summary of the changes, which stays empty if the world didn't change.
+    pub async fn refresh<Client>(&mut self, gateway_client: &Client) -> Result<ScenarioWorldInfosRefreshSummary, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        self.refresh_with_options(gateway_client, &ScenarioWorldFetchOptions::default()).await
+    }
+
+    /// Same as `refresh`, requesting the gateway as `fetch_with_options` does.
+    pub async fn refresh_with_options<Client>(&mut self, gateway_client: &Client, options: &ScenarioWorldFetchOptions) -> Result<ScenarioWorldInfosRefreshSummary, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized
+    {
+        let mut addresses: Vec<Address> = self.address_infos
+            .iter()
+            .map(|e| Address::from_bech32_string(&e.data.account.address))
+            .collect::<Result<_, _>>()
+            .map_err(NovaXError::from)?;
+
+        addresses.extend(self.address_keys.keys().chain(self.address_balances.keys()).map(|e| Address::from_bytes(*e)));
+        addresses.sort_by_key(|e| e.to_bytes());
+        addresses.dedup();
+
+        let current_accounts = get_addresses_infos(gateway_client, &addresses, options).await?;
+
+        let mut summary = ScenarioWorldInfosRefreshSummary::default();
+        let mut outdated_addresses = vec![];
+
+        for (address, current_account) in addresses.into_iter().zip(current_accounts) {
+            let saved_account = self.address_infos
+                .iter()
+                .find(|e| e.data.account.address == current_account.data.account.address);
+
+            if is_account_outdated(saved_account, &current_account) {
+                outdated_addresses.push(address);
+            } else {
+                summary.unchanged.push(address);
+            }
+        }
+
+        if outdated_addresses.is_empty() {
+            return Ok(summary)
+        }
+
+        let fetched = ScenarioWorldInfos::fetch_with_options(gateway_client, &outdated_addresses, options).await?;
+
+        for address in outdated_addresses {
+            match get_account_changes(&address, self, &fetched) {
+                Some(changes) => summary.changed.push(changes),
+                None => summary.unchanged.push(address)
+            }
+        }
+
+        summary.unchanged.sort_by_key(|e| e.to_bytes());
+        self.overwrite(fetched);
+
+        Ok(summary)
     }
 
-    pub fn save_into_file<P: AsRef<Path>>(self, file_path: P) {
-        let writer = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(file_path)
-            .unwrap();
+    /// Refreshes the infos saved in a file, see `refresh`, and saves them back in the same file.
+    pub async fn refresh_file<Client, P>(file_path: P, gateway_client: &Client) -> Result<ScenarioWorldInfosRefreshSummary, NovaXMockingError>
+    where
+        Client: GatewayClient + ?Sized,
+        P: AsRef<Path>
+    {
+        let mut infos = ScenarioWorldInfos::from_file(&file_path)?;
+        let summary = infos.refresh(gateway_client).await?;
+
+        infos.save_into_file(file_path)?;
 
-        serde_json::to_writer(writer, &ScenarioWorldInfosJson::from(self)).unwrap();
+        Ok(summary)
     }
-}
 
-async fn get_addresses_infos(gateway_url: &str, addresses: &[Address]) -> Result<Vec<AccountInfos>, NovaXMockingError> {
-    let mut accounts_futures = vec![];
-    for address in addresses {
-        accounts_futures.push(async {
-            AccountInfos::from_address(gateway_url, address).await
-        })
-    };
+    pub fn save_into_file<P: AsRef<Path>>(self, file_path: P) -> Result<(), NovaXMockingError> {
+        let Ok(writer) = OpenOptions::new().write(true).create(true).truncate(true).open(file_path) else {
+            return Err(NovaXMockingError::UnableToWriteInfosFile)
+        };
 
-    let accounts: Vec<Result<AccountInfos, NovaXError>> = join_all(accounts_futures).await;
-    let mut results = vec![];
+        let Ok(json) = serde_json::to_value(ScenarioWorldInfosJson::from(self).sorted()) else { return Err(NovaXMockingError::UnableToWriteInfosFile) };
 
-    for account in accounts {
-        results.push(account?)
+        serde_json::to_writer_pretty(writer, &sort_json_value(json)).map_err(|_| NovaXMockingError::UnableToWriteInfosFile)
     }
 
-    Ok(results)
+    /// Whether the account, the storage and the ESDT balances of the address have been fetched.
+    pub(crate) fn contains_fetched_address(&self, address: &Address) -> bool {
+        let bytes = address.to_bytes();
+
+        self.address_keys.contains_key(&bytes)
+            && self.address_balances.contains_key(&bytes)
+            && self.address_infos.iter().any(|e| Address::from_bech32_string(&e.data.account.address).is_ok_and(|e| &e == address))
+    }
+
+    /// Reads the infos from a scenario file, such as the `.scen.json` ones run by `sc-meta` scenario tests.
+    ///
+    /// Every account put by the `setState` steps is imported, with its nonce, EGLD balance, ESDT balances and roles, storage, code and owner.
+    /// The code paths, such as `mxsc:output/contract.mxsc.json`, are resolved relatively to the scenario file and the code is inlined in the returned infos.
+    /// The other steps are ignored.
+    pub fn from_scenario_file<P: AsRef<Path>>(file_path: P) -> Result<ScenarioWorldInfos, NovaXMockingError> {
+        let file_path = file_path.as_ref();
+
+        let Ok(content) = std::fs::read_to_string(file_path) else { return Err(NovaXMockingError::UnableToReadScenarioFile) };
+        let Ok(scenario) = serde_json::from_str::<ScenarioRaw>(&content) else { return Err(NovaXMockingError::UnableToReadScenarioFile) };
+
+        let context_dir = file_path.parent().unwrap_or(Path::new(""));
+
+        get_infos_from_scenario(scenario, context_dir)
+    }
+
+    /// Saves the infos as a scenario file made of a single `setState` step, which can be run by `sc-meta` scenario tests.
+    ///
+    /// `code_path` returns the code path of a contract from its address and its hex encoded code, for example `mxsc:output/contract.mxsc.json`.
+    /// The code is inlined as hex when it returns `None`.
+    ///
+    /// The scenario format has no room for the token properties, they are therefore not saved.
+    pub fn save_into_scenario_file<P, CodePathFunction>(&self, file_path: P, code_path: CodePathFunction) -> Result<(), NovaXMockingError>
+    where
+        P: AsRef<Path>,
+        CodePathFunction: Fn([u8; 32], &str) -> Option<String>
+    {
+        let set_state_accounts_step = get_set_state_step(self, |address_bytes, code| {
+            match code_path(address_bytes, code) {
+                Some(path) => BytesValue {
+                    value: vec![],
+                    original: ValueSubTree::Str(path),
+                },
+                None => BytesValue::from(&*format!("0x{code}"))
+            }
+        })?;
+
+        let scenario = Scenario {
+            name: None,
+            comment: None,
+            check_gas: None,
+            steps: vec![Step::SetState(set_state_accounts_step)],
+        };
+
+        let mut content = scenario.into_raw().to_json_string();
+        content.push('\n');
+
+        std::fs::write(file_path, content).map_err(|_| NovaXMockingError::UnableToWriteScenarioFile)
+    }
 }
 
-async fn get_addresses_keys(gateway_url: &str, addresses: &[Address]) -> Result<HashMap<[u8; 32], AddressKeys>, NovaXMockingError> {
-    let mut keys_futures = vec![];
-    for address in addresses {
-        keys_futures.push(async {
-            Ok((address.to_bytes(), AddressKeys::from_gateway(gateway_url, address).await?))
+pub(crate) async fn fetch_address<Client>(
+    gateway_client: &Client,
+    address: &Address,
+    options: &ScenarioWorldFetchOptions
+) -> Result<([u8; 32], AccountInfos, AddressKeys, Vec<ScenarioWorldInfosEsdtTokenAmount>), NovaXMockingError>
+where
+    Client: GatewayClient + ?Sized
+{
+    let bytes = address.to_bytes();
+    let account_infos = fetch_with_retries(options, || fetch_account_infos(gateway_client, address)).await?;
+    let keys = fetch_with_retries(options, || AddressKeys::from_gateway(gateway_client, address)).await?;
+    let balances = fetch_with_retries(options, || get_address_balances(gateway_client, address)).await?;
+
+    Ok((bytes, account_infos, keys, balances))
+}
+
+async fn get_addresses_infos<Client>(gateway_client: &Client, addresses: &[Address], options: &ScenarioWorldFetchOptions) -> Result<Vec<AccountInfos>, NovaXMockingError>
+where
+    Client: GatewayClient + ?Sized
+{
+    fetch_concurrently(addresses, options, |address| {
+        fetch_with_retries(options, move || fetch_account_infos(gateway_client, address))
+    }).await
+}
+
+async fn get_address_balances<Client>(gateway_client: &Client, address: &Address) -> Result<Vec<ScenarioWorldInfosEsdtTokenAmount>, NovaXMockingError>
+where
+    Client: GatewayClient + ?Sized
+{
+    let tokens = address.fetch_all_tokens(gateway_client, &CachingNone).await?;
+
+    let amounts = tokens.into_iter()
+        .map(|infos| ScenarioWorldInfosEsdtTokenAmount {
+            token_identifier: parse_token_identifier(&infos.token_identifier),
+            nonce: infos.nonce,
+            amount: infos.balance,
+            opt_attributes: infos.attributes
         })
-    };
+        .collect();
+
+    Ok(amounts)
+}
+
+async fn get_addresses_roles<Client>(gateway_client: &Client, addresses: &[Address], options: &ScenarioWorldFetchOptions) -> Result<HashMap<[u8; 32], Vec<ScenarioWorldInfosEsdtRoles>>, NovaXMockingError>
+where
+    Client: GatewayClient + ?Sized
+{
+    let roles = fetch_concurrently(addresses, options, |address| async move {
+        let address_roles = fetch_with_retries(options, || AddressRoles::from_gateway(gateway_client, address)).await?;
+
+        Ok((address.to_bytes(), address_roles))
+    }).await?;
 
-    let keys: Vec<Result<([u8; 32], AddressKeys), NovaXMockingError>> = join_all(keys_futures).await;
     let mut results = HashMap::new();
 
-    for key in keys {
-        let key = key?;
-        results.insert(key.0, key.1);
+    for (address_bytes, address_roles) in roles {
+        let mut esdt_roles: Vec<ScenarioWorldInfosEsdtRoles> = address_roles.data.roles
+            .into_iter()
+            .map(|(token_identifier, roles)| ScenarioWorldInfosEsdtRoles { token_identifier, roles })
+            .collect();
+
+        if esdt_roles.is_empty() {
+            continue
+        }
+
+        esdt_roles.sort_by(|a, b| a.token_identifier.cmp(&b.token_identifier));
+        results.insert(address_bytes, esdt_roles);
     }
 
     Ok(results)
 }
 
-async fn get_addresses_balances(gateway_url: &str, addresses: &[Address]) -> Result<HashMap<[u8; 32], Vec<ScenarioWorldInfosEsdtTokenAmount>>, NovaXMockingError> {
-    let mut balances_futures = vec![];
-    for address in addresses {
-        balances_futures.push(async {
-            let infos = address.fetch_all_tokens(gateway_url, &CachingNone).await?;
-
-            Ok::<([u8; 32], Vec<TokenInfos>), TokenError>((address.to_bytes(), infos))
+async fn get_tokens_properties<Client>(gateway_client: &Client, token_identifiers: &[String], options: &ScenarioWorldFetchOptions) -> Result<HashMap<String, TokenProperties>, NovaXMockingError>
+where
+    Client: GatewayClient + ?Sized
+{
+    let properties = fetch_concurrently(token_identifiers, options, |token_identifier| {
+        fetch_with_retries(options, move || async move {
+            Ok(token_identifier.fetch_token_properties(gateway_client).await?)
         })
-    };
-
-    let balances_results: BalanceAsyncResults = join_all(balances_futures).await;
-    let mut balances = vec![];
-    for balance in balances_results {
-        balances.push(balance?)
-    }
-
-    let result = balances.into_iter()
-        .map(|e| {
-            let mut amounts: Vec<ScenarioWorldInfosEsdtTokenAmount> = vec![];
-            for infos in e.1 {
-                amounts.push(ScenarioWorldInfosEsdtTokenAmount {
-                    token_identifier: parse_token_identifier(&infos.token_identifier),
-                    nonce: infos.nonce,
-                    amount: infos.balance,
-                    opt_attributes: infos.attributes
-                })
-            }
+    }).await?;
 
-            (e.0, amounts)
-        })
-        .collect();
+    let mut results = HashMap::new();
+
+    for token_properties in properties {
+        results.insert(token_properties.identifier.clone(), token_properties);
+    }
 
-    Ok(result)
+    Ok(results)
 }
 
 fn convert_hashmap_address_keys_to_bytes<T>(hashmap: HashMap<String, T>) -> HashMap<[u8; 32], T> {
@@ -308,7 +628,88 @@ fn parse_token_identifier(token_identifier: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::world::infos::parse_token_identifier;
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use num_bigint::BigUint;
+    use novax::Address;
+    use crate::errors::mocking::NovaXMockingError;
+    use crate::gateway::keys::{AddressKeys, AddressKeysData};
+    use crate::mock::request::MockClient;
+    use crate::world::dependencies::ScenarioWorldDependenciesOptions;
+    use crate::world::fetch::ScenarioWorldFetchOptions;
+    use crate::world::infos::{parse_token_identifier, ScenarioWorldInfos, ScenarioWorldInfosEsdtRoles, ScenarioWorldInfosEsdtTokenAmount};
+
+    const FIRST_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+    const SECOND_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqp699jngundfqw07d8jzkepucvpzush6k3wvqyc44rx";
+
+    fn get_fetch_options() -> ScenarioWorldFetchOptions {
+        ScenarioWorldFetchOptions {
+            max_concurrent_requests: 2,
+            max_retries: 1,
+            retry_delay: Duration::from_millis(1),
+            batch_size: 1,
+        }
+    }
+
+    fn get_infos(keys_count: u8) -> ScenarioWorldInfos {
+        let address_bytes = Address::from("erd1devnet6uy8xjusvusfy3q83qadfhwrtty5fwa8ceh9cl60q2p6ysra7aaa").to_bytes();
+
+        ScenarioWorldInfos {
+            address_keys: HashMap::from([(
+                address_bytes,
+                AddressKeys {
+                    data: AddressKeysData {
+                        pairs: (0..keys_count).map(|index| (format!("{index:02x}"), format!("{index:02x}"))).collect(),
+                    },
+                    error: "".to_string(),
+                    code: "successful".to_string(),
+                }
+            )]),
+            address_balances: HashMap::from([(
+                address_bytes,
+                vec![
+                    ScenarioWorldInfosEsdtTokenAmount {
+                        token_identifier: "WEGLD-abcdef".to_string(),
+                        nonce: 0,
+                        amount: BigUint::from(10u8),
+                        opt_attributes: None,
+                    },
+                    ScenarioWorldInfosEsdtTokenAmount {
+                        token_identifier: "NFT-abcdef".to_string(),
+                        nonce: 1,
+                        amount: BigUint::from(1u8),
+                        opt_attributes: None,
+                    },
+                ]
+            )]),
+            address_infos: vec![],
+            address_esdt_roles: HashMap::new(),
+            token_properties: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_save_into_file_is_deterministic() {
+        let first_path = std::env::temp_dir().join("novax_infos_deterministic_first.json");
+        let second_path = std::env::temp_dir().join("novax_infos_deterministic_second.json");
+
+        get_infos(50).save_into_file(&first_path).unwrap();
+
+        let mut reversed_infos = get_infos(50);
+        for balances in reversed_infos.address_balances.values_mut() {
+            balances.reverse();
+        }
+        reversed_infos.save_into_file(&second_path).unwrap();
+
+        let first = std::fs::read_to_string(&first_path).unwrap();
+        let second = std::fs::read_to_string(&second_path).unwrap();
+
+        std::fs::remove_file(&first_path).unwrap();
+        std::fs::remove_file(&second_path).unwrap();
+
+        assert_eq!(first, second);
+        assert!(first.find("\"00\"").unwrap() < first.find("\"31\"").unwrap());
+    }
 
     #[test]
     fn test_parse_token_identifier_empty_string() {
@@ -339,4 +740,151 @@ mod tests {
     fn test_parse_token_identifier_more_than_two_hyphens() {
         assert_eq!(parse_token_identifier("TEST-abcdef-123456-abcdef"), "TEST-abcdef");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_fetch_with_options() {
+        let addresses = vec![Address::from(FIRST_ADDRESS), Address::from(SECOND_ADDRESS)];
+
+        let infos = ScenarioWorldInfos::fetch_with_options(&MockClient::new(), &addresses, &get_fetch_options()).await.unwrap();
+
+        let fetched_addresses: Vec<String> = infos.address_infos.iter().map(|e| e.data.account.address.clone()).collect();
+        let first_bytes = Address::from(FIRST_ADDRESS).to_bytes();
+
+        assert_eq!(fetched_addresses, vec![FIRST_ADDRESS.to_string(), SECOND_ADDRESS.to_string()]);
+        assert_eq!(infos.address_keys[&first_bytes].data.pairs["73756d"], "05");
+        assert_eq!(infos.address_balances[&first_bytes][0].amount, BigUint::from(10u8));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_options_retries_then_fails() {
+        let client = MockClient::with_failing_address(SECOND_ADDRESS);
+        let addresses = vec![Address::from(SECOND_ADDRESS)];
+
+        let result = ScenarioWorldInfos::fetch_with_options(&client, &addresses, &get_fetch_options()).await;
+
+        assert_eq!(result, Err(NovaXMockingError::UnableToFetchAccountInfos));
+        assert_eq!(client.get_requests_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_options_retries_rate_limited_requests() {
+        let client = MockClient::new()
+            .with_rate_limited_path(&format!("/address/{SECOND_ADDRESS}"))
+            .with_rate_limited_path(&format!("/address/{SECOND_ADDRESS}/keys"));
+        let addresses = vec![Address::from(SECOND_ADDRESS)];
+
+        let infos = ScenarioWorldInfos::fetch_with_options(&client, &addresses, &get_fetch_options()).await.unwrap();
+
+        // The account and the storage are fetched twice, the ESDT balances once
+        assert_eq!(client.get_requests_count(), 5);
+        assert_eq!(infos.address_infos[0].data.account.address, SECOND_ADDRESS);
+        assert_eq!(infos.address_keys[&Address::from(SECOND_ADDRESS).to_bytes()].data.pairs["73756d"], "05");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_esdt_system_state() {
+        let client = MockClient::new();
+        let addresses = vec![Address::from(FIRST_ADDRESS)];
+
+        let mut infos = ScenarioWorldInfos::fetch_with_options(&client, &addresses, &get_fetch_options()).await.unwrap();
+        infos.fetch_esdt_system_state_with_options(&client, &get_fetch_options()).await.unwrap();
+
+        let expected_roles = vec![
+            ScenarioWorldInfosEsdtRoles {
+                token_identifier: "WEGLD-abcdef".to_string(),
+                roles: vec!["ESDTRoleLocalMint".to_string(), "ESDTRoleLocalBurn".to_string()],
+            }
+        ];
+
+        assert_eq!(infos.address_esdt_roles.len(), 1);
+        assert_eq!(infos.address_esdt_roles[&Address::from(FIRST_ADDRESS).to_bytes()], expected_roles);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_tokens_properties() {
+        let client = MockClient::new();
+        let addresses = vec![Address::from(FIRST_ADDRESS)];
+
+        let mut infos = ScenarioWorldInfos::fetch_with_options(&client, &addresses, &get_fetch_options()).await.unwrap();
+        infos.fetch_esdt_system_state_with_options(&client, &get_fetch_options()).await.unwrap();
+        infos.fetch_tokens_properties_with_options(&client, &get_fetch_options()).await.unwrap();
+
+        // WEGLD-abcdef is both held and on which the address has roles, it is fetched once
+        assert_eq!(client.get_requests_count(), 3 + 1 + 1);
+        assert_eq!(infos.token_properties.len(), 1);
+        assert_eq!(infos.token_properties["WEGLD-abcdef"].identifier, "WEGLD-abcdef");
+        assert_eq!(infos.token_properties["WEGLD-abcdef"].decimals, 18);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_into_file_resumes() {
+        let file_path = std::env::temp_dir().join("novax_infos_fetch_into_file_resumes.json");
+        let _ = std::fs::remove_file(&file_path);
+        let addresses = vec![Address::from(FIRST_ADDRESS), Address::from(SECOND_ADDRESS)];
+
+        let interrupted_result = ScenarioWorldInfos::fetch_into_file(&MockClient::with_failing_address(SECOND_ADDRESS), &addresses, &file_path, &get_fetch_options()).await;
+        let saved_infos = ScenarioWorldInfos::from_file(&file_path).unwrap();
+
+        let client = MockClient::new();
+        let infos = ScenarioWorldInfos::fetch_into_file(&client, &addresses, &file_path, &get_fetch_options()).await.unwrap();
+        let final_saved_infos = ScenarioWorldInfos::from_file(&file_path).unwrap();
+
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(interrupted_result, Err(NovaXMockingError::UnableToFetchAccountInfos));
+        assert_eq!(saved_infos.address_infos.len(), 1);
+        assert_eq!(saved_infos.address_infos[0].data.account.address, FIRST_ADDRESS);
+
+        // Only the second address is fetched: its account, its storage and its ESDT balances
+        assert_eq!(client.get_requests_count(), 3);
+        assert_eq!(infos.address_infos.len(), 2);
+        assert_eq!(infos, final_saved_infos);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_dependencies_caps_fetched_candidates() {
+        let contract_addresses: Vec<Address> = (1..=4u8)
+            .map(|index| {
+                let mut bytes = [index; 32];
+                bytes[..10].copy_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 5, 0]);
+                Address::from_bytes(bytes)
+            })
+            .collect();
+        let contract_bech32s: Vec<String> = contract_addresses.iter().map(|e| e.to_bech32_string().unwrap()).collect();
+        let referenced_contracts: String = contract_addresses.iter().map(|e| hex::encode(e.to_bytes())).collect();
+
+        let client = MockClient::new()
+            .with_pairs(FIRST_ADDRESS, &format!(r#"{{"00":"{referenced_contracts}"}}"#))
+            .with_undeployed_address(&contract_bech32s[0]);
+        let options = ScenarioWorldDependenciesOptions {
+            max_depth: 3,
+            max_addresses: 2,
+        };
+
+        let (infos, dependencies) = ScenarioWorldInfos::fetch_with_dependencies_with_options(&client, &[Address::from(FIRST_ADDRESS)], options, &get_fetch_options()).await.unwrap();
+
+        let dependencies_addresses: Vec<Address> = dependencies.dependencies.iter().map(|e| e.address.clone()).collect();
+
+        assert_eq!(dependencies_addresses, vec![contract_addresses[1].clone(), contract_addresses[2].clone()]);
+        assert!(dependencies.dependencies.iter().all(|e| e.depth == 1 && e.found_in == Address::from(FIRST_ADDRESS)));
+        assert!(dependencies.limit_reached);
+        assert_eq!(infos.address_infos.len(), 3);
+
+        // The root fetch, the accounts of the first three candidates only, then the fetch of the two dependencies
+        assert_eq!(client.get_requests_count(), 3 + 3 + 2 * 3);
+    }
+
+    #[test]
+    fn test_from_file_not_existing() {
+        let result = ScenarioWorldInfos::from_file(std::env::temp_dir().join("novax_infos_not_existing.json"));
+
+        assert_eq!(result, Err(NovaXMockingError::UnableToOpenInfosFile));
+    }
+
+    #[test]
+    fn test_save_into_file_in_missing_directory() {
+        let result = get_infos(1).save_into_file(std::env::temp_dir().join("novax_missing_directory").join("infos.json"));
+
+        assert_eq!(result, Err(NovaXMockingError::UnableToWriteInfosFile));
+    }
+}
@@ -1,2 +1,7 @@
+pub mod builder;
+pub mod dependencies;
+pub mod fetch;
 pub mod infos;
-mod serde;
\ No newline at end of file
+pub mod refresh;
+pub(crate) mod scenario;
+pub(crate) mod serde;
\ No newline at end of file
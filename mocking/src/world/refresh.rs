@@ -0,0 +1,269 @@
+use std::collections::{BTreeMap, BTreeSet};
+use serde::{Deserialize, Serialize};
+use novax::account::AccountInfos;
+use novax::Address;
+use crate::world::infos::ScenarioWorldInfos;
+
+/// The changes of an account refreshed by `ScenarioWorldInfos::refresh`.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ScenarioWorldInfosAccountChanges {
+    pub address: Address,
+    pub previous_nonce: Option<u64>,
+    pub nonce: u64,
+    pub previous_egld_balance: Option<String>,
+    pub egld_balance: String,
+    pub code_changed: bool,
+    /// The hex encoded storage keys, sorted.
+    pub added_keys: Vec<String>,
+    pub removed_keys: Vec<String>,
+    pub modified_keys: Vec<String>,
+    /// The tokens whose balance or attributes changed, as `TOKEN-abcdef` for a fungible ESDT and `TOKEN-abcdef-<nonce in hex>` otherwise.
+    pub changed_tokens: Vec<String>
+}
+
+/// What changed in a `ScenarioWorldInfos` refreshed by `ScenarioWorldInfos::refresh`.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
+pub struct ScenarioWorldInfosRefreshSummary {
+    /// The accounts whose state is the same as the saved one, sorted.
+    pub unchanged: Vec<Address>,
+    /// The changes of the other accounts, sorted by address.
+    pub changed: Vec<ScenarioWorldInfosAccountChanges>
+}
+
+impl ScenarioWorldInfosRefreshSummary {
+    pub fn has_changes(&self) -> bool {
+        !self.changed.is_empty()
+    }
+}
+
+/// Whether the account might have changed since it has been saved, in which case it needs to be fetched again.
+///
+/// The storage root hash changes with the storage and the ESDT balances, the nonce and the EGLD balance are compared as well.
+/// An account saved without root hash, for example by an older version, is always considered as changed.
+pub(crate) fn is_account_outdated(saved: Option<&AccountInfos>, current: &AccountInfos) -> bool {
+    let Some(saved) = saved else { return true };
+    let saved = &saved.data.account;
+    let current = &current.data.account;
+
+    saved.root_hash.is_none()
+        || saved.root_hash != current.root_hash
+        || saved.nonce != current.nonce
+        || saved.balance != current.balance
+}
+
+/// Returns the changes of the account at `address` between the `previous` and the `current` infos, or `None` if nothing changed.
+pub(crate) fn get_account_changes(address: &Address, previous: &ScenarioWorldInfos, current: &ScenarioWorldInfos) -> Option<ScenarioWorldInfosAccountChanges> {
+    let address_bytes = address.to_bytes();
+    let previous_account = find_account_infos(previous, address);
+    let current_account = find_account_infos(current, address);
+
+    let previous_keys = previous.address_keys.get(&address_bytes).map(|e| e.data.pairs.clone()).unwrap_or_default();
+    let current_keys = current.address_keys.get(&address_bytes).map(|e| e.data.pairs.clone()).unwrap_or_default();
+
+    let mut added_keys = vec![];
+    let mut modified_keys = vec![];
+    for (key, value) in current_keys.iter() {
+        match previous_keys.get(key) {
+            None => added_keys.push(key.clone()),
+            Some(previous_value) if previous_value != value => modified_keys.push(key.clone()),
+            _ => {}
+        }
+    }
+
+    let mut removed_keys: Vec<String> = previous_keys.keys()
+        .filter(|key| !current_keys.contains_key(*key))
+        .cloned()
+        .collect();
+
+    added_keys.sort();
+    modified_keys.sort();
+    removed_keys.sort();
+
+    let previous_tokens = get_tokens(previous, &address_bytes);
+    let current_tokens = get_tokens(current, &address_bytes);
+
+    let changed_tokens: Vec<String> = previous_tokens.keys()
+        .chain(current_tokens.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|token| previous_tokens.get(*token) != current_tokens.get(*token))
+        .cloned()
+        .collect();
+
+    let previous_data = previous_account.map(|e| &e.data.account);
+    let current_data = current_account.map(|e| &e.data.account);
+
+    let nonce = current_data.map(|e| e.nonce).unwrap_or_default();
+    let egld_balance = current_data.map(|e| e.balance.clone()).unwrap_or_else(|| "0".to_string());
+    let previous_nonce = previous_data.map(|e| e.nonce);
+    let previous_egld_balance = previous_data.map(|e| e.balance.clone());
+    let code_changed = previous_data.and_then(|e| e.code.as_ref()) != current_data.and_then(|e| e.code.as_ref());
+
+    let is_unchanged = previous_nonce == Some(nonce)
+        && previous_egld_balance.as_ref() == Some(&egld_balance)
+        && !code_changed
+        && added_keys.is_empty()
+        && removed_keys.is_empty()
+        && modified_keys.is_empty()
+        && changed_tokens.is_empty();
+
+    if is_unchanged {
+        return None
+    }
+
+    Some(ScenarioWorldInfosAccountChanges {
+        address: address.clone(),
+        previous_nonce,
+        nonce,
+        previous_egld_balance,
+        egld_balance,
+        code_changed,
+        added_keys,
+        removed_keys,
+        modified_keys,
+        changed_tokens,
+    })
+}
+
+fn find_account_infos<'a>(infos: &'a ScenarioWorldInfos, address: &Address) -> Option<&'a AccountInfos> {
+    infos.address_infos
+        .iter()
+        .find(|e| Address::from_bech32_string(&e.data.account.address).is_ok_and(|e| &e == address))
+}
+
+fn get_tokens(infos: &ScenarioWorldInfos, address_bytes: &[u8; 32]) -> BTreeMap<String, (String, Option<Vec<u8>>)> {
+    let Some(balances) = infos.address_balances.get(address_bytes) else { return BTreeMap::new() };
+
+    balances.iter()
+        .map(|balance| {
+            let identifier = if balance.nonce == 0 {
+                balance.token_identifier.clone()
+            } else {
+                format!("{}-{:02x}", balance.token_identifier, balance.nonce)
+            };
+
+            (identifier, (balance.amount.to_string(), balance.opt_attributes.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use num_bigint::BigUint;
+    use novax::account::{AccountInfos, AccountInfosAccountData, AccountInfosData};
+    use novax::Address;
+    use crate::gateway::keys::{AddressKeys, AddressKeysData};
+    use crate::world::infos::{ScenarioWorldInfos, ScenarioWorldInfosEsdtTokenAmount};
+    use crate::world::refresh::{get_account_changes, is_account_outdated, ScenarioWorldInfosAccountChanges};
+
+    const ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+
+    fn get_account_infos(nonce: u64, root_hash: Option<&str>) -> AccountInfos {
+        AccountInfos {
+            data: AccountInfosData {
+                account: AccountInfosAccountData {
+                    address: ADDRESS.to_string(),
+                    nonce,
+                    balance: "0".to_string(),
+                    code: Some("0061736d".to_string()),
+                    owner_address: None,
+                    root_hash: root_hash.map(|e| e.to_string()),
+                },
+            },
+        }
+    }
+
+    fn get_infos(pairs: Vec<(&str, &str)>, balances: Vec<(&str, u64, u8)>) -> ScenarioWorldInfos {
+        let address_bytes = Address::from(ADDRESS).to_bytes();
+
+        ScenarioWorldInfos {
+            address_keys: HashMap::from([(
+                address_bytes,
+                AddressKeys {
+                    data: AddressKeysData {
+                        pairs: pairs.into_iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+                    },
+                    error: "".to_string(),
+                    code: "successful".to_string(),
+                }
+            )]),
+            address_balances: HashMap::from([(
+                address_bytes,
+                balances.into_iter()
+                    .map(|(token_identifier, nonce, amount)| ScenarioWorldInfosEsdtTokenAmount {
+                        token_identifier: token_identifier.to_string(),
+                        nonce,
+                        amount: BigUint::from(amount),
+                        opt_attributes: None,
+                    })
+                    .collect()
+            )]),
+            address_infos: vec![get_account_infos(0, Some("aGFzaA=="))],
+            address_esdt_roles: HashMap::new(),
+            token_properties: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_is_account_outdated_same_root_hash() {
+        let account = get_account_infos(1, Some("aGFzaA=="));
+
+        assert!(!is_account_outdated(Some(&account), &account));
+    }
+
+    #[test]
+    fn test_is_account_outdated_other_root_hash() {
+        assert!(is_account_outdated(Some(&get_account_infos(1, Some("aGFzaA=="))), &get_account_infos(1, Some("b3RoZXI="))));
+    }
+
+    #[test]
+    fn test_is_account_outdated_other_nonce() {
+        assert!(is_account_outdated(Some(&get_account_infos(1, Some("aGFzaA=="))), &get_account_infos(2, Some("aGFzaA=="))));
+    }
+
+    #[test]
+    fn test_is_account_outdated_without_saved_root_hash() {
+        assert!(is_account_outdated(Some(&get_account_infos(1, None)), &get_account_infos(1, None)));
+    }
+
+    #[test]
+    fn test_is_account_outdated_not_saved() {
+        assert!(is_account_outdated(None, &get_account_infos(1, Some("aGFzaA=="))));
+    }
+
+    #[test]
+    fn test_get_account_changes_unchanged() {
+        let infos = get_infos(vec![("01", "02")], vec![("WEGLD-abcdef", 0, 10)]);
+
+        assert_eq!(get_account_changes(&Address::from(ADDRESS), &infos, &infos.clone()), None);
+    }
+
+    #[test]
+    fn test_get_account_changes() {
+        let previous = get_infos(
+            vec![("01", "02"), ("03", "04"), ("05", "06")],
+            vec![("WEGLD-abcdef", 0, 10), ("NFT-abcdef", 10, 1), ("USDC-abcdef", 0, 1)]
+        );
+
+        let current = get_infos(
+            vec![("01", "02"), ("03", "ff"), ("07", "08")],
+            vec![("WEGLD-abcdef", 0, 20), ("USDC-abcdef", 0, 1)]
+        );
+
+        let expected = ScenarioWorldInfosAccountChanges {
+            address: Address::from(ADDRESS),
+            previous_nonce: Some(0),
+            nonce: 0,
+            previous_egld_balance: Some("0".to_string()),
+            egld_balance: "0".to_string(),
+            code_changed: false,
+            added_keys: vec!["07".to_string()],
+            removed_keys: vec!["05".to_string()],
+            modified_keys: vec!["03".to_string()],
+            changed_tokens: vec!["NFT-abcdef-0a".to_string(), "WEGLD-abcdef".to_string()],
+        };
+
+        assert_eq!(get_account_changes(&Address::from(ADDRESS), &previous, &current), Some(expected));
+    }
+}
@@ -0,0 +1,458 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+use num_bigint::BigUint;
+use multiversx_sc_snippets::hex;
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext};
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_format::serde_raw::{ScenarioRaw, StepRaw};
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::{AddressKey, BytesKey, BytesValue, Esdt, Step};
+use novax::account::{AccountInfos, AccountInfosAccountData, AccountInfosData};
+use novax::Address;
+use novax::errors::NovaXError;
+use crate::errors::mocking::NovaXMockingError;
+use crate::gateway::keys::{AddressKeys, AddressKeysData};
+use crate::world::infos::{ScenarioWorldInfos, ScenarioWorldInfosEsdtRoles, ScenarioWorldInfosEsdtTokenAmount};
+use crate::{Account, SetStateStep};
+
+/// The prefix of the value put by the scenario interpreter in place of a code file it couldn't read.
+const MISSING_FILE_VALUE_PREFIX: &[u8] = b"MISSING:";
+
+/// Builds the `setState` step putting every account of the infos, as applied by `ScenarioWorldInfos::into_world`.
+///
+/// `get_code` returns the code value of a contract from its address and its hex encoded code, it is only called for the accounts having a code.
+pub(crate) fn get_set_state_step<GetCodeFunction>(infos: &ScenarioWorldInfos, mut get_code: GetCodeFunction) -> Result<SetStateStep, NovaXMockingError>
+where
+    GetCodeFunction: FnMut([u8; 32], &str) -> BytesValue
+{
+    let address_infos: Vec<([u8; 32], &AccountInfos)> = infos.address_infos
+        .iter()
+        .map(|e| Ok((Address::from_bech32_string(&e.data.account.address)?.to_bytes(), e)))
+        .collect::<Result<_, NovaXError>>()?;
+
+    let mut all_addresses: Vec<[u8; 32]> = infos.address_keys.keys()
+        .chain(infos.address_balances.keys())
+        .chain(infos.address_esdt_roles.keys())
+        .chain(address_infos.iter().map(|e| &e.0))
+        .copied()
+        .collect();
+
+    all_addresses.sort();
+    all_addresses.dedup();
+
+    let mut set_state_accounts_step = SetStateStep::new();
+
+    for address_bytes in all_addresses {
+        let encoded_contract_address_expr = format!("0x{}", hex::encode(address_bytes));
+
+        let account_infos = address_infos
+            .iter()
+            .find(|e| e.0 == address_bytes)
+            .map(|e| e.1);
+
+        let mut account = Account::new();
+
+        if let Some(account_infos) = account_infos {
+            account = account.nonce(account_infos.data.account.nonce);
+        }
+
+        let egld_balance = account_infos.and_then(|account_infos| BigUint::from_str(&account_infos.data.account.balance).ok());
+
+        if let Some(egld_balance) = egld_balance {
+            account = account.balance(&egld_balance);
+        }
+
+        if let Some(balances) = infos.address_balances.get(&address_bytes) {
+            for balance in balances {
+                let token_identifier_expr = format!("str:{}", balance.token_identifier);
+                if balance.nonce == 0 {
+                    account = account.esdt_balance(&*token_identifier_expr, &balance.amount)
+                } else {
+                    account = account.esdt_nft_balance(&*token_identifier_expr, balance.nonce, &balance.amount, balance.opt_attributes.clone());
+                }
+            }
+        }
+
+        if let Some(esdt_roles) = infos.address_esdt_roles.get(&address_bytes) {
+            for esdt_roles in esdt_roles {
+                let token_identifier_expr = format!("str:{}", esdt_roles.token_identifier);
+                account = account.esdt_roles(&*token_identifier_expr, esdt_roles.roles.clone());
+            }
+        }
+
+        if let Some(keys) = infos.address_keys.get(&address_bytes) {
+            let mut keys_map = BTreeMap::new();
+            for key in &keys.data.pairs {
+                let key_expr = format!("0x{}", key.0);
+                let value_expr = format!("0x{}", key.1);
+                let context = InterpreterContext::default();
+                keys_map.insert(BytesKey::interpret_from(key_expr, &context), BytesValue::interpret_from(value_expr, &context));
+            }
+
+            account.storage = keys_map;
+        }
+
+        if let Some(account_infos) = account_infos {
+            if let Some(code) = account_infos.data.account.code.as_ref().filter(|code| !code.is_empty()) {
+                account.code = Some(get_code(address_bytes, code));
+
+                if let Some(owner) = account_infos.data.account.owner_address.as_ref().filter(|owner| !owner.is_empty()) {
+                    let owner_address_expr = format!("bech32:{owner}");
+                    account = account.owner(&*owner_address_expr);
+                }
+            }
+        }
+
+        set_state_accounts_step = set_state_accounts_step.put_account(&*encoded_contract_address_expr, account);
+    }
+
+    Ok(set_state_accounts_step)
+}
+
+/// Reads the infos from the `setState` steps of a scenario, the later steps overwriting the accounts put by the earlier ones.
+///
+/// The code paths, such as `file:` or `mxsc:` ones, are resolved relatively to `context_dir` and the code is inlined in the returned infos.
+/// The other steps, as well as the new addresses and the block infos of the `setState` ones, are ignored.
+pub(crate) fn get_infos_from_scenario(scenario: ScenarioRaw, context_dir: &Path) -> Result<ScenarioWorldInfos, NovaXMockingError> {
+    let context = InterpreterContext::new()
+        .with_dir(context_dir.to_path_buf())
+        .with_allowed_missing_files();
+
+    let mut infos = ScenarioWorldInfos::default();
+
+    for raw_step in scenario.steps {
+        if !matches!(raw_step, StepRaw::SetState { .. }) {
+            continue
+        }
+
+        let Step::SetState(step) = Step::interpret_from(raw_step, &context) else { continue };
+
+        for (address_key, account) in step.accounts {
+            put_account(&mut infos, address_key, account)?;
+        }
+    }
+
+    Ok(infos)
+}
+
+fn put_account(infos: &mut ScenarioWorldInfos, address_key: AddressKey, account: Account) -> Result<(), NovaXMockingError> {
+    let address_bytes = *address_key.value.as_array();
+    let address = Address::from_bytes(address_bytes);
+    let bech32 = address.to_bech32_string().map_err(NovaXError::from)?;
+
+    infos.address_infos.retain(|e| e.data.account.address != bech32);
+    infos.address_keys.remove(&address_bytes);
+    infos.address_balances.remove(&address_bytes);
+    infos.address_esdt_roles.remove(&address_bytes);
+
+    let code = match account.code {
+        Some(code) if code.value.starts_with(MISSING_FILE_VALUE_PREFIX) => {
+            return Err(NovaXMockingError::UnableToReadScenarioCodeFile(String::from_utf8_lossy(&code.value[MISSING_FILE_VALUE_PREFIX.len()..]).to_string()))
+        },
+        Some(code) => Some(hex::encode(code.value)),
+        None => None
+    };
+
+    let owner_address = match account.owner {
+        Some(owner) => Some(Address::from_bytes(*owner.value.as_array()).to_bech32_string().map_err(NovaXError::from)?),
+        None => None
+    };
+
+    infos.address_infos.push(AccountInfos {
+        data: AccountInfosData {
+            account: AccountInfosAccountData {
+                address: bech32,
+                nonce: account.nonce.map(|nonce| nonce.value).unwrap_or_default(),
+                balance: account.balance.map(|balance| balance.value.to_string()).unwrap_or_else(|| "0".to_string()),
+                code,
+                owner_address,
+                root_hash: None,
+            },
+        },
+    });
+
+    let mut balances = vec![];
+    let mut esdt_roles = vec![];
+    for (token_identifier, esdt) in account.esdt {
+        let token_identifier = String::from_utf8_lossy(&token_identifier.value).to_string();
+
+        match esdt {
+            Esdt::Short(amount) => balances.push(ScenarioWorldInfosEsdtTokenAmount {
+                token_identifier,
+                nonce: 0,
+                amount: amount.value,
+                opt_attributes: None,
+            }),
+            Esdt::Full(esdt_object) => {
+                for instance in esdt_object.instances {
+                    balances.push(ScenarioWorldInfosEsdtTokenAmount {
+                        token_identifier: token_identifier.clone(),
+                        nonce: instance.nonce.map(|nonce| nonce.value).unwrap_or_default(),
+                        amount: instance.balance.map(|balance| balance.value).unwrap_or_default(),
+                        opt_attributes: instance.attributes.map(|attributes| attributes.value),
+                    });
+                }
+
+                if !esdt_object.roles.is_empty() {
+                    esdt_roles.push(ScenarioWorldInfosEsdtRoles {
+                        token_identifier,
+                        roles: esdt_object.roles,
+                    });
+                }
+            }
+        }
+    }
+
+    if !balances.is_empty() {
+        infos.address_balances.insert(address_bytes, balances);
+    }
+
+    if !esdt_roles.is_empty() {
+        infos.address_esdt_roles.insert(address_bytes, esdt_roles);
+    }
+
+    if !account.storage.is_empty() {
+        infos.address_keys.insert(
+            address_bytes,
+            AddressKeys {
+                data: AddressKeysData {
+                    pairs: account.storage
+                        .into_iter()
+                        .map(|(key, value)| (hex::encode(key.value), hex::encode(value.value)))
+                        .collect(),
+                },
+                error: "".to_string(),
+                code: "successful".to_string(),
+            }
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use num_bigint::BigUint;
+    use novax::account::{AccountInfos, AccountInfosAccountData, AccountInfosData};
+    use novax::Address;
+    use crate::errors::mocking::NovaXMockingError;
+    use crate::gateway::keys::{AddressKeys, AddressKeysData};
+    use crate::world::infos::{ScenarioWorldInfos, ScenarioWorldInfosEsdtRoles, ScenarioWorldInfosEsdtTokenAmount};
+
+    const CONTRACT: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+    const OWNER: &str = "erd17ytgf5z28rqmpjyvv0qzgaapufpu08fqygaxjuu79uj5uhe66vpsd230j2";
+    const USER: &str = "erd1devnet6uy8xjusvusfy3q83qadfhwrtty5fwa8ceh9cl60q2p6ysra7aaa";
+
+    fn get_account_infos(address: &str, balance: &str, code: Option<&str>, owner_address: Option<&str>) -> AccountInfos {
+        AccountInfos {
+            data: AccountInfosData {
+                account: AccountInfosAccountData {
+                    address: address.to_string(),
+                    nonce: 0,
+                    balance: balance.to_string(),
+                    code: code.map(|e| e.to_string()),
+                    owner_address: owner_address.map(|e| e.to_string()),
+                    root_hash: None,
+                },
+            },
+        }
+    }
+
+    fn get_infos() -> ScenarioWorldInfos {
+        let contract_bytes = Address::from(CONTRACT).to_bytes();
+        let user_bytes = Address::from(USER).to_bytes();
+
+        ScenarioWorldInfos {
+            address_keys: HashMap::from([(
+                contract_bytes,
+                AddressKeys {
+                    data: AddressKeysData {
+                        pairs: HashMap::from([("73756d".to_string(), "2a".to_string())]),
+                    },
+                    error: "".to_string(),
+                    code: "successful".to_string(),
+                }
+            )]),
+            address_balances: HashMap::from([(
+                user_bytes,
+                vec![
+                    ScenarioWorldInfosEsdtTokenAmount {
+                        token_identifier: "NFT-abcdef".to_string(),
+                        nonce: 6,
+                        amount: BigUint::from(1u8),
+                        opt_attributes: Some(vec![1, 2, 3]),
+                    },
+                    ScenarioWorldInfosEsdtTokenAmount {
+                        token_identifier: "WEGLD-abcdef".to_string(),
+                        nonce: 0,
+                        amount: BigUint::from(10u8),
+                        opt_attributes: None,
+                    },
+                ]
+            )]),
+            address_infos: vec![
+                get_account_infos(CONTRACT, "0", Some("0061736d"), Some(OWNER)),
+                get_account_infos(USER, "100", None, None),
+            ],
+            address_esdt_roles: HashMap::from([(
+                contract_bytes,
+                vec![ScenarioWorldInfosEsdtRoles {
+                    token_identifier: "WEGLD-abcdef".to_string(),
+                    roles: vec!["ESDTRoleLocalMint".to_string()],
+                }]
+            )]),
+            token_properties: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_scenario_file_roundtrip() {
+        let file_path = std::env::temp_dir().join("novax_scenario_roundtrip.scen.json");
+
+        let infos = get_infos();
+        infos.save_into_scenario_file(&file_path, |_, _| None).unwrap();
+
+        let result = ScenarioWorldInfos::from_scenario_file(&file_path).unwrap();
+
+        std::fs::remove_file(&file_path).unwrap();
+
+        let mut expected_infos = infos;
+        expected_infos.address_infos.sort_by_key(|e| Address::from(&e.data.account.address).to_bytes());
+
+        assert_eq!(result, expected_infos);
+    }
+
+    #[test]
+    fn test_scenario_file_roundtrip_from_fetched_infos() {
+        let file_path = std::env::temp_dir().join("novax_scenario_fetched_roundtrip.scen.json");
+
+        // Fetched infos only carry the nonces and the EGLD balances in `address_infos`
+        let mut user_infos = get_account_infos(USER, "100", None, None);
+        user_infos.data.account.nonce = 7;
+
+        let infos = ScenarioWorldInfos {
+            address_infos: vec![
+                get_account_infos(CONTRACT, "0", Some("0061736d"), Some(OWNER)),
+                user_infos,
+            ],
+            ..Default::default()
+        };
+
+        infos.save_into_scenario_file(&file_path, |_, _| None).unwrap();
+
+        let result = ScenarioWorldInfos::from_scenario_file(&file_path).unwrap();
+
+        std::fs::remove_file(&file_path).unwrap();
+
+        let result_user_infos = result.address_infos
+            .iter()
+            .find(|e| e.data.account.address == USER)
+            .unwrap();
+
+        assert_eq!(result_user_infos.data.account.nonce, 7);
+        assert_eq!(result_user_infos.data.account.balance, "100");
+    }
+
+    #[test]
+    fn test_scenario_file_invalid_address() {
+        let file_path = std::env::temp_dir().join("novax_scenario_invalid_address.scen.json");
+
+        let infos = ScenarioWorldInfos {
+            address_infos: vec![get_account_infos("erd1invalid", "0", None, None)],
+            ..Default::default()
+        };
+
+        let result = infos.save_into_scenario_file(&file_path, |_, _| None);
+
+        assert!(matches!(result, Err(NovaXMockingError::NovaXError(_))));
+        assert!(!file_path.exists());
+    }
+
+    #[test]
+    fn test_scenario_file_keeps_code_paths() {
+        let file_path = std::env::temp_dir().join("novax_scenario_code_path.scen.json");
+
+        get_infos()
+            .save_into_scenario_file(&file_path, |address, code| {
+                assert_eq!(address, Address::from(CONTRACT).to_bytes());
+                assert_eq!(code, "0061736d");
+
+                Some("mxsc:output/contract.mxsc.json".to_string())
+            })
+            .unwrap();
+
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        let result = ScenarioWorldInfos::from_scenario_file(&file_path);
+
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert!(content.contains("\"code\": \"mxsc:output/contract.mxsc.json\""));
+        assert!(content.contains("\"owner\": \"bech32:erd17ytgf5z28rqmpjyvv0qzgaapufpu08fqygaxjuu79uj5uhe66vpsd230j2\""));
+        assert!(content.contains("\"str:WEGLD-abcdef\": \"10\""));
+        assert!(matches!(result, Err(NovaXMockingError::UnableToReadScenarioCodeFile(_))));
+    }
+
+    #[test]
+    fn test_scenario_file_later_set_state_overwrites_account() {
+        let file_path = std::env::temp_dir().join("novax_scenario_overwrite.scen.json");
+
+        let content = r#"{
+            "steps": [
+                {
+                    "step": "setState",
+                    "accounts": {
+                        "address:user": {
+                            "nonce": "1",
+                            "balance": "10",
+                            "storage": {
+                                "str:key": "str:value"
+                            }
+                        }
+                    }
+                },
+                {
+                    "step": "scQuery",
+                    "tx": {
+                        "to": "sc:missing",
+                        "function": "getSum",
+                        "arguments": []
+                    }
+                },
+                {
+                    "step": "setState",
+                    "accounts": {
+                        "address:user": {
+                            "nonce": "2",
+                            "esdt": {
+                                "str:WEGLD-abcdef": "5"
+                            }
+                        }
+                    }
+                }
+            ]
+        }"#;
+
+        std::fs::write(&file_path, content).unwrap();
+
+        let result = ScenarioWorldInfos::from_scenario_file(&file_path).unwrap();
+
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(result.address_infos.len(), 1);
+        assert_eq!(result.address_infos[0].data.account.nonce, 2);
+        assert_eq!(result.address_infos[0].data.account.balance, "0");
+        assert!(result.address_keys.is_empty());
+
+        let balances = result.address_balances.values().next().unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].token_identifier, "WEGLD-abcdef");
+        assert_eq!(balances[0].amount, BigUint::from(5u8));
+    }
+
+    #[test]
+    fn test_scenario_file_unreadable() {
+        let result = ScenarioWorldInfos::from_scenario_file(std::env::temp_dir().join("novax_scenario_not_existing.scen.json"));
+
+        assert_eq!(result, Err(NovaXMockingError::UnableToReadScenarioFile));
+    }
+}
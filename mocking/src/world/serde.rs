@@ -1,15 +1,21 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use novax::account::AccountInfos;
 use novax::Address;
+use novax_token::properties::model::TokenProperties;
 use crate::gateway::keys::AddressKeys;
-use crate::world::infos::{ScenarioWorldInfosEsdtTokenAmount, ScenarioWorldInfos};
+use crate::world::infos::{ScenarioWorldInfosEsdtRoles, ScenarioWorldInfosEsdtTokenAmount, ScenarioWorldInfos};
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub(crate) struct ScenarioWorldInfosJson {
     pub(crate) address_keys: HashMap<String, AddressKeys>,
     pub(crate) address_balances: HashMap<String, Vec<ScenarioWorldInfosEsdtTokenAmount>>,
-    pub(crate) address_infos: Vec<AccountInfos>
+    pub(crate) address_infos: Vec<AccountInfos>,
+    #[serde(default)]
+    pub(crate) address_esdt_roles: HashMap<String, Vec<ScenarioWorldInfosEsdtRoles>>,
+    #[serde(default)]
+    pub(crate) token_properties: HashMap<String, TokenProperties>
 }
 
 impl From<ScenarioWorldInfos> for ScenarioWorldInfosJson {
@@ -18,10 +24,47 @@ impl From<ScenarioWorldInfos> for ScenarioWorldInfosJson {
             address_keys: convert_hashmap_address_keys_to_bech32(value.address_keys),
             address_balances: convert_hashmap_address_keys_to_bech32(value.address_balances),
             address_infos: value.address_infos,
+            address_esdt_roles: convert_hashmap_address_keys_to_bech32(value.address_esdt_roles),
+            token_properties: value.token_properties,
         }
     }
 }
 
+impl ScenarioWorldInfosJson {
+    /// Sorts the lists whose order doesn't matter, so the same infos are always serialized the same way.
+    pub(crate) fn sorted(mut self) -> ScenarioWorldInfosJson {
+        self.address_infos.sort_by(|a, b| a.data.account.address.cmp(&b.data.account.address));
+
+        for balances in self.address_balances.values_mut() {
+            balances.sort_by(|a, b| (&a.token_identifier, a.nonce).cmp(&(&b.token_identifier, b.nonce)));
+        }
+
+        for esdt_roles in self.address_esdt_roles.values_mut() {
+            esdt_roles.sort_by(|a, b| a.token_identifier.cmp(&b.token_identifier));
+
+            for roles in esdt_roles.iter_mut() {
+                roles.roles.sort();
+            }
+        }
+
+        self
+    }
+}
+
+/// Returns the value with the keys of every object sorted, whatever the order in which they have been inserted.
+pub(crate) fn sort_json_value(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<(String, Value)> = object.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+            Value::Object(entries.into_iter().map(|(key, value)| (key, sort_json_value(value))).collect::<Map<_, _>>())
+        },
+        Value::Array(array) => Value::Array(array.into_iter().map(sort_json_value).collect()),
+        value => value
+    }
+}
+
 fn convert_hashmap_address_keys_to_bech32<T>(hashmap: HashMap<[u8; 32], T>) -> HashMap<String, T> {
     let mut result = HashMap::new();
     for (key, value) in hashmap.into_iter() {
@@ -38,7 +81,7 @@ mod test {
     use novax::account::{AccountInfos, AccountInfosAccountData, AccountInfosData};
     use novax::Address;
     use crate::gateway::keys::{AddressKeys, AddressKeysData};
-    use crate::world::serde::{convert_hashmap_address_keys_to_bech32, ScenarioWorldInfosJson};
+    use crate::world::serde::{convert_hashmap_address_keys_to_bech32, sort_json_value, ScenarioWorldInfosJson};
     use crate::world::infos::{ScenarioWorldInfosEsdtTokenAmount, ScenarioWorldInfos};
 
     #[test]
@@ -89,6 +132,7 @@ mod test {
                         balance: "1".to_string(),
                         code: Some("code".to_string()),
                         owner_address: Some(first_address.to_string()),
+                        root_hash: None,
                     },
                 },
             }
@@ -97,7 +141,9 @@ mod test {
         let scenario_world = ScenarioWorldInfos {
             address_keys: address_keys.clone(),
             address_balances: address_balances.clone(),
-            address_infos: address_infos.clone()
+            address_infos: address_infos.clone(),
+            address_esdt_roles: HashMap::new(),
+            token_properties: HashMap::new(),
         };
 
         let result = ScenarioWorldInfosJson::from(scenario_world);
@@ -110,11 +156,25 @@ mod test {
             address_keys: expected_address_keys,
             address_balances: expected_address_balances,
             address_infos: expected_address_infos,
+            address_esdt_roles: HashMap::new(),
+            token_properties: HashMap::new(),
         };
 
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn test_sort_json_value() {
+        let value = serde_json::json!({
+            "b": [{"d": 1, "c": 2}],
+            "a": "value"
+        });
+
+        let result = serde_json::to_string(&sort_json_value(value)).unwrap();
+
+        assert_eq!(result, r#"{"a":"value","b":[{"c":2,"d":1}]}"#);
+    }
+
     #[test]
     fn test_convert_hashmap_empty() {
         let input: HashMap<[u8; 32], u64> = HashMap::new();
@@ -62,6 +62,7 @@ pub mod multisig {
     use novax_executor::call_result::CallResult;
     use novax_executor::DeployExecutor;
     use novax_executor::QueryExecutor;
+    use novax_executor::QueryBlock;
     use novax_executor::TokenTransfer;
     use novax_executor::TransactionExecutor;
     use serde::{Deserialize, Serialize};
@@ -234,6 +235,29 @@ pub mod multisig {
     {
         contract_address: A,
         egld_value: num_bigint::BigUint,
+        token_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>,
+        executor: Executor,
+        caching: Caching,
+    }
+    #[doc = r" Struct representing a smart contract query refreshing outdated cached results in the background."]
+    #[doc = r" It is obtained through `#query_name::with_background_refresh` and has the same views as `#query_name`."]
+    #[doc = r#" With a caching strategy supporting it, such as `CachingStale` from the "novax-caching" crate,"#]
+    #[doc = r" a stale result is returned right away while the query is executed again by a spawned task."]
+    #[derive(Clone, Debug)]
+    pub struct MultisigContractBackgroundQuery<Executor, Caching, A>
+    where
+        Executor: QueryExecutor,
+        Caching: CachingStrategy,
+        A: Deref + Send + Sync,
+        Address: for<'a> From<&'a A::Target>,
+    {
+        contract_address: A,
+        egld_value: num_bigint::BigUint,
+        token_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>,
         executor: Executor,
         caching: Caching,
     }
@@ -271,6 +295,9 @@ pub mod multisig {
             MultisigContractQuery {
                 contract_address: self.address,
                 egld_value: num_bigint::BigUint::from(0u8),
+                token_transfers: vec![],
+                caller: None,
+                block: None,
                 executor,
                 caching: CachingNone,
             }
@@ -348,6 +375,9 @@ pub mod multisig {
                 contract_address: self.contract_address,
                 executor: self.executor,
                 egld_value: self.egld_value,
+                token_transfers: self.token_transfers,
+                caller: self.caller,
+                block: self.block,
                 caching: strategy.clone(),
             }
         }
@@ -365,6 +395,81 @@ pub mod multisig {
         ) -> MultisigContractQuery<Executor, Caching, A> {
             MultisigContractQuery { egld_value, ..self }
         }
+        #[doc = r" Modifies the ESDT transfers associated with the query."]
+        #[doc = r" This method allows setting a new list of ESDT transfers sent along with the query, for views depending on payments."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `token_transfers`: A reference to the new list of ESDT transfers to be used."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` with the updated ESDT transfers."]
+        pub fn with_esdt_transfers(
+            self,
+            token_transfers: &Vec<TokenTransfer>,
+        ) -> MultisigContractQuery<Executor, Caching, A> {
+            MultisigContractQuery {
+                token_transfers: token_transfers.clone(),
+                ..self
+            }
+        }
+        #[doc = r" Modifies the caller of the query."]
+        #[doc = r" By default, the query is made by the contract itself."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `caller`: A reference to the address making the query."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` with the updated caller."]
+        pub fn with_caller(self, caller: &Address) -> MultisigContractQuery<Executor, Caching, A> {
+            MultisigContractQuery {
+                caller: Some(caller.clone()),
+                ..self
+            }
+        }
+        #[doc = r" Executes the query against the state of the blockchain right after the block with the given nonce."]
+        #[doc = r" The block is part of the caching key, so results at different blocks are cached separately."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `block_nonce`: The nonce of the block."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` targeting the given block."]
+        pub fn with_block_nonce(self, block_nonce: u64) -> MultisigContractQuery<Executor, Caching, A> {
+            MultisigContractQuery {
+                block: Some(QueryBlock::Nonce(block_nonce)),
+                ..self
+            }
+        }
+        #[doc = r" Executes the query against the state of the blockchain right after the block with the given hash."]
+        #[doc = r" The block is part of the caching key, so results at different blocks are cached separately."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `block_hash`: The hex-encoded hash of the block."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` targeting the given block."]
+        pub fn with_block_hash(self, block_hash: String) -> MultisigContractQuery<Executor, Caching, A> {
+            MultisigContractQuery {
+                block: Some(QueryBlock::Hash(block_hash)),
+                ..self
+            }
+        }
+        #[doc = r" Refreshes outdated cached results in the background instead of waiting for the query."]
+        #[doc = r" The query is executed by a spawned task, so the executor must be `Clone + 'static`."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#background_query_name` with the same parameters."]
+        pub fn with_background_refresh(self) -> MultisigContractBackgroundQuery<Executor, Caching, A> {
+            MultisigContractBackgroundQuery {
+                contract_address: self.contract_address,
+                egld_value: self.egld_value,
+                token_transfers: self.token_transfers,
+                caller: self.caller,
+                block: self.block,
+                executor: self.executor,
+                caching: self.caching,
+            }
+        }
         #[doc = r" Executes the `#function_name` query on the smart contract."]
         #[doc = r""]
         #[doc = r" # Description"]
@@ -390,6 +495,24 @@ pub mod multisig {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "deposit".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -400,7 +523,9 @@ pub mod multisig {
                             "deposit".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -461,6 +586,24 @@ pub mod multisig {
             "signed".hash(&mut _novax_hasher);
             user.hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -471,7 +614,9 @@ pub mod multisig {
                             "signed".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -521,6 +666,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "sign".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -531,7 +694,9 @@ pub mod multisig {
                             "sign".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -581,6 +746,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "unsign".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -591,7 +774,9 @@ pub mod multisig {
                             "unsign".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -641,6 +826,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "discardAction".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -651,7 +854,9 @@ pub mod multisig {
                             "discardAction".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -690,6 +895,24 @@ pub mod multisig {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getQuorum".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -700,7 +923,9 @@ pub mod multisig {
                             "getQuorum".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -739,6 +964,24 @@ pub mod multisig {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getNumBoardMembers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -749,7 +992,9 @@ pub mod multisig {
                             "getNumBoardMembers".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -788,6 +1033,24 @@ pub mod multisig {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getNumProposers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -798,7 +1061,9 @@ pub mod multisig {
                             "getNumProposers".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -837,6 +1102,24 @@ pub mod multisig {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getActionLastIndex".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -847,7 +1130,9 @@ pub mod multisig {
                             "getActionLastIndex".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -898,6 +1183,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "proposeAddBoardMember".hash(&mut _novax_hasher);
             board_member_address.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -908,7 +1211,9 @@ pub mod multisig {
                             "proposeAddBoardMember".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -959,6 +1264,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "proposeAddProposer".hash(&mut _novax_hasher);
             proposer_address.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -969,7 +1292,9 @@ pub mod multisig {
                             "proposeAddProposer".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1020,6 +1345,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "proposeRemoveUser".hash(&mut _novax_hasher);
             user_address.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1030,7 +1373,9 @@ pub mod multisig {
                             "proposeRemoveUser".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1080,6 +1425,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "proposeChangeQuorum".hash(&mut _novax_hasher);
             new_quorum.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1090,7 +1453,9 @@ pub mod multisig {
                             "proposeChangeQuorum".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1165,6 +1530,24 @@ pub mod multisig {
             to.hash(&mut _novax_hasher);
             egld_amount.hash(&mut _novax_hasher);
             function_call.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1175,7 +1558,9 @@ pub mod multisig {
                             "proposeTransferExecute".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1250,6 +1635,24 @@ pub mod multisig {
             to.hash(&mut _novax_hasher);
             egld_amount.hash(&mut _novax_hasher);
             function_call.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1260,7 +1663,9 @@ pub mod multisig {
                             "proposeAsyncCall".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1344,6 +1749,24 @@ pub mod multisig {
             source.hash(&mut _novax_hasher);
             code_metadata.hash(&mut _novax_hasher);
             arguments.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1354,7 +1777,9 @@ pub mod multisig {
                             "proposeSCDeployFromSource".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1449,6 +1874,24 @@ pub mod multisig {
             source.hash(&mut _novax_hasher);
             code_metadata.hash(&mut _novax_hasher);
             arguments.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1459,7 +1902,9 @@ pub mod multisig {
                             "proposeSCUpgradeFromSource".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1509,6 +1954,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "quorumReached".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1519,7 +1982,9 @@ pub mod multisig {
                             "quorumReached".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1572,6 +2037,24 @@ pub mod multisig {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "performAction".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1582,7 +2065,9 @@ pub mod multisig {
                             "performAction".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -1643,6 +2128,24 @@ pub mod multisig {
             "dnsRegister".hash(&mut _novax_hasher);
             dns_address.hash(&mut _novax_hasher);
             name.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -1653,7 +2156,1797 @@ pub mod multisig {
                             "dnsRegister".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+    }
+    impl<Executor, Caching, A> MultisigContractBackgroundQuery<Executor, Caching, A>
+    where
+        Executor: QueryExecutor + Clone + 'static,
+        Caching: CachingStrategy,
+        A: Deref + Send + Sync,
+        Address: for<'a> From<&'a A::Target>,
+    {
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn deposit(&mut self) -> Result<(), NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "deposit".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<()>(
+                            &_novax_contract_address,
+                            "deposit".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn signed(
+            &mut self,
+            user: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<<bool as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_user: ManagedAddress<StaticApi> = r#user.to_managed();
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_user.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "signed".hash(&mut _novax_hasher);
+            user.hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<bool>(
+                            &_novax_contract_address,
+                            "signed".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn sign(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<(), NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "sign".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<()>(
+                            &_novax_contract_address,
+                            "sign".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn unsign(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<(), NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "unsign".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<()>(
+                            &_novax_contract_address,
+                            "unsign".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn discard_action(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<(), NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "discardAction".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<()>(
+                            &_novax_contract_address,
+                            "discardAction".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_quorum(
+            &mut self,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getQuorum".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "getQuorum".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_num_board_members(
+            &mut self,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getNumBoardMembers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "getNumBoardMembers".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_num_proposers(
+            &mut self,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getNumProposers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "getNumProposers".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_action_last_index(
+            &mut self,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getActionLastIndex".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "getActionLastIndex".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_add_board_member(
+            &mut self,
+            board_member_address: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_board_member_address: ManagedAddress<StaticApi> =
+                r#board_member_address.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_board_member_address.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeAddBoardMember".hash(&mut _novax_hasher);
+            board_member_address.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeAddBoardMember".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_add_proposer(
+            &mut self,
+            proposer_address: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_proposer_address: ManagedAddress<StaticApi> =
+                r#proposer_address.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_proposer_address.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeAddProposer".hash(&mut _novax_hasher);
+            proposer_address.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeAddProposer".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_remove_user(
+            &mut self,
+            user_address: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_user_address: ManagedAddress<StaticApi> =
+                r#user_address.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_user_address.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeRemoveUser".hash(&mut _novax_hasher);
+            user_address.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeRemoveUser".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_change_quorum(
+            &mut self,
+            new_quorum: &<u32 as NativeConvertible>::Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_new_quorum: u32 = r#new_quorum.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_new_quorum.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeChangeQuorum".hash(&mut _novax_hasher);
+            new_quorum.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeChangeQuorum".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_transfer_execute(
+            &mut self,
+            to: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+            egld_amount: &<BigUint<StaticApi> as NativeConvertible>::Native,
+            function_call : & < MultiValueEncoded < StaticApi , ManagedBuffer < StaticApi > > as NativeConvertible > :: Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_to: ManagedAddress<StaticApi> = r#to.to_managed();
+            let _novax_argument_egld_amount: BigUint<StaticApi> = r#egld_amount.to_managed();
+            let _novax_argument_function_call: MultiValueEncoded<
+                StaticApi,
+                ManagedBuffer<StaticApi>,
+            > = r#function_call.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_to.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_egld_amount.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_function_call.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeTransferExecute".hash(&mut _novax_hasher);
+            to.hash(&mut _novax_hasher);
+            egld_amount.hash(&mut _novax_hasher);
+            function_call.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeTransferExecute".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_async_call(
+            &mut self,
+            to: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+            egld_amount: &<BigUint<StaticApi> as NativeConvertible>::Native,
+            function_call : & < MultiValueEncoded < StaticApi , ManagedBuffer < StaticApi > > as NativeConvertible > :: Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_to: ManagedAddress<StaticApi> = r#to.to_managed();
+            let _novax_argument_egld_amount: BigUint<StaticApi> = r#egld_amount.to_managed();
+            let _novax_argument_function_call: MultiValueEncoded<
+                StaticApi,
+                ManagedBuffer<StaticApi>,
+            > = r#function_call.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_to.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_egld_amount.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_function_call.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeAsyncCall".hash(&mut _novax_hasher);
+            to.hash(&mut _novax_hasher);
+            egld_amount.hash(&mut _novax_hasher);
+            function_call.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeAsyncCall".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_sc_deploy_from_source(
+            &mut self,
+            amount: &<BigUint<StaticApi> as NativeConvertible>::Native,
+            source: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+            code_metadata: &<CodeMetadata as NativeConvertible>::Native,
+            arguments : & < MultiValueEncoded < StaticApi , ManagedBuffer < StaticApi > > as NativeConvertible > :: Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_amount: BigUint<StaticApi> = r#amount.to_managed();
+            let _novax_argument_source: ManagedAddress<StaticApi> = r#source.to_managed();
+            let _novax_argument_code_metadata: CodeMetadata = r#code_metadata.to_managed();
+            let _novax_argument_arguments: MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> =
+                r#arguments.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_amount.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_source.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_code_metadata.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_arguments.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeSCDeployFromSource".hash(&mut _novax_hasher);
+            amount.hash(&mut _novax_hasher);
+            source.hash(&mut _novax_hasher);
+            code_metadata.hash(&mut _novax_hasher);
+            arguments.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeSCDeployFromSource".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn propose_sc_upgrade_from_source(
+            &mut self,
+            sc_address: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+            amount: &<BigUint<StaticApi> as NativeConvertible>::Native,
+            source: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+            code_metadata: &<CodeMetadata as NativeConvertible>::Native,
+            arguments : & < MultiValueEncoded < StaticApi , ManagedBuffer < StaticApi > > as NativeConvertible > :: Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_sc_address: ManagedAddress<StaticApi> = r#sc_address.to_managed();
+            let _novax_argument_amount: BigUint<StaticApi> = r#amount.to_managed();
+            let _novax_argument_source: ManagedAddress<StaticApi> = r#source.to_managed();
+            let _novax_argument_code_metadata: CodeMetadata = r#code_metadata.to_managed();
+            let _novax_argument_arguments: MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> =
+                r#arguments.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_sc_address.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_amount.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_source.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_code_metadata.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_arguments.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "proposeSCUpgradeFromSource".hash(&mut _novax_hasher);
+            sc_address.hash(&mut _novax_hasher);
+            amount.hash(&mut _novax_hasher);
+            source.hash(&mut _novax_hasher);
+            code_metadata.hash(&mut _novax_hasher);
+            arguments.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "proposeSCUpgradeFromSource".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn quorum_reached(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<<bool as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "quorumReached".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<bool>(
+                            &_novax_contract_address,
+                            "quorumReached".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn perform_action(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<
+            <OptionalValue<ManagedAddress<StaticApi>> as NativeConvertible>::Native,
+            NovaXError,
+        > {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "performAction".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<OptionalValue<ManagedAddress<StaticApi>>>(
+                            &_novax_contract_address,
+                            "performAction".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn dns_register(
+            &mut self,
+            dns_address: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+            name: &<ManagedBuffer<StaticApi> as NativeConvertible>::Native,
+        ) -> Result<(), NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract = MultisigContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_dns_address: ManagedAddress<StaticApi> = r#dns_address.to_managed();
+            let _novax_argument_name: ManagedBuffer<StaticApi> = r#name.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_dns_address.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_name.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "dnsRegister".hash(&mut _novax_hasher);
+            dns_address.hash(&mut _novax_hasher);
+            name.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<()>(
+                            &_novax_contract_address,
+                            "dnsRegister".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
                         )
                         .await;
                     if let Result::Ok(result) = result {
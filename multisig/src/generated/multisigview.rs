@@ -62,6 +62,7 @@ pub mod multisigview {
     use novax_executor::call_result::CallResult;
     use novax_executor::DeployExecutor;
     use novax_executor::QueryExecutor;
+    use novax_executor::QueryBlock;
     use novax_executor::TokenTransfer;
     use novax_executor::TransactionExecutor;
     use serde::{Deserialize, Serialize};
@@ -183,6 +184,29 @@ pub mod multisigview {
     {
         contract_address: A,
         egld_value: num_bigint::BigUint,
+        token_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>,
+        executor: Executor,
+        caching: Caching,
+    }
+    #[doc = r" Struct representing a smart contract query refreshing outdated cached results in the background."]
+    #[doc = r" It is obtained through `#query_name::with_background_refresh` and has the same views as `#query_name`."]
+    #[doc = r#" With a caching strategy supporting it, such as `CachingStale` from the "novax-caching" crate,"#]
+    #[doc = r" a stale result is returned right away while the query is executed again by a spawned task."]
+    #[derive(Clone, Debug)]
+    pub struct MultisigViewContractBackgroundQuery<Executor, Caching, A>
+    where
+        Executor: QueryExecutor,
+        Caching: CachingStrategy,
+        A: Deref + Send + Sync,
+        Address: for<'a> From<&'a A::Target>,
+    {
+        contract_address: A,
+        egld_value: num_bigint::BigUint,
+        token_transfers: Vec<TokenTransfer>,
+        caller: Option<Address>,
+        block: Option<QueryBlock>,
         executor: Executor,
         caching: Caching,
     }
@@ -220,6 +244,9 @@ pub mod multisigview {
             MultisigViewContractQuery {
                 contract_address: self.address,
                 egld_value: num_bigint::BigUint::from(0u8),
+                token_transfers: vec![],
+                caller: None,
+                block: None,
                 executor,
                 caching: CachingNone,
             }
@@ -288,6 +315,9 @@ pub mod multisigview {
                 contract_address: self.contract_address,
                 executor: self.executor,
                 egld_value: self.egld_value,
+                token_transfers: self.token_transfers,
+                caller: self.caller,
+                block: self.block,
                 caching: strategy.clone(),
             }
         }
@@ -305,6 +335,81 @@ pub mod multisigview {
         ) -> MultisigViewContractQuery<Executor, Caching, A> {
             MultisigViewContractQuery { egld_value, ..self }
         }
+        #[doc = r" Modifies the ESDT transfers associated with the query."]
+        #[doc = r" This method allows setting a new list of ESDT transfers sent along with the query, for views depending on payments."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `token_transfers`: A reference to the new list of ESDT transfers to be used."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` with the updated ESDT transfers."]
+        pub fn with_esdt_transfers(
+            self,
+            token_transfers: &Vec<TokenTransfer>,
+        ) -> MultisigViewContractQuery<Executor, Caching, A> {
+            MultisigViewContractQuery {
+                token_transfers: token_transfers.clone(),
+                ..self
+            }
+        }
+        #[doc = r" Modifies the caller of the query."]
+        #[doc = r" By default, the query is made by the contract itself."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `caller`: A reference to the address making the query."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` with the updated caller."]
+        pub fn with_caller(self, caller: &Address) -> MultisigViewContractQuery<Executor, Caching, A> {
+            MultisigViewContractQuery {
+                caller: Some(caller.clone()),
+                ..self
+            }
+        }
+        #[doc = r" Executes the query against the state of the blockchain right after the block with the given nonce."]
+        #[doc = r" The block is part of the caching key, so results at different blocks are cached separately."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `block_nonce`: The nonce of the block."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` targeting the given block."]
+        pub fn with_block_nonce(self, block_nonce: u64) -> MultisigViewContractQuery<Executor, Caching, A> {
+            MultisigViewContractQuery {
+                block: Some(QueryBlock::Nonce(block_nonce)),
+                ..self
+            }
+        }
+        #[doc = r" Executes the query against the state of the blockchain right after the block with the given hash."]
+        #[doc = r" The block is part of the caching key, so results at different blocks are cached separately."]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" * `block_hash`: The hex-encoded hash of the block."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#query_name` targeting the given block."]
+        pub fn with_block_hash(self, block_hash: String) -> MultisigViewContractQuery<Executor, Caching, A> {
+            MultisigViewContractQuery {
+                block: Some(QueryBlock::Hash(block_hash)),
+                ..self
+            }
+        }
+        #[doc = r" Refreshes outdated cached results in the background instead of waiting for the query."]
+        #[doc = r" The query is executed by a spawned task, so the executor must be `Clone + 'static`."]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A new instance of `#background_query_name` with the same parameters."]
+        pub fn with_background_refresh(self) -> MultisigViewContractBackgroundQuery<Executor, Caching, A> {
+            MultisigViewContractBackgroundQuery {
+                contract_address: self.contract_address,
+                egld_value: self.egld_value,
+                token_transfers: self.token_transfers,
+                caller: self.caller,
+                block: self.block,
+                executor: self.executor,
+                caching: self.caching,
+            }
+        }
         #[doc = r" Executes the `#function_name` query on the smart contract."]
         #[doc = r""]
         #[doc = r" # Description"]
@@ -336,6 +441,24 @@ pub mod multisigview {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getPendingActionFullInfo".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -346,7 +469,9 @@ pub mod multisigview {
                             "getPendingActionFullInfo".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -397,6 +522,24 @@ pub mod multisigview {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "userRole".hash(&mut _novax_hasher);
             user.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -407,7 +550,9 @@ pub mod multisigview {
                             "userRole".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -450,6 +595,24 @@ pub mod multisigview {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getAllBoardMembers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -460,7 +623,9 @@ pub mod multisigview {
                             "getAllBoardMembers".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -503,6 +668,24 @@ pub mod multisigview {
             let mut _novax_hasher = DefaultHasher::new();
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getAllProposers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -513,7 +696,9 @@ pub mod multisigview {
                             "getAllProposers".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -564,6 +749,24 @@ pub mod multisigview {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getActionData".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -574,7 +777,9 @@ pub mod multisigview {
                             "getActionData".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -628,6 +833,24 @@ pub mod multisigview {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getActionSigners".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -638,7 +861,9 @@ pub mod multisigview {
                             "getActionSigners".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -689,6 +914,24 @@ pub mod multisigview {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getActionSignerCount".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -699,7 +942,9 @@ pub mod multisigview {
                             "getActionSignerCount".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
                         )
                         .await;
                     if let Result::Ok(result) = result {
@@ -750,6 +995,24 @@ pub mod multisigview {
             _novax_contract_address_value.value.hash(&mut _novax_hasher);
             "getActionValidSignerCount".hash(&mut _novax_hasher);
             action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
             let _novax_key = _novax_hasher.finish();
             self.caching
                 .get_or_set_cache(_novax_key, async {
@@ -760,7 +1023,676 @@ pub mod multisigview {
                             "getActionValidSignerCount".to_string(),
                             _novax_bytes_args,
                             self.egld_value.clone(),
-                            vec![],
+                            self.token_transfers.clone(),
+                            self.caller.clone(),
+                            self.block.clone(),
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+    }
+    impl<Executor, Caching, A> MultisigViewContractBackgroundQuery<Executor, Caching, A>
+    where
+        Executor: QueryExecutor + Clone + 'static,
+        Caching: CachingStrategy,
+        A: Deref + Send + Sync,
+        Address: for<'a> From<&'a A::Target>,
+    {
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_pending_action_full_info(
+            &mut self,
+        ) -> Result<
+            <MultiValueEncoded<StaticApi, ActionFullInfoManaged> as NativeConvertible>::Native,
+            NovaXError,
+        > {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getPendingActionFullInfo".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<MultiValueEncoded<StaticApi, ActionFullInfoManaged>>(
+                            &_novax_contract_address,
+                            "getPendingActionFullInfo".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn user_role(
+            &mut self,
+            user: &<ManagedAddress<StaticApi> as NativeConvertible>::Native,
+        ) -> Result<<UserRoleManaged as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_user: ManagedAddress<StaticApi> = r#user.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_user.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "userRole".hash(&mut _novax_hasher);
+            user.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<UserRoleManaged>(
+                            &_novax_contract_address,
+                            "userRole".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_all_board_members(
+            &mut self,
+        ) -> Result<
+            <MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> as NativeConvertible>::Native,
+            NovaXError,
+        > {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getAllBoardMembers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>>>(
+                            &_novax_contract_address,
+                            "getAllBoardMembers".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_all_proposers(
+            &mut self,
+        ) -> Result<
+            <MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> as NativeConvertible>::Native,
+            NovaXError,
+        > {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getAllProposers".hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>>>(
+                            &_novax_contract_address,
+                            "getAllProposers".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_action_data(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<<ActionManaged as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getActionData".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<ActionManaged>(
+                            &_novax_contract_address,
+                            "getActionData".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_action_signers(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<
+            <ManagedVec<StaticApi, ManagedAddress<StaticApi>> as NativeConvertible>::Native,
+            NovaXError,
+        > {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getActionSigners".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<ManagedVec<StaticApi, ManagedAddress<StaticApi>>>(
+                            &_novax_contract_address,
+                            "getActionSigners".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_action_signer_count(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getActionSignerCount".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "getActionSignerCount".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
+                        )
+                        .await;
+                    if let Result::Ok(result) = result {
+                        Result::Ok::<_, NovaXError>(result)
+                    } else {
+                        let error: NovaXError = result.unwrap_err().into();
+                        Result::Err(error)
+                    }
+                })
+                .await
+        }
+        #[doc = r" Executes the `#function_name` query on the smart contract, refreshing an outdated cached result in the background."]
+        #[doc = r""]
+        #[doc = r" # Description"]
+        #[doc = r" #abi_description"]
+        #[doc = r""]
+        #[doc = r" # Parameters"]
+        #[doc = r" #function_inputs"]
+        #[doc = r""]
+        #[doc = r" # Returns"]
+        #[doc = r" A `Result` containing the `#function_native_outputs` or a `NovaXError` if the query fails."]
+        pub async fn get_action_valid_signer_count(
+            &mut self,
+            action_id: &<u32 as NativeConvertible>::Native,
+        ) -> Result<<u32 as NativeConvertible>::Native, NovaXError> {
+            let _novax_request_arc =
+                novax::utils::static_request_arc::get_static_request_arc_clone();
+            let _novax_contract_address = Address::from(&self.contract_address);
+            let _novax_contract_address_value: AddressValue = (&_novax_contract_address).into();
+            let mut _novax_contract =
+                MultisigViewContractInfos::new(&_novax_contract_address_value);
+            let _novax_argument_action_id: u32 = r#action_id.to_managed();
+            let _novax_payment = if self.egld_value > num_bigint::BigUint::from(0u8) {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(self.egld_value.clone()))
+            } else {
+                EgldOrMultiEsdtPayment::Egld(BigUint::<StaticApi>::from(0u8))
+            };
+            let mut _novax_bytes_args: std::vec::Vec<std::vec::Vec<u8>> = vec![];
+            {
+                let mut _novax_top_encoded_args =
+                    ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::new();
+                _ = _novax_argument_action_id.multi_encode(&mut _novax_top_encoded_args);
+                for _novax_top_encoded_arg in _novax_top_encoded_args.into_iter() {
+                    _novax_bytes_args.push(_novax_top_encoded_arg.to_boxed_bytes().into_vec());
+                }
+            }
+            let mut _novax_hasher = DefaultHasher::new();
+            _novax_contract_address_value.value.hash(&mut _novax_hasher);
+            "getActionValidSignerCount".hash(&mut _novax_hasher);
+            action_id.hash(&mut _novax_hasher);
+            if self.egld_value > num_bigint::BigUint::from(0u8) {
+                self.egld_value.hash(&mut _novax_hasher);
+            }
+            for transfer in &self.token_transfers {
+                transfer.identifier.hash(&mut _novax_hasher);
+                transfer.nonce.hash(&mut _novax_hasher);
+                transfer.amount.hash(&mut _novax_hasher);
+            }
+            if let Some(caller) = &self.caller {
+                caller.hash(&mut _novax_hasher);
+            }
+            let _novax_block = match &self.block {
+                Some(block) => Some(block.clone()),
+                None => self.executor.get_pinned_block().await,
+            };
+            if let Some(block) = &_novax_block {
+                block.hash(&mut _novax_hasher);
+            }
+            let _novax_key = _novax_hasher.finish();
+            let _novax_executor = self.executor.clone();
+            let _novax_egld_value = self.egld_value.clone();
+            let _novax_token_transfers = self.token_transfers.clone();
+            let _novax_caller = self.caller.clone();
+            let _novax_query_block = self.block.clone();
+            self.caching
+                .get_or_refresh_cache_in_background(_novax_key, async move {
+                    let result = _novax_executor
+                        .execute::<u32>(
+                            &_novax_contract_address,
+                            "getActionValidSignerCount".to_string(),
+                            _novax_bytes_args,
+                            _novax_egld_value,
+                            _novax_token_transfers,
+                            _novax_caller,
+                            _novax_query_block,
                         )
                         .await;
                     if let Result::Ok(result) = result {
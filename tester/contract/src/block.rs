@@ -0,0 +1,28 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait BlockModule: ContractBase {
+    #[view(getCurrentBlockInfos)]
+    fn get_current_block_infos(&self) -> MultiValue4<u64, u64, u64, u64> {
+        let blockchain = self.blockchain();
+
+        (
+            blockchain.get_block_timestamp(),
+            blockchain.get_block_nonce(),
+            blockchain.get_block_round(),
+            blockchain.get_block_epoch(),
+        ).into()
+    }
+
+    #[view(getPreviousBlockInfos)]
+    fn get_previous_block_infos(&self) -> MultiValue4<u64, u64, u64, u64> {
+        let blockchain = self.blockchain();
+
+        (
+            blockchain.get_prev_block_timestamp(),
+            blockchain.get_prev_block_nonce(),
+            blockchain.get_prev_block_round(),
+            blockchain.get_prev_block_epoch(),
+        ).into()
+    }
+}
@@ -4,11 +4,13 @@ mod adder;
 mod printer;
 mod call;
 mod events;
+mod block;
+mod roles;
 
 multiversx_sc::imports!();
 
 #[multiversx_sc::contract]
-pub trait Tester: ContractBase + adder::AdderModule + printer::PrinterModule + call::CallModule + events::EventsModule {
+pub trait Tester: ContractBase + adder::AdderModule + printer::PrinterModule + call::CallModule + events::EventsModule + block::BlockModule + roles::RolesModule {
     #[init]
     fn init(&self, initial_value: BigUint<Self::Api>) {
         self.sum().set(initial_value);
@@ -115,6 +115,18 @@ pub trait PrinterModule: ContractBase {
         self.blockchain().get_caller()
     }
 
+    #[payable("EGLD")]
+    #[endpoint(returnEgldPayment)]
+    fn return_egld_payment(&self) -> BigUint<Self::Api> {
+        self.call_value().egld().clone_value()
+    }
+
+    #[payable("*")]
+    #[endpoint(returnSingleEsdtPaymentAmount)]
+    fn return_single_esdt_payment_amount(&self) -> BigUint<Self::Api> {
+        self.call_value().single_esdt().amount.clone()
+    }
+
     #[endpoint(returnManagedBuffer)]
     fn return_managed_buffer(&self) -> ManagedBuffer<Self::Api> {
         ManagedBuffer::from("test")
@@ -223,6 +235,13 @@ pub trait PrinterModule: ContractBase {
         2 * arg
     }
 
+    #[endpoint(returnNonZeroU64Arg)]
+    fn return_non_zero_u64_arg(&self, arg: u64) -> u64 {
+        require!(arg != 0, "arg is zero");
+
+        arg
+    }
+
     #[endpoint(returnDoubleOfBiguintArg)]
     fn return_double_of_biguint_arg(&self, arg: BigUint<Self::Api>) -> BigUint<Self::Api> {
         BigUint::from(2u8) * arg
@@ -0,0 +1,11 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait RolesModule: ContractBase {
+    #[view(hasLocalMintRole)]
+    fn has_local_mint_role(&self, token_identifier: TokenIdentifier<Self::Api>) -> bool {
+        self.blockchain()
+            .get_esdt_local_roles(&token_identifier)
+            .has_role(&EsdtLocalRole::Mint)
+    }
+}
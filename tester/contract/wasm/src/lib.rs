@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           55
+// Endpoints:                           61
 // Async Callback:                       1
-// Total number of exported functions:  58
+// Total number of exported functions:  64
 
 #![no_std]
 
@@ -29,6 +29,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         returnNonFungibleBalance => return_non_fungible_balance
         noArgNoReturnEndpoint => no_arg_no_return_endpoint
         returnCaller => return_caller
+        returnEgldPayment => return_egld_payment
+        returnSingleEsdtPaymentAmount => return_single_esdt_payment_amount
         returnManagedBuffer => return_managed_buffer
         returnContractAddress => return_contract_address
         returnBiguint => return_biguint
@@ -44,6 +46,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         returnTwoBuffers => return_two_buffers
         returnOneBufferOneU64AndOneBiguint => return_one_buffer_one_u64_and_one_buffer
         returnDoubleOfU64Arg => return_double_of_u64_arg
+        returnNonZeroU64Arg => return_non_zero_u64_arg
         returnDoubleOfBiguintArg => return_double_of_biguint_arg
         returnAppendedBufferArg => return_appended_buffer_arg
         returnSumTwoBiguintArgs => return_sum_two_biguint_args
@@ -75,6 +78,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         emitEventWithOnlyData => emit_event_with_only_data
         emitEventWithMultiValueEncoded => emit_event_with_multi_value_encoded
         emitEventWithMultiValue => emit_event_with_multi_value
+        getCurrentBlockInfos => get_current_block_infos
+        getPreviousBlockInfos => get_previous_block_infos
+        hasLocalMintRole => has_local_mint_role
     )
 }
 
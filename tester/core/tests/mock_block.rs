@@ -0,0 +1,124 @@
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use novax::errors::NovaXError;
+use novax::executor::{MockBlockInfos, StandardMockExecutor};
+use novax::tester::tester::TesterContract;
+use novax_mocking::world::infos::ScenarioWorldInfos;
+
+const CALLER: &str = "bech32:erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+const TESTER_CONTRACT_ADDRESS: &str = "bech32:erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+
+fn get_executor() -> StandardMockExecutor {
+    let infos = ScenarioWorldInfos::from_file(Path::new("tests/data/adder_world_dump.json")).unwrap();
+    let world = infos.into_world(|_, code_expr, world| {
+        world.register_contract(code_expr, tester_contract::ContractBuilder)
+    }).unwrap();
+
+    StandardMockExecutor::new(
+        Arc::new(Mutex::new(world)),
+        Some(CALLER.to_string())
+    )
+}
+
+async fn query_current_block_infos(executor: &StandardMockExecutor) -> Result<(u64, u64, u64, u64), NovaXError> {
+    TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .get_current_block_infos()
+        .await
+}
+
+async fn query_previous_block_infos(executor: &StandardMockExecutor) -> Result<(u64, u64, u64, u64), NovaXError> {
+    TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .get_previous_block_infos()
+        .await
+}
+
+#[tokio::test]
+async fn test_default_blocks() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    assert_eq!(executor.get_current_block().await, MockBlockInfos::default());
+    assert_eq!(executor.get_previous_block().await, MockBlockInfos::default());
+    assert_eq!(query_current_block_infos(&executor).await?, (0, 0, 0, 0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_current_and_previous_blocks() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor.set_current_block(MockBlockInfos { timestamp: 1_000, nonce: 10, round: 11, epoch: 1 }).await;
+    executor.set_previous_block(MockBlockInfos { timestamp: 994, nonce: 9, round: 10, epoch: 1 }).await;
+
+    assert_eq!(executor.get_current_block().await, MockBlockInfos { timestamp: 1_000, nonce: 10, round: 11, epoch: 1 });
+    assert_eq!(query_current_block_infos(&executor).await?, (1_000, 10, 11, 1));
+    assert_eq!(query_previous_block_infos(&executor).await?, (994, 9, 10, 1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_advance_blocks() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor.set_current_block(MockBlockInfos { timestamp: 1_000, nonce: 10, round: 14_000, epoch: 1 }).await;
+    executor.advance_blocks(500).await;
+
+    assert_eq!(query_current_block_infos(&executor).await?, (4_000, 510, 14_500, 2));
+    assert_eq!(query_previous_block_infos(&executor).await?, (3_994, 509, 14_499, 2));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_advance_blocks_concurrently() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let tasks: Vec<_> = (0..10)
+        .map(|_| {
+            let executor = executor.clone();
+            tokio::spawn(async move { executor.advance_blocks(10).await })
+        })
+        .collect();
+
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    assert_eq!(query_current_block_infos(&executor).await?, (600, 100, 100, 0));
+    assert_eq!(query_previous_block_infos(&executor).await?, (594, 99, 99, 0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_advance_zero_block() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor.set_current_block(MockBlockInfos { timestamp: 1_000, nonce: 10, round: 11, epoch: 1 }).await;
+    executor.advance_blocks(0).await;
+
+    assert_eq!(query_current_block_infos(&executor).await?, (1_000, 10, 11, 1));
+    assert_eq!(query_previous_block_infos(&executor).await?, (0, 0, 0, 0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_calls_are_executed_in_current_block() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    executor.advance_blocks(10).await;
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor.clone(), 600_000_000)
+        .get_current_block_infos()
+        .await?;
+
+    assert_eq!(result.result, Some((60, 10, 10, 0)));
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use novax::errors::NovaXError;
 use novax_mocking::world::infos::ScenarioWorldInfos;
 use num_bigint::{BigInt, BigUint};
 use novax::tester::tester::{CustomEnum, CustomEnumWithFields, CustomEnumWithValues, CustomStruct, CustomStructWithStructAndVec, TesterContract};
-use novax::executor::{ExecutorError, MockTransactionError, StandardMockExecutor};
+use novax::executor::{ExecutorError, MockTransactionError, StandardMockExecutor, TransactionError, TransactionOnNetworkTransactionLogsEvents};
 use novax_mocking::ScenarioWorld;
 
 const CALLER: &str = "bech32:erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
@@ -16,7 +16,7 @@ fn get_executor() -> Arc<Mutex<StandardMockExecutor>> {
     let infos = ScenarioWorldInfos::from_file(Path::new("tests/data/adder_world_dump.json")).unwrap();
     let world = infos.into_world(|_, code_expr, world| {
         world.register_contract(code_expr, tester_contract::ContractBuilder)
-    });
+    }).unwrap();
 
     let executor = StandardMockExecutor::new(
         Arc::new(Mutex::new(world)),
@@ -30,7 +30,7 @@ fn get_executor_without_caller() -> Arc<Mutex<StandardMockExecutor>> {
     let infos = ScenarioWorldInfos::from_file(Path::new("tests/data/adder_world_dump.json")).unwrap();
     let world = infos.into_world(|_, code_expr, world| {
         world.register_contract(code_expr, tester_contract::ContractBuilder)
-    });
+    }).unwrap();
 
     let executor = StandardMockExecutor::new(
         Arc::new(Mutex::new(world)),
@@ -129,6 +129,84 @@ async fn test_call_with_biguint_argument() -> Result<(), NovaXError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_call_with_sc_error() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .call(executor, 600000000)
+        .return_non_zero_u_64_arg(&0)
+        .await
+        .err()
+        .unwrap();
+
+    let expected = NovaXError::Executor(ExecutorError::Transaction(TransactionError::SmartContractExecutionError {
+        status: 4,
+        message: "arg is zero".to_string(),
+    }));
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_with_sc_error_doesnt_modify_state() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let contract = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    );
+
+    let result = contract
+        .call(executor.clone(), 600000000)
+        .with_egld_value(BigUint::from(10u8))
+        .add(&BigUint::from(10u8))
+        .await;
+
+    assert!(result.is_err());
+
+    let result = contract
+        .query(executor)
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(5u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_emitting_event() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .call(executor, 600000000)
+        .emit_empty_event()
+        .await?;
+
+    assert!(result.response.is_success());
+
+    let events = result.response.transaction.logs.unwrap().events;
+
+    let expected = vec![
+        TransactionOnNetworkTransactionLogsEvents {
+            address: "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p".to_string(),
+            identifier: "emitEmptyEvent".to_string(),
+            topics: vec!["ZW1wdHlFdmVudA==".to_string()],
+            data: None,
+        }
+    ];
+
+    assert_eq!(events, expected);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_call_buffer_result() -> Result<(), NovaXError> {
     let executor = get_executor();
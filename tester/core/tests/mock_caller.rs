@@ -0,0 +1,170 @@
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use num_bigint::BigUint;
+use novax::Address;
+use novax::errors::NovaXError;
+use novax::executor::{ExecutorError, MockAccountError, StandardMockExecutor, TokenTransfer};
+use novax::tester::tester::TesterContract;
+use novax_mocking::world::infos::ScenarioWorldInfos;
+
+const CALLER: &str = "bech32:erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+const TESTER_CONTRACT_ADDRESS: &str = "bech32:erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+const ALICE: &str = "address:alice";
+const BOB: &str = "address:bob";
+
+fn get_executor() -> StandardMockExecutor {
+    let infos = ScenarioWorldInfos::from_file(Path::new("tests/data/adder_world_dump.json")).unwrap();
+    let world = infos.into_world(|_, code_expr, world| {
+        world.register_contract(code_expr, tester_contract::ContractBuilder)
+    }).unwrap();
+
+    StandardMockExecutor::new(
+        Arc::new(Mutex::new(world)),
+        Some(CALLER.to_string())
+    )
+}
+
+fn get_token_transfer(identifier: &str, nonce: u64, amount: u8) -> TokenTransfer {
+    TokenTransfer {
+        identifier: identifier.to_string(),
+        nonce,
+        amount: BigUint::from(amount),
+    }
+}
+
+async fn get_executor_with_users() -> Result<StandardMockExecutor, NovaXError> {
+    let executor = get_executor();
+
+    executor.create_account(
+        &Address::from(ALICE),
+        BigUint::from(10u8),
+        vec![get_token_transfer("TEST-abcdef", 0, 100)]
+    ).await?;
+
+    executor.create_account(
+        &Address::from(BOB),
+        BigUint::from(0u8),
+        vec![get_token_transfer("NFT-abcdef", 3, 1)]
+    ).await?;
+
+    Ok(executor)
+}
+
+#[tokio::test]
+async fn test_as_caller() -> Result<(), NovaXError> {
+    let executor = get_executor_with_users().await?;
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    let alice_result = contract
+        .call(executor.as_caller(ALICE.to_string()), 600_000_000)
+        .return_caller()
+        .await?;
+
+    let bob_result = contract
+        .call(executor.as_caller(BOB.to_string()), 600_000_000)
+        .return_caller()
+        .await?;
+
+    let default_result = contract
+        .call(executor.clone(), 600_000_000)
+        .return_caller()
+        .await?;
+
+    assert_eq!(alice_result.result, Some(Address::from(ALICE)));
+    assert_eq!(bob_result.result, Some(Address::from(BOB)));
+    assert_eq!(default_result.result, Some(Address::from(CALLER)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_as_caller_shares_the_world() -> Result<(), NovaXError> {
+    let executor = get_executor_with_users().await?;
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    contract
+        .call(executor.as_caller(ALICE.to_string()), 600_000_000)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    contract
+        .call(executor.as_caller(BOB.to_string()), 600_000_000)
+        .add(&BigUint::from(20u8))
+        .await?;
+
+    let sum = contract
+        .query(executor)
+        .get_sum()
+        .await?;
+
+    assert_eq!(sum, BigUint::from(35u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_account_with_egld_and_esdt() -> Result<(), NovaXError> {
+    let executor = get_executor_with_users().await?;
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    let egld_result = contract
+        .call(executor.as_caller(ALICE.to_string()), 600_000_000)
+        .with_egld_value(BigUint::from(10u8))
+        .return_egld_payment()
+        .await?;
+
+    let esdt_result = contract
+        .call(executor.as_caller(ALICE.to_string()), 600_000_000)
+        .with_esdt_transfers(&vec![get_token_transfer("TEST-abcdef", 0, 100)])
+        .return_single_esdt_payment_amount()
+        .await?;
+
+    assert_eq!(egld_result.result, Some(BigUint::from(10u8)));
+    assert_eq!(esdt_result.result, Some(BigUint::from(100u8)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_account_with_nft() -> Result<(), NovaXError> {
+    let executor = get_executor_with_users().await?;
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    let result = contract
+        .call(executor.as_caller(BOB.to_string()), 600_000_000)
+        .with_esdt_transfers(&vec![get_token_transfer("NFT-abcdef", 3, 1)])
+        .return_single_esdt_payment_amount()
+        .await?;
+
+    assert_eq!(result.result, Some(BigUint::from(1u8)));
+
+    let second_result = contract
+        .call(executor.as_caller(BOB.to_string()), 600_000_000)
+        .with_esdt_transfers(&vec![get_token_transfer("NFT-abcdef", 3, 1)])
+        .return_single_esdt_payment_amount()
+        .await;
+
+    assert!(second_result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_existing_account() -> Result<(), NovaXError> {
+    let executor = get_executor_with_users().await?;
+
+    let result = executor.create_account(
+        &Address::from(ALICE),
+        BigUint::from(0u8),
+        vec![]
+    ).await.unwrap_err();
+
+    let expected = ExecutorError::MockAccount(MockAccountError::AccountAlreadyExists {
+        address: Address::from(ALICE).to_bech32_string().unwrap(),
+    });
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
@@ -0,0 +1,243 @@
+use std::time::Duration;
+use num_bigint::BigUint;
+use novax::caching::CachingNone;
+use novax::errors::NovaXError;
+use novax::Address;
+use novax::executor::{ExecutorError, MockForkError};
+use novax::tester::tester::TesterContract;
+use novax_mocking::fork::executor::ForkExecutor;
+use novax_mocking::ScenarioWorld;
+use novax_mocking::world::fetch::ScenarioWorldFetchOptions;
+use crate::utils::mock_client::{MockClient, OTHER_TESTER_CONTRACT_ADDRESS, TESTER_CONTRACT_ADDRESS};
+
+mod utils;
+
+const CALLER: &str = "erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+
+fn register(_: [u8; 32], code_expr: &str, world: &mut ScenarioWorld) {
+    world.register_contract(code_expr, tester_contract::ContractBuilder)
+}
+
+fn get_executor(client: MockClient) -> ForkExecutor<MockClient, CachingNone> {
+    ForkExecutor::new(client, CachingNone, register)
+        .with_caller(CALLER.to_string())
+        .with_fetch_options(ScenarioWorldFetchOptions {
+            max_retries: 0,
+            retry_delay: Duration::from_millis(1),
+            ..Default::default()
+        })
+}
+
+#[tokio::test]
+async fn test_fork_query_loads_accounts() -> Result<(), NovaXError> {
+    let client = MockClient::new(false);
+    let executor = get_executor(client.clone());
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(42u8));
+    // The account, the keys and the ESDT balances of both the caller and the contract
+    assert_eq!(client.get_requests_count(), 6);
+    assert_eq!(executor.get_infos().address_infos.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_call_keeps_local_state() -> Result<(), NovaXError> {
+    let client = MockClient::new(false);
+    let executor = get_executor(client.clone());
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    contract
+        .call(executor.clone(), 600_000_000)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    let result = contract
+        .query(executor.clone())
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(52u8));
+    assert_eq!(client.get_requests_count(), 6);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_unavailable_account() -> Result<(), NovaXError> {
+    let executor = get_executor(MockClient::new(true));
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor)
+        .get_sum()
+        .await;
+
+    let is_expected_error = matches!(
+        result,
+        Err(NovaXError::Executor(ExecutorError::MockFork(MockForkError::UnableToLoadAccount { .. })))
+    );
+
+    assert!(is_expected_error);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_infos_file_allows_offline_runs() -> Result<(), NovaXError> {
+    let file_path = std::env::temp_dir().join("novax_mock_fork_test.json");
+    let _ = std::fs::remove_file(&file_path);
+
+    let online_executor = get_executor(MockClient::new(false))
+        .with_infos_file(&file_path)
+        .unwrap();
+
+    let online_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(online_executor)
+        .get_sum()
+        .await?;
+
+    let offline_client = MockClient::new(true);
+    let offline_executor = get_executor(offline_client.clone())
+        .with_infos_file(&file_path)
+        .unwrap();
+
+    let offline_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(offline_executor)
+        .get_sum()
+        .await?;
+
+    std::fs::remove_file(&file_path).unwrap();
+
+    assert_eq!(online_result, BigUint::from(42u8));
+    assert_eq!(offline_result, BigUint::from(42u8));
+    assert_eq!(offline_client.get_requests_count(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_query_loads_contracts_passed_as_arguments() -> Result<(), NovaXError> {
+    let client = MockClient::new(false);
+    let executor = get_executor(client.clone());
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .call_another_contract_return_two_u_64(&Address::from(OTHER_TESTER_CONTRACT_ADDRESS))
+        .await?;
+
+    assert_eq!(result, (10, 9000000000));
+    assert_eq!(executor.get_infos().address_infos.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_call_to_missing_account() -> Result<(), NovaXError> {
+    let executor = get_executor(MockClient::new(false));
+
+    // A smart contract address having no code on the network
+    let mut undeployed_contract_bytes = [1u8; 32];
+    undeployed_contract_bytes[..10].copy_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 5, 0]);
+    let undeployed_contract = Address::from_bytes(undeployed_contract_bytes);
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .call(executor, 600_000_000)
+        .call_another_contract_return_two_u_64(&undeployed_contract)
+        .await;
+
+    let Err(error) = result else { panic!("the call should fail") };
+
+    let expected = NovaXError::Executor(ExecutorError::MockFork(MockForkError::AccountNotLoaded { address: undeployed_contract.to_bech32_string()? }));
+
+    assert_eq!(error, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_query_to_missing_account() -> Result<(), NovaXError> {
+    let executor = get_executor(MockClient::new(false));
+
+    let mut undeployed_contract_bytes = [1u8; 32];
+    undeployed_contract_bytes[..10].copy_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 5, 0]);
+    let undeployed_contract = Address::from_bytes(undeployed_contract_bytes);
+
+    let result = TesterContract::new(undeployed_contract.to_bech32_string()?)
+        .query(executor)
+        .get_sum()
+        .await;
+
+    let expected = NovaXError::Executor(ExecutorError::MockFork(MockForkError::AccountNotLoaded { address: undeployed_contract.to_bech32_string()? }));
+
+    assert_eq!(result, Err(expected));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_executor_usable_after_missing_account() -> Result<(), NovaXError> {
+    let executor = get_executor(MockClient::new(false));
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    let mut undeployed_contract_bytes = [1u8; 32];
+    undeployed_contract_bytes[..10].copy_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 5, 0]);
+    let undeployed_contract = Address::from_bytes(undeployed_contract_bytes);
+
+    let failed_result = contract
+        .call(executor.clone(), 600_000_000)
+        .call_another_contract_return_two_u_64(&undeployed_contract)
+        .await;
+
+    let is_expected_error = matches!(
+        failed_result,
+        Err(NovaXError::Executor(ExecutorError::MockFork(MockForkError::AccountNotLoaded { .. })))
+    );
+
+    assert!(is_expected_error);
+
+    // The failed call neither poisoned the world nor changed its state
+    contract
+        .call(executor.clone(), 600_000_000)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    let result = contract
+        .query(executor.clone())
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(52u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fork_restore_reloads_accounts() -> Result<(), NovaXError> {
+    let client = MockClient::new(false);
+    let executor = get_executor(client.clone());
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    let snapshot = executor.snapshot().await;
+
+    contract
+        .call(executor.clone(), 600_000_000)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    executor.restore(snapshot).await?;
+
+    let result = contract
+        .query(executor.clone())
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(42u8));
+    assert_eq!(client.get_requests_count(), 6);
+
+    Ok(())
+}
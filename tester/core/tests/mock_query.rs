@@ -3,15 +3,17 @@ use std::sync::Arc;
 use base64::Engine;
 use tokio::sync::Mutex;
 use novax::errors::NovaXError;
+use novax_mocking::{Account, ScenarioWorld, SetStateStep};
 use novax_mocking::world::infos::{ScenarioWorldInfosEsdtTokenAmount, ScenarioWorldInfos};
 use num_bigint::{BigInt, BigUint};
 use novax::Address;
 use novax::tester::tester::{CustomEnum, CustomEnumWithFields, CustomEnumWithValues, CustomStruct, CustomStructWithStructAndVec, TesterContract, TestTokenProperties};
-use novax::executor::StandardMockExecutor;
+use novax::executor::{ExecutorError, MockQueryError, StandardMockExecutor, TokenTransfer};
 
 const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+const CALLER: &str = "bech32:erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
 
-fn get_executor() -> Arc<StandardMockExecutor> {
+fn get_world() -> ScenarioWorld {
     let mut infos = ScenarioWorldInfos::from_file(Path::new("tests/data/adder_world_dump.json")).unwrap();
 
     let tester_contract_address_bytes = Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap().to_bytes();
@@ -43,18 +45,41 @@ fn get_executor() -> Arc<StandardMockExecutor> {
         tester_contract_balances
     );
 
-    let world = infos.into_world(|_, code_expr, world| {
+    infos.into_world(|_, code_expr, world| {
         world.register_contract(code_expr, tester_contract::ContractBuilder)
-    });
+    }).unwrap()
+}
 
+fn get_executor() -> Arc<StandardMockExecutor> {
     let executor = StandardMockExecutor::new(
-        Arc::new(Mutex::new(world)),
+        Arc::new(Mutex::new(get_world())),
         None
     );
 
     Arc::new(executor)
 }
 
+fn get_executor_with_funded_caller() -> Arc<StandardMockExecutor> {
+    let mut world = get_world();
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(
+                CALLER,
+                Account::new()
+                    .balance(1000u64)
+                    .esdt_balance("str:TEST-abcdef", 100u64)
+            )
+    );
+
+    let executor = StandardMockExecutor::new(
+        Arc::new(Mutex::new(world)),
+        Some(CALLER.to_string())
+    );
+
+    Arc::new(executor)
+}
+
 #[tokio::test]
 async fn test_simple_query() -> Result<(), NovaXError> {
     let executor = get_executor();
@@ -71,6 +96,26 @@ async fn test_simple_query() -> Result<(), NovaXError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_simple_query_at_block_nonce() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_block_nonce(10)
+        .get_sum()
+        .await
+        .unwrap_err();
+
+    let expected = NovaXError::Executor(ExecutorError::MockQuery(MockQueryError::HistoricalQueryNotSupported));
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_simple_query_with_argument() -> Result<(), NovaXError> {
     let executor = get_executor();
@@ -108,6 +153,169 @@ async fn test_query_doesnt_modify_state() -> Result<(), NovaXError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_query_return_caller_no_caller_set() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .return_caller()
+        .await?;
+
+    assert_eq!(result, Address::from_bech32_string(TESTER_CONTRACT_ADDRESS)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_return_caller_with_executor_caller() -> Result<(), NovaXError> {
+    let executor = get_executor_with_funded_caller();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .return_caller()
+        .await?;
+
+    assert_eq!(result, Address::from(CALLER));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_return_caller_with_caller() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_caller(&Address::from(CALLER))
+        .return_caller()
+        .await?;
+
+    assert_eq!(result, Address::from(CALLER));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_with_egld_value() -> Result<(), NovaXError> {
+    let executor = get_executor_with_funded_caller();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_egld_value(BigUint::from(10u8))
+        .return_egld_payment()
+        .await?;
+
+    assert_eq!(result, BigUint::from(10u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_with_esdt_transfers() -> Result<(), NovaXError> {
+    let executor = get_executor_with_funded_caller();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_esdt_transfers(&vec![
+            TokenTransfer {
+                identifier: "TEST-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(10u8),
+            }
+        ])
+        .return_single_esdt_payment_amount()
+        .await?;
+
+    assert_eq!(result, BigUint::from(10u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_with_esdt_transfers_doesnt_modify_state() -> Result<(), NovaXError> {
+    let executor = get_executor_with_funded_caller();
+
+    let contract = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    );
+
+    for _ in 0..2 {
+        contract
+            .query(executor.clone())
+            .with_esdt_transfers(&vec![
+                TokenTransfer {
+                    identifier: "TEST-abcdef".to_string(),
+                    nonce: 0,
+                    amount: BigUint::from(100u8),
+                }
+            ])
+            .return_single_esdt_payment_amount()
+            .await?;
+    }
+
+    let result = contract
+        .query(executor)
+        .return_fungible_balance()
+        .await?;
+
+    assert_eq!(result, BigUint::from(25u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_with_egld_value_non_payable_endpoint() -> Result<(), NovaXError> {
+    let executor = get_executor_with_funded_caller();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_egld_value(BigUint::from(10u8))
+        .return_caller()
+        .await
+        .unwrap_err();
+
+    let expected = NovaXError::Executor(ExecutorError::MockQuery(MockQueryError::ExecutionFailed { status: 10, message: "function does not accept EGLD payment".to_string() }));
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_with_egld_value_insufficient_funds() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_caller(&Address::from(CALLER))
+        // More than the cloned balance of the caller
+        .with_egld_value(BigUint::from(10u8).pow(21))
+        .return_egld_payment()
+        .await
+        .unwrap_err();
+
+    let expected = NovaXError::Executor(ExecutorError::MockQuery(MockQueryError::ExecutionFailed { status: 10, message: "failed transfer (insufficient funds)".to_string() }));
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_query_sc_fungible_balance() -> Result<(), NovaXError> {
     let executor = get_executor();
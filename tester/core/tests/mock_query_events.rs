@@ -0,0 +1,222 @@
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use novax::Address;
+use novax::errors::NovaXError;
+use novax::executor::{EventQueryOptions, EventQueryResult, QueryEventsSortOptions, SortOption, StandardMockExecutor, TimestampOption};
+use novax::tester::tester::{EmptyEventEventQueryResult, EventWithMultiValueEventFilterOptions, EventWithMultiValueEventQueryResult, TesterContract};
+use novax_mocking::world::infos::ScenarioWorldInfos;
+use novax_mocking::ScenarioWorld;
+use num_bigint::BigUint;
+
+const CALLER: &str = "bech32:erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+const TESTER_CONTRACT_ADDRESS: &str = "bech32:erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+const FIRST_VALUE_ADDRESS: &str = "erd1n7ed3f6rkqvwkpfevulvhyl4hskx2vqyleed5lqfq9jp2csfw8esg88f5g";
+
+fn get_world() -> Arc<Mutex<ScenarioWorld>> {
+    let infos = ScenarioWorldInfos::from_file(Path::new("tests/data/adder_world_dump.json")).unwrap();
+    let world = infos.into_world(|_, code_expr, world| {
+        world.register_contract(code_expr, tester_contract::ContractBuilder)
+    }).unwrap();
+
+    Arc::new(Mutex::new(world))
+}
+
+async fn set_block_timestamp(world: &Arc<Mutex<ScenarioWorld>>, timestamp: u64) {
+    world.lock().await
+        .current_block()
+        .block_timestamp(timestamp);
+}
+
+async fn emit_event_with_multi_value(executor: &Arc<Mutex<StandardMockExecutor>>, second_value: u8, data: u8) -> Result<(), NovaXError> {
+    TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .call(executor.clone(), 600_000_000)
+        .emit_event_with_multi_value(
+            &Address::from_bech32_string(FIRST_VALUE_ADDRESS).unwrap(),
+            &"WEGLD-a28c59".to_string(),
+            &"USDC-350c4e".to_string(),
+            &BigUint::from(second_value),
+            &BigUint::from(data),
+            &vec![BigUint::from(1u8), BigUint::from(2u8)]
+        )
+        .await?;
+
+    Ok(())
+}
+
+fn get_event_with_multi_value_result(timestamp: u64, second_value: u8, data: u8) -> EventQueryResult<EventWithMultiValueEventQueryResult> {
+    EventQueryResult {
+        timestamp,
+        event: EventWithMultiValueEventQueryResult {
+            first_value: Address::from_bech32_string(FIRST_VALUE_ADDRESS).unwrap(),
+            multi_value2: ("WEGLD-a28c59".to_string(), "USDC-350c4e".to_string()),
+            second_value: BigUint::from(second_value),
+            values: vec![BigUint::from(1u8), BigUint::from(2u8)],
+            data: BigUint::from(data)
+        },
+    }
+}
+
+/// Emits the events with the second values 10, 20 and 10 and the datas 1, 2 and 3, at the timestamps 100, 200 and 300.
+async fn get_executor_with_events() -> Result<Arc<Mutex<StandardMockExecutor>>, NovaXError> {
+    let world = get_world();
+    let executor = Arc::new(Mutex::new(StandardMockExecutor::new(
+        world.clone(),
+        Some(CALLER.to_string())
+    )));
+
+    for (timestamp, second_value, data) in [(100, 10, 1), (200, 20, 2), (300, 10, 3)] {
+        set_block_timestamp(&world, timestamp).await;
+        emit_event_with_multi_value(&executor, second_value, data).await?;
+    }
+
+    Ok(executor)
+}
+
+#[tokio::test]
+async fn test_query_empty_events_no_filter_no_options() -> Result<(), NovaXError> {
+    let world = get_world();
+    set_block_timestamp(&world, 100).await;
+
+    let executor = Arc::new(Mutex::new(StandardMockExecutor::new(
+        world,
+        Some(CALLER.to_string())
+    )));
+
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+    contract.call(executor.clone(), 600_000_000).emit_empty_event().await?;
+    contract.call(executor.clone(), 600_000_000).emit_empty_event().await?;
+
+    let result = contract
+        .query_events(executor.clone())
+        .empty_event(
+            None,
+            None
+        )
+        .await?;
+
+    let expected = vec![
+        EventQueryResult {
+            timestamp: 100,
+            event: EmptyEventEventQueryResult {},
+        },
+        EventQueryResult {
+            timestamp: 100,
+            event: EmptyEventEventQueryResult {},
+        }
+    ];
+
+    assert_eq!(result, expected);
+
+    let other_contract_result = TesterContract::new("bech32:erd1qqqqqqqqqqqqqpgqtqfhy99su9xzjjrq59kpzpp25udtc9eq0n4sr90ax6")
+        .query_events(executor)
+        .empty_event(
+            None,
+            None
+        )
+        .await?;
+
+    assert!(other_contract_result.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_events_with_multi_value_no_filter_no_options() -> Result<(), NovaXError> {
+    let executor = get_executor_with_events().await?;
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query_events(executor)
+        .event_with_multi_value(
+            None,
+            None
+        )
+        .await?;
+
+    let expected = vec![
+        get_event_with_multi_value_result(100, 10, 1),
+        get_event_with_multi_value_result(200, 20, 2),
+        get_event_with_multi_value_result(300, 10, 3),
+    ];
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_events_with_multi_value_with_filter() -> Result<(), NovaXError> {
+    let executor = get_executor_with_events().await?;
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query_events(executor)
+        .event_with_multi_value(
+            None,
+            Some(
+                EventWithMultiValueEventFilterOptions {
+                    first_value: Some(Address::from_bech32_string(FIRST_VALUE_ADDRESS).unwrap()),
+                    multi_value2: Some(("WEGLD-a28c59".to_string(), "USDC-350c4e".to_string())),
+                    second_value: Some(BigUint::from(10u8)),
+                    values: None
+                }
+            )
+        )
+        .await?;
+
+    let expected = vec![
+        get_event_with_multi_value_result(100, 10, 1),
+        get_event_with_multi_value_result(300, 10, 3),
+    ];
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_events_with_multi_value_with_all_options() -> Result<(), NovaXError> {
+    let executor = get_executor_with_events().await?;
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query_events(executor)
+        .event_with_multi_value(
+            Some(
+                EventQueryOptions {
+                    from: Some(1),
+                    size: Some(1),
+                    timestamp: Some(TimestampOption::GreaterThanOrEqual(200)),
+                    sort: Some(QueryEventsSortOptions { timestamp: Some(SortOption::Descending) }),
+                }
+            ),
+            None
+        )
+        .await?;
+
+    let expected = vec![
+        get_event_with_multi_value_result(200, 20, 2),
+    ];
+
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_events_shared_between_clones() -> Result<(), NovaXError> {
+    let executor = get_executor_with_events().await?;
+    let cloned_executor = executor.lock().await.clone();
+
+    let result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query_events(cloned_executor)
+        .event_with_multi_value(
+            None,
+            None
+        )
+        .await?;
+
+    assert_eq!(result.len(), 3);
+
+    Ok(())
+}
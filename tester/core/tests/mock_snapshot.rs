@@ -0,0 +1,187 @@
+use std::sync::Arc;
+use num_bigint::BigUint;
+use tokio::sync::Mutex;
+use novax::code::DeployData;
+use novax::Address;
+use novax::errors::NovaXError;
+use novax::tester::tester::TesterContract;
+use novax::executor::{ExecutorError, MockBlockInfos, MockSnapshotError, MockSnapshotId, StandardMockExecutor, TokenTransfer};
+use novax::tester::tester::EmptyEventEventQueryResult;
+use novax_mocking::{Account, ScenarioWorld, SetStateStep};
+
+const CALLER: &str = "address:caller";
+const CONTRACT: &str = "sc:tester";
+const OTHER_CONTRACT: &str = "sc:other-tester";
+
+fn get_world() -> Arc<Mutex<ScenarioWorld>> {
+    let mut world = ScenarioWorld::new();
+    world.register_contract("file:../../.novax/tester-contract.wasm", tester_contract::ContractBuilder);
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(
+                CALLER,
+                Account::new()
+                    .nonce(0)
+                    .esdt_balance("str:TEST-abcdef", 100u64)
+            )
+            .new_address(CALLER, 0, CONTRACT)
+            .new_address(CALLER, 1, OTHER_CONTRACT)
+    );
+
+    Arc::new(Mutex::new(world))
+}
+
+async fn deploy(executor: &StandardMockExecutor) -> Result<TesterContract<Address>, NovaXError> {
+    let deploy_data = DeployData {
+        code: "../../.novax/tester-contract.wasm",
+        metadata: Default::default(),
+    };
+
+    let (address, _) = TesterContract::deploy(
+        deploy_data,
+        &mut executor.clone(),
+        BigUint::from(0u8),
+        600000000u64,
+        &BigUint::from(5u8)
+    )
+        .await?;
+
+    Ok(TesterContract::new(address))
+}
+
+/// Returns an executor with a deployed tester contract, and a snapshot of its world.
+async fn get_executor_with_snapshot() -> Result<(StandardMockExecutor, TesterContract<Address>, MockSnapshotId), NovaXError> {
+    let executor = StandardMockExecutor::new(
+        get_world(),
+        Some(CALLER.to_string())
+    );
+
+    let contract = deploy(&executor).await?;
+    let snapshot_id = executor.snapshot().await;
+
+    Ok((executor, contract, snapshot_id))
+}
+
+fn get_test_transfer(amount: u8) -> TokenTransfer {
+    TokenTransfer {
+        identifier: "TEST-abcdef".to_string(),
+        nonce: 0,
+        amount: BigUint::from(amount),
+    }
+}
+
+async fn modify_world(executor: &StandardMockExecutor, contract: &TesterContract<Address>) -> Result<(), NovaXError> {
+    contract.clone()
+        .call(executor.clone(), 600_000_000)
+        .add(&BigUint::from(10u8))
+        .await?;
+
+    contract.clone()
+        .call(executor.clone(), 600_000_000)
+        .with_esdt_transfers(&vec![get_test_transfer(30)])
+        .return_single_esdt_payment_amount()
+        .await?;
+
+    contract.clone()
+        .call(executor.clone(), 600_000_000)
+        .emit_empty_event()
+        .await?;
+
+    executor.advance_blocks(10).await;
+
+    Ok(())
+}
+
+async fn query_empty_events(executor: &StandardMockExecutor, contract: &TesterContract<Address>) -> Result<usize, NovaXError> {
+    let events: Vec<_> = contract.clone()
+        .query_events(executor.clone())
+        .empty_event(None, None)
+        .await?;
+
+    Ok(events.into_iter().filter(|event| event.event == EmptyEventEventQueryResult {}).count())
+}
+
+#[tokio::test]
+async fn test_restore() -> Result<(), NovaXError> {
+    let (executor, contract, snapshot_id) = get_executor_with_snapshot().await?;
+
+    modify_world(&executor, &contract).await?;
+
+    assert_eq!(contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(15u8));
+    assert_eq!(query_empty_events(&executor, &contract).await?, 1);
+
+    executor.restore(snapshot_id).await?;
+
+    assert_eq!(contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(5u8));
+    assert_eq!(query_empty_events(&executor, &contract).await?, 0);
+    assert_eq!(executor.get_current_block().await, MockBlockInfos::default());
+
+    let all_esdt_payment_amount = contract.clone()
+        .query(executor.clone())
+        .with_esdt_transfers(&vec![get_test_transfer(100)])
+        .return_single_esdt_payment_amount()
+        .await?;
+
+    assert_eq!(all_esdt_payment_amount, BigUint::from(100u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_twice() -> Result<(), NovaXError> {
+    let (executor, contract, snapshot_id) = get_executor_with_snapshot().await?;
+
+    modify_world(&executor, &contract).await?;
+    executor.restore(snapshot_id).await?;
+    modify_world(&executor, &contract).await?;
+
+    assert_eq!(contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(15u8));
+
+    executor.restore(snapshot_id).await?;
+
+    assert_eq!(contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(5u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_from_clone() -> Result<(), NovaXError> {
+    let (executor, contract, snapshot_id) = get_executor_with_snapshot().await?;
+
+    modify_world(&executor, &contract).await?;
+    executor.clone().restore(snapshot_id).await?;
+
+    assert_eq!(contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(5u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_empties_contracts_deployed_after_snapshot() -> Result<(), NovaXError> {
+    let (executor, contract, snapshot_id) = get_executor_with_snapshot().await?;
+
+    let other_contract = deploy(&executor).await?;
+    assert_eq!(other_contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(5u8));
+
+    executor.restore(snapshot_id).await?;
+
+    assert_eq!(other_contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(0u8));
+    assert_eq!(contract.clone().query(executor.clone()).get_sum().await?, BigUint::from(5u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_restore_unknown_snapshot() -> Result<(), NovaXError> {
+    let executor = StandardMockExecutor::new(
+        get_world(),
+        Some(CALLER.to_string())
+    );
+
+    let result = executor.restore(MockSnapshotId(0)).await.unwrap_err();
+
+    assert_eq!(result, ExecutorError::MockSnapshot(MockSnapshotError::UnknownSnapshot { id: 0 }));
+
+    Ok(())
+}
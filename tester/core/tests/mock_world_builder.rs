@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use multiversx_sc_scenario::imports::StaticApi;
+use multiversx_sc_scenario::multiversx_sc::types::{BigUint as ManagedBigUint, EsdtLocalRole};
+use tokio::sync::Mutex;
+use num_bigint::BigUint;
+use novax::Address;
+use novax::errors::NovaXError;
+use novax::executor::{StandardMockExecutor, TokenTransfer};
+use novax::tester::tester::{TestTokenProperties, TesterContract};
+use novax_mocking::ScenarioWorld;
+use novax_mocking::world::builder::{ScenarioWorldAccountBuilder, ScenarioWorldBuilder};
+use novax_mocking::world::infos::ScenarioWorldInfos;
+
+const CALLER: &str = "erd1h4uhy73dev6qrfj7wxsguapzs8632mfwqjswjpsj6kzm2jfrnslqsuduqu";
+const OWNER: &str = "erd17ytgf5z28rqmpjyvv0qzgaapufpu08fqygaxjuu79uj5uhe66vpsd230j2";
+const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+
+fn get_world_builder() -> ScenarioWorldBuilder {
+    let code = std::fs::read("../../.novax/tester-contract.wasm").unwrap();
+
+    ScenarioWorldBuilder::new()
+        .account(
+            ScenarioWorldAccountBuilder::new(&Address::from(CALLER))
+                .egld_balance(BigUint::from(100u8))
+                .esdt_balance("TEST-abcdef", BigUint::from(50u8))
+        )
+        .account(
+            ScenarioWorldAccountBuilder::new(&Address::from(TESTER_CONTRACT_ADDRESS))
+                .code(code, &Address::from(OWNER))
+                .storage::<ManagedBigUint<StaticApi>, _>("sum", &BigUint::from(42u8))
+                .esdt_balance("TEST-abcdef", BigUint::from(25u8))
+                .nft_balance_with_attributes(
+                    "NFT-abcdef",
+                    6,
+                    BigUint::from(1u8),
+                    &TestTokenProperties {
+                        buffer: "test buffer".to_string(),
+                        integer: BigUint::from(10u8),
+                    }
+                )
+        )
+}
+
+fn get_executor(world: ScenarioWorld) -> StandardMockExecutor {
+    StandardMockExecutor::new(
+        Arc::new(Mutex::new(world)),
+        Some(CALLER.to_string())
+    )
+}
+
+fn register(_: [u8; 32], code_expr: &str, world: &mut ScenarioWorld) {
+    world.register_contract(code_expr, tester_contract::ContractBuilder)
+}
+
+async fn assert_world(executor: StandardMockExecutor) -> Result<(), NovaXError> {
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    assert_eq!(contract.query(executor.clone()).get_sum().await?, BigUint::from(42u8));
+    assert_eq!(contract.query(executor.clone()).return_fungible_balance().await?, BigUint::from(25u8));
+    assert_eq!(contract.query(executor.clone()).return_non_fungible_balance().await?, BigUint::from(1u8));
+
+    let expected_properties = TestTokenProperties {
+        buffer: "test buffer".to_string(),
+        integer: BigUint::from(10u8),
+    };
+
+    assert_eq!(contract.query(executor.clone()).return_nft_properties().await?, expected_properties);
+
+    let egld_result = contract
+        .call(executor.clone(), 600_000_000)
+        .with_egld_value(BigUint::from(100u8))
+        .return_egld_payment()
+        .await?;
+
+    assert_eq!(egld_result.result, Some(BigUint::from(100u8)));
+
+    let esdt_result = contract
+        .call(executor, 600_000_000)
+        .with_esdt_transfers(&vec![
+            TokenTransfer {
+                identifier: "TEST-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(50u8),
+            }
+        ])
+        .return_single_esdt_payment_amount()
+        .await?;
+
+    assert_eq!(esdt_result.result, Some(BigUint::from(50u8)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_into_world() -> Result<(), NovaXError> {
+    let world = get_world_builder().into_world(register).unwrap();
+
+    assert_world(get_executor(world)).await
+}
+
+#[tokio::test]
+async fn test_builder_infos_saved_into_file() -> Result<(), NovaXError> {
+    let file_path = std::env::temp_dir().join("novax_mock_world_builder_test.json");
+
+    get_world_builder()
+        .into_infos()
+        .unwrap()
+        .save_into_file(&file_path)
+        .unwrap();
+
+    let world = ScenarioWorldInfos::from_file(&file_path)
+        .unwrap()
+        .into_world(register)
+        .unwrap();
+
+    std::fs::remove_file(&file_path).unwrap();
+
+    assert_world(get_executor(world)).await
+}
+
+#[tokio::test]
+async fn test_builder_esdt_roles() -> Result<(), NovaXError> {
+    let world = get_world_builder()
+        .account(
+            ScenarioWorldAccountBuilder::new(&Address::from(TESTER_CONTRACT_ADDRESS))
+                .code(std::fs::read("../../.novax/tester-contract.wasm").unwrap(), &Address::from(OWNER))
+                .esdt_roles("TEST-abcdef", &[EsdtLocalRole::Mint, EsdtLocalRole::Burn])
+        )
+        .into_world(register)
+        .unwrap();
+
+    let executor = get_executor(world);
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    assert!(contract.query(executor.clone()).has_local_mint_role(&"TEST-abcdef".to_string()).await?);
+    assert!(!contract.query(executor).has_local_mint_role(&"OTHER-abcdef".to_string()).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_infos_saved_into_scenario_file() -> Result<(), NovaXError> {
+    let file_path = std::env::temp_dir().join("novax_mock_world_builder_test.scen.json");
+    let code_path = std::fs::canonicalize("../../.novax/tester-contract.wasm").unwrap();
+
+    get_world_builder()
+        .into_infos()
+        .unwrap()
+        .save_into_scenario_file(&file_path, |_, _| Some(format!("file:{}", code_path.display())))
+        .unwrap();
+
+    let scenario = std::fs::read_to_string(&file_path).unwrap();
+
+    let world = ScenarioWorldInfos::from_scenario_file(&file_path)
+        .unwrap()
+        .into_world(register)
+        .unwrap();
+
+    std::fs::remove_file(&file_path).unwrap();
+
+    assert!(scenario.contains("\"step\": \"setState\""));
+    assert!(scenario.contains(&format!("\"code\": \"file:{}\"", code_path.display())));
+    assert!(scenario.contains("\"str:TEST-abcdef\""));
+
+    assert_world(get_executor(world)).await
+}
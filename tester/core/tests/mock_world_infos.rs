@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use novax::Address;
+use novax::errors::NovaXError;
+use novax::executor::StandardMockExecutor;
+use novax::tester::tester::TesterContract;
+use novax_mocking::ScenarioWorld;
+use novax_mocking::world::infos::ScenarioWorldInfos;
+use crate::utils::mock_client::{MockClient, TESTER_CONTRACT_ADDRESS};
+
+mod utils;
+
+fn register(_: [u8; 32], code_expr: &str, world: &mut ScenarioWorld) {
+    world.register_contract(code_expr, tester_contract::ContractBuilder)
+}
+
+#[tokio::test]
+async fn test_fetched_esdt_roles_applied_by_into_world() -> Result<(), NovaXError> {
+    let client = MockClient::new(false);
+
+    let mut infos = ScenarioWorldInfos::fetch(&client, &[Address::from(TESTER_CONTRACT_ADDRESS)]).await.unwrap();
+    infos.fetch_esdt_system_state(&client).await.unwrap();
+
+    let world = infos.into_world(register).unwrap();
+    let executor = StandardMockExecutor::new(Arc::new(Mutex::new(world)), None);
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    assert!(contract.query(executor.clone()).has_local_mint_role(&"TEST-abcdef".to_string()).await?);
+    assert!(!contract.query(executor).has_local_mint_role(&"OTHER-abcdef".to_string()).await?);
+
+    Ok(())
+}
@@ -3,13 +3,21 @@ use async_trait::async_trait;
 use novax::errors::NovaXError;
 use num_bigint::{BigInt, BigUint};
 use novax::tester::tester::{CustomEnum, CustomEnumWithFields, CustomEnumWithValues, CustomStruct, CustomStructWithStructAndVec, TesterContract};
-use novax::executor::{BlockchainProxy, ExecutorError, QueryNetworkExecutor, VmValuesQueryRequest, VmValuesQueryResponseData, VmValuesQueryResponseDataData};
+use novax::caching::CachingDurationStrategy;
+use novax::Address;
+use novax::executor::{BlockchainProxy, ExecutorError, QueryBlock, QueryNetworkExecutor, QuerySession, TokenTransfer, VmValuesQueryRequest, VmValuesQueryResponseData, VmValuesQueryResponseDataData};
+use novax_caching::local::caching_local::CachingLocal;
 
 const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+const CALLER_ADDRESS: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
 
 #[derive(Clone)]
 struct MockProxy;
 
+fn get_tester_contract_hex() -> String {
+    hex::encode(Address::from_bech32_string(TESTER_CONTRACT_ADDRESS).unwrap().to_bytes())
+}
+
 #[async_trait]
 impl BlockchainProxy for MockProxy {
     fn new(_gateway_url: String) -> Self {
@@ -18,7 +26,25 @@ impl BlockchainProxy for MockProxy {
 
     async fn execute_vmquery(&self, vm_request: &VmValuesQueryRequest) -> Result<VmValuesQueryResponseData, ExecutorError> {
         let mut return_data: Option<Vec<String>> = None;
-        if vm_request.func_name == "getSum" {
+        if vm_request.func_name == "getSum" && vm_request.block == Some(QueryBlock::Nonce(10)) {
+            return_data = Some(vec!["Aw==".to_string()]);
+        } else if vm_request.func_name == "getSum" && vm_request.block == Some(QueryBlock::Hash("abcd".to_string())) {
+            return_data = Some(vec!["BA==".to_string()]);
+        } else if vm_request.func_name == "getSum" && vm_request.caller == Some(CALLER_ADDRESS.to_string()) {
+            return_data = Some(vec!["Bg==".to_string()]);
+        } else if vm_request.func_name == "ESDTTransfer" && vm_request.sc_address == TESTER_CONTRACT_ADDRESS {
+            if vm_request.args == vec!["544553542d616263646566".to_string(), "0a".to_string(), "67657453756d".to_string()] {
+                return_data = Some(vec!["Bw==".to_string()]);
+            }
+        } else if vm_request.func_name == "MultiESDTNFTTransfer" && vm_request.sc_address == TESTER_CONTRACT_ADDRESS && vm_request.caller == Some(CALLER_ADDRESS.to_string()) {
+            if vm_request.args.first() == Some(&get_tester_contract_hex()) && vm_request.args.last() == Some(&"67657453756d".to_string()) {
+                return_data = Some(vec!["CA==".to_string()]);
+            }
+        } else if vm_request.func_name == "ESDTNFTTransfer" && vm_request.sc_address == TESTER_CONTRACT_ADDRESS && vm_request.caller == Some(CALLER_ADDRESS.to_string()) {
+            if vm_request.args == vec!["4e46542d616263646566".to_string(), "01".to_string(), "01".to_string(), get_tester_contract_hex(), "67657453756d".to_string()] {
+                return_data = Some(vec!["CQ==".to_string()]);
+            }
+        } else if vm_request.func_name == "getSum" {
             return_data = Some(vec!["BQ==".to_string()]);
         } else if vm_request.func_name == "add" {
             if vm_request.args == vec!["0a"] {
@@ -155,6 +181,237 @@ async fn test_simple_query() -> Result<(), NovaXError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_simple_query_at_block_nonce() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_block_nonce(10)
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(3u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_at_block_hash() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_block_hash("abcd".to_string())
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(4u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_at_blocks_cached_separately() -> Result<(), NovaXError> {
+    let executor = get_executor();
+    let caching = CachingLocal::empty(CachingDurationStrategy::EachBlock);
+
+    let latest_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .with_caching_strategy(&caching)
+        .get_sum()
+        .await?;
+
+    let block_nonce_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .with_caching_strategy(&caching)
+        .with_block_nonce(10)
+        .get_sum()
+        .await?;
+
+    let block_hash_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor)
+        .with_caching_strategy(&caching)
+        .with_block_hash("abcd".to_string())
+        .get_sum()
+        .await?;
+
+    assert_eq!(latest_result, BigUint::from(5u8));
+    assert_eq!(block_nonce_result, BigUint::from(3u8));
+    assert_eq!(block_hash_result, BigUint::from(4u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_in_session_cached_separately() -> Result<(), NovaXError> {
+    let executor = get_executor();
+    let caching = CachingLocal::empty(CachingDurationStrategy::EachBlock);
+
+    let latest_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .with_caching_strategy(&caching)
+        .get_sum()
+        .await?;
+
+    let session_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(QuerySession::new(executor, QueryBlock::Nonce(10)))
+        .with_caching_strategy(&caching)
+        .get_sum()
+        .await?;
+
+    assert_eq!(latest_result, BigUint::from(5u8));
+    assert_eq!(session_result, BigUint::from(3u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_with_caller() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_caller(&Address::from_bech32_string(CALLER_ADDRESS)?)
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(6u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_with_esdt_transfers() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_esdt_transfers(&vec![
+            TokenTransfer {
+                identifier: "TEST-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(10u8),
+            }
+        ])
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(7u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_with_caller_and_multi_esdt_transfers() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_caller(&Address::from_bech32_string(CALLER_ADDRESS)?)
+        .with_esdt_transfers(&vec![
+            TokenTransfer {
+                identifier: "TEST-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(10u8),
+            },
+            TokenTransfer {
+                identifier: "OTHER-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(20u8),
+            }
+        ])
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(8u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_with_caller_egld_value_and_esdt_transfer() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_caller(&Address::from_bech32_string(CALLER_ADDRESS)?)
+        .with_egld_value(BigUint::from(5u8))
+        .with_esdt_transfers(&vec![
+            TokenTransfer {
+                identifier: "TEST-abcdef".to_string(),
+                nonce: 0,
+                amount: BigUint::from(10u8),
+            }
+        ])
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(8u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_with_caller_and_nft_transfer() -> Result<(), NovaXError> {
+    let executor = get_executor();
+
+    let result = TesterContract::new(
+        TESTER_CONTRACT_ADDRESS
+    )
+        .query(executor)
+        .with_caller(&Address::from_bech32_string(CALLER_ADDRESS)?)
+        .with_esdt_transfers(&vec![
+            TokenTransfer {
+                identifier: "NFT-abcdef".to_string(),
+                nonce: 1,
+                amount: BigUint::from(1u8),
+            }
+        ])
+        .get_sum()
+        .await?;
+
+    assert_eq!(result, BigUint::from(9u8));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_query_with_callers_cached_separately() -> Result<(), NovaXError> {
+    let executor = get_executor();
+    let caching = CachingLocal::empty(CachingDurationStrategy::EachBlock);
+
+    let contract_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor.clone())
+        .with_caching_strategy(&caching)
+        .get_sum()
+        .await?;
+
+    let caller_result = TesterContract::new(TESTER_CONTRACT_ADDRESS)
+        .query(executor)
+        .with_caching_strategy(&caching)
+        .with_caller(&Address::from_bech32_string(CALLER_ADDRESS)?)
+        .get_sum()
+        .await?;
+
+    assert_eq!(contract_result, BigUint::from(5u8));
+    assert_eq!(caller_result, BigUint::from(6u8));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_simple_query_with_argument() -> Result<(), NovaXError> {
     let executor = get_executor();
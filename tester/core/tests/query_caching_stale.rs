@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Duration;
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use tokio::sync::Notify;
+use novax::Address;
+use novax::caching::CachingDurationStrategy;
+use novax::errors::NovaXError;
+use novax::executor::{ExecutorError, QueryBlock, QueryExecutor, TokenTransfer};
+use novax::data::NativeConvertible;
+use novax::tester::tester::TesterContract;
+use novax_caching::local::caching_local::CachingLocal;
+use novax_caching::stale::caching::CachingStale;
+use multiversx_sc_scenario::multiversx_sc::codec::TopDecodeMulti;
+
+const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+
+/// Returns the number of queries made so far, the queries following the first one waiting for the gate to open.
+#[derive(Clone)]
+struct GatedQueryExecutor {
+    calls: Arc<AtomicU8>,
+    gate: Arc<Notify>
+}
+
+#[async_trait]
+impl QueryExecutor for GatedQueryExecutor {
+    async fn execute<OutputManaged>(
+        &self,
+        _to: &Address,
+        _function: String,
+        _arguments: Vec<Vec<u8>>,
+        _egld_value: BigUint,
+        _esdt_transfers: Vec<TokenTransfer>,
+        _caller: Option<Address>,
+        _block: Option<QueryBlock>
+    ) -> Result<OutputManaged::Native, ExecutorError>
+        where
+            OutputManaged: TopDecodeMulti + NativeConvertible + Send + Sync
+    {
+        let calls = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+
+        if calls > 1 {
+            self.gate.notified().await;
+        }
+
+        let mut raw_result = vec![vec![calls]];
+
+        Ok(OutputManaged::multi_decode(&mut raw_result).unwrap().to_native())
+    }
+}
+
+#[tokio::test]
+async fn test_query_stale_value_does_not_wait_for_refresh() -> Result<(), NovaXError> {
+    let executor = GatedQueryExecutor {
+        calls: Arc::new(AtomicU8::new(0)),
+        gate: Arc::new(Notify::new()),
+    };
+
+    // Values are stale as soon as they are set
+    let caching = CachingStale::new(
+        CachingLocal::empty(CachingDurationStrategy::EachBlock),
+        CachingDurationStrategy::Duration(Duration::ZERO),
+        Duration::from_secs(60)
+    );
+
+    let contract = TesterContract::new(TESTER_CONTRACT_ADDRESS);
+
+    let first_result = contract
+        .query(executor.clone())
+        .with_caching_strategy(&caching)
+        .with_background_refresh()
+        .get_sum()
+        .await?;
+
+    let stale_result = tokio::time::timeout(
+        Duration::from_secs(1),
+        contract
+            .query(executor.clone())
+            .with_caching_strategy(&caching)
+            .with_background_refresh()
+            .get_sum()
+    )
+        .await
+        .expect("the query waited for the refresh")?;
+
+    executor.gate.notify_one();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let refreshed_result = contract
+        .query(executor)
+        .with_caching_strategy(&caching)
+        .with_background_refresh()
+        .get_sum()
+        .await?;
+
+    assert_eq!(first_result, BigUint::from(1u8));
+    assert_eq!(stale_result, BigUint::from(1u8));
+    assert_eq!(refreshed_result, BigUint::from(2u8));
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use async_trait::async_trait;
+use hyper::StatusCode;
+use serde::Serialize;
+use novax_request::error::request::RequestError;
+use novax_request::gateway::client::GatewayClient;
+
+pub const OWNER: &str = "erd17ytgf5z28rqmpjyvv0qzgaapufpu08fqygaxjuu79uj5uhe66vpsd230j2";
+pub const TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgq9wmk04e90fkhcuzns0pgwm33sdtxze346vpsq0ka9p";
+pub const OTHER_TESTER_CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqp699jngundfqw07d8jzkepucvpzush6k3wvqyc44rx";
+
+/// A gateway serving the tester contract, which has the local mint role of TEST-abcdef, and plain wallet accounts, or failing every request when offline.
+#[derive(Clone)]
+pub struct MockClient {
+    url: String,
+    is_offline: bool,
+    requests_count: Arc<AtomicUsize>
+}
+
+impl MockClient {
+    pub fn new(is_offline: bool) -> MockClient {
+        MockClient {
+            url: String::new(),
+            is_offline,
+            requests_count: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn get_requests_count(&self) -> usize {
+        self.requests_count.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl GatewayClient for MockClient {
+    type Owned = Self;
+
+    fn get_gateway_url(&self) -> &str {
+        &self.url
+    }
+
+    fn with_appended_url(&self, url: &str) -> Self::Owned {
+        MockClient {
+            url: format!("{}{url}", self.url),
+            is_offline: self.is_offline,
+            requests_count: self.requests_count.clone(),
+        }
+    }
+
+    async fn get(&self) -> Result<(StatusCode, Option<String>), RequestError> {
+        self.requests_count.fetch_add(1, Ordering::SeqCst);
+
+        if self.is_offline {
+            return Ok((StatusCode::INTERNAL_SERVER_ERROR, None))
+        }
+
+        let address = self.url.trim_start_matches("/address/").split('/').next().unwrap();
+
+        let text = if self.url.ends_with("/keys") {
+            let pairs = if address == TESTER_CONTRACT_ADDRESS { r#"{"73756d":"2a"}"# } else { "{}" };
+
+            format!(r#"{{"data":{{"pairs":{pairs}}},"error":"","code":"successful"}}"#)
+        } else if self.url.ends_with("/esdt") {
+            r#"{"data":{"esdts":{}},"error":"","code":"successful"}"#.to_string()
+        } else if self.url.ends_with("/esdts/roles") {
+            let roles = if address == TESTER_CONTRACT_ADDRESS { r#"{"TEST-abcdef":["ESDTRoleLocalMint"]}"# } else { "{}" };
+
+            format!(r#"{{"data":{{"roles":{roles}}},"error":"","code":"successful"}}"#)
+        } else if address == TESTER_CONTRACT_ADDRESS || address == OTHER_TESTER_CONTRACT_ADDRESS {
+            let code = hex::encode(std::fs::read("../../.novax/tester-contract.wasm").unwrap());
+
+            format!(r#"{{"data":{{"account":{{"address":"{address}","nonce":0,"balance":"0","code":"{code}","ownerAddress":"{OWNER}","rootHash":"aGFzaA=="}}}},"error":"","code":"successful"}}"#)
+        } else {
+            format!(r#"{{"data":{{"account":{{"address":"{address}","nonce":5,"balance":"1000","code":"","ownerAddress":"","rootHash":null}}}},"error":"","code":"successful"}}"#)
+        };
+
+        Ok((StatusCode::OK, Some(text)))
+    }
+
+    async fn post<Body>(&self, _body: &Body) -> Result<(StatusCode, Option<String>), RequestError> where Body: Serialize + Send + Sync {
+        unreachable!()
+    }
+}
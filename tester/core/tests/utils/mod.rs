@@ -1 +1,2 @@
-pub mod decode_scr_data;
\ No newline at end of file
+pub mod decode_scr_data;
+pub mod mock_client;
\ No newline at end of file